@@ -0,0 +1,3 @@
+-- Penanda akun yang sudah dihapus atas permintaan pengguna.
+-- Baris users tetap ada (dianonimkan) agar riwayat order untuk pembukuan tidak rusak.
+ALTER TABLE users ADD COLUMN deleted_at DATETIME NULL;
@@ -0,0 +1,24 @@
+-- Buku alamat pengguna (bisa lebih dari satu alamat pengiriman).
+CREATE TABLE IF NOT EXISTS addresses (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    user_id BIGINT NOT NULL,
+    label VARCHAR(50) NULL,
+    recipient_name VARCHAR(100) NOT NULL,
+    phone VARCHAR(20) NOT NULL,
+    province VARCHAR(100) NOT NULL,
+    city VARCHAR(100) NOT NULL,
+    district VARCHAR(100) NOT NULL,
+    postal_code VARCHAR(10) NOT NULL,
+    street VARCHAR(255) NOT NULL,
+    is_default BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    INDEX idx_addresses_user (user_id),
+    CONSTRAINT fk_addresses_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+-- Snapshot alamat disimpan di order supaya perubahan/penghapusan alamat
+-- tidak mengubah data pengiriman order yang sudah dibuat.
+ALTER TABLE orders
+    ADD COLUMN address_id BIGINT NULL,
+    ADD COLUMN shipping_address_snapshot JSON NULL;
@@ -0,0 +1,30 @@
+-- Data wilayah Indonesia (kode Kemendagri) untuk dropdown berjenjang dan validasi alamat.
+-- Isi tabel dimuat otomatis saat server start dari data/regions/*.csv.
+CREATE TABLE IF NOT EXISTS provinces (
+    id VARCHAR(13) PRIMARY KEY,
+    name VARCHAR(100) NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS regencies (
+    id VARCHAR(13) PRIMARY KEY,
+    province_id VARCHAR(13) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    INDEX idx_regencies_province (province_id),
+    CONSTRAINT fk_regencies_province FOREIGN KEY (province_id) REFERENCES provinces (id)
+);
+
+CREATE TABLE IF NOT EXISTS districts (
+    id VARCHAR(13) PRIMARY KEY,
+    regency_id VARCHAR(13) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    INDEX idx_districts_regency (regency_id),
+    CONSTRAINT fk_districts_regency FOREIGN KEY (regency_id) REFERENCES regencies (id)
+);
+
+CREATE TABLE IF NOT EXISTS villages (
+    id VARCHAR(13) PRIMARY KEY,
+    district_id VARCHAR(13) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    INDEX idx_villages_district (district_id),
+    CONSTRAINT fk_villages_district FOREIGN KEY (district_id) REFERENCES districts (id)
+);
@@ -0,0 +1,6 @@
+-- Index FULLTEXT untuk pencarian produk (/products/search).
+-- ft_products_name dipakai untuk memberi bobot lebih pada kecocokan di nama produk.
+ALTER TABLE products ADD FULLTEXT INDEX ft_products_name (name);
+ALTER TABLE products
+    ADD FULLTEXT INDEX ft_products_search (name, description, action, material, power, rod_length);
+ALTER TABLE kategori ADD FULLTEXT INDEX ft_kategori_name (name);
@@ -0,0 +1,7 @@
+-- Query pencarian yang pernah memberi hasil, dipakai untuk saran "pencarian populer".
+CREATE TABLE IF NOT EXISTS search_queries (
+    query VARCHAR(100) NOT NULL PRIMARY KEY,
+    hits BIGINT NOT NULL DEFAULT 1,
+    last_searched_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_search_queries_hits (hits)
+);
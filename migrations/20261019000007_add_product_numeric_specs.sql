@@ -0,0 +1,9 @@
+-- Spesifikasi joran dalam bentuk angka (satuan baku cm, lb, gram), disimpan di samping
+-- teks aslinya agar bisa difilter dan dikonversi. Baris lama diisi saat server start.
+ALTER TABLE products
+    ADD COLUMN rod_length_cm DOUBLE NULL AFTER rod_length,
+    ADD COLUMN line_min_lb DOUBLE NULL AFTER line_weight,
+    ADD COLUMN line_max_lb DOUBLE NULL AFTER line_min_lb,
+    ADD COLUMN cast_min_g DOUBLE NULL AFTER cast_weight,
+    ADD COLUMN cast_max_g DOUBLE NULL AFTER cast_min_g,
+    ADD INDEX idx_products_rod_length_cm (rod_length_cm);
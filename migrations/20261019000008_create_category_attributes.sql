@@ -0,0 +1,21 @@
+-- Skema atribut per kategori (reel, senar, umpan, tackle box, ...).
+-- Produk menyimpan nilai atributnya di products.attributes (JSON) yang divalidasi terhadap skema ini.
+CREATE TABLE IF NOT EXISTS category_attributes (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    category_id INT NOT NULL,
+    code VARCHAR(50) NOT NULL,
+    label VARCHAR(100) NOT NULL,
+    data_type VARCHAR(10) NOT NULL,
+    unit VARCHAR(20) NULL,
+    allowed_values JSON NULL,
+    required BOOLEAN NOT NULL DEFAULT FALSE,
+    min_value DOUBLE NULL,
+    max_value DOUBLE NULL,
+    sort_order INT NOT NULL DEFAULT 0,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uq_category_attributes_code (category_id, code),
+    CONSTRAINT fk_category_attributes_category
+        FOREIGN KEY (category_id) REFERENCES kategori(id) ON DELETE CASCADE
+);
+
+ALTER TABLE products ADD COLUMN attributes JSON NULL;
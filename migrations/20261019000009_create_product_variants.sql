@@ -0,0 +1,25 @@
+-- Varian produk (mis. satu model joran dengan beberapa panjang/power), masing-masing dengan SKU,
+-- kombinasi opsi, harga pengganti (opsional), stok, dan gambar sendiri.
+CREATE TABLE IF NOT EXISTS product_variants (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    product_id BIGINT NOT NULL,
+    sku VARCHAR(64) NOT NULL,
+    options JSON NOT NULL,
+    price DOUBLE NULL,
+    stock INT NOT NULL DEFAULT 0,
+    image_url VARCHAR(255) NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE KEY uq_product_variants_sku (sku),
+    INDEX idx_product_variants_product (product_id),
+    CONSTRAINT fk_product_variants_product
+        FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE
+);
+
+-- Varian yang dibeli; SKU disalin agar riwayat order tetap terbaca walau varian dihapus.
+ALTER TABLE order_items
+    ADD COLUMN variant_id BIGINT NULL,
+    ADD COLUMN sku VARCHAR(64) NULL,
+    ADD CONSTRAINT fk_order_items_variant
+        FOREIGN KEY (variant_id) REFERENCES product_variants (id) ON DELETE SET NULL;
@@ -0,0 +1,59 @@
+-- Stok per produk (untuk produk tanpa varian) dan per varian. `reserved_stock` adalah
+-- jumlah yang ditahan order PENDING; stok yang bisa dijual = stock - reserved_stock.
+ALTER TABLE products
+    ADD COLUMN stock INT NOT NULL DEFAULT 0,
+    ADD COLUMN reserved_stock INT NOT NULL DEFAULT 0;
+
+ALTER TABLE product_variants
+    ADD COLUMN reserved_stock INT NOT NULL DEFAULT 0;
+
+-- Batas waktu reservasi; order PENDING yang lewat batas ini dibatalkan dan stoknya dilepas.
+ALTER TABLE orders
+    ADD COLUMN reserved_until DATETIME NULL,
+    ADD INDEX idx_orders_reservation (status, reserved_until);
+
+-- Buku besar pergerakan stok.
+--   RESERVE / RELEASE : stok ditahan / dilepas untuk order (quantity = jumlah, positif)
+--   SALE              : order dibayar, stok fisik berkurang (quantity negatif)
+--   ADJUSTMENT        : koreksi manual admin (quantity positif atau negatif)
+CREATE TABLE IF NOT EXISTS stock_movements (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    product_id BIGINT NOT NULL,
+    variant_id BIGINT NULL,
+    order_id BIGINT NULL,
+    movement_type VARCHAR(20) NOT NULL,
+    quantity INT NOT NULL,
+    stock_after INT NOT NULL,
+    note VARCHAR(255) NULL,
+    created_by BIGINT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_stock_movements_product (product_id, created_at),
+    INDEX idx_stock_movements_order (order_id, movement_type),
+    -- variant_id sengaja tanpa FK: riwayat tetap menunjuk varian walau variannya dihapus
+    CONSTRAINT fk_stock_movements_product
+        FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE
+);
+
+-- Order PENDING dari sebelum reservasi sudah langsung mengurangi stok varian. Kembalikan
+-- stoknya lalu catat sebagai reservasi supaya dilepas/dijual dengan alur yang sama.
+UPDATE product_variants v
+JOIN (
+    SELECT oi.variant_id, SUM(oi.quantity) AS qty
+    FROM order_items oi
+    JOIN orders o ON o.id = oi.order_id
+    WHERE o.status = 'PENDING' AND oi.variant_id IS NOT NULL
+    GROUP BY oi.variant_id
+) pending ON pending.variant_id = v.id
+SET v.stock = v.stock + pending.qty,
+    v.reserved_stock = v.reserved_stock + pending.qty;
+
+INSERT INTO stock_movements (product_id, variant_id, order_id, movement_type, quantity, stock_after, note)
+SELECT oi.product_id, oi.variant_id, oi.order_id, 'RESERVE', oi.quantity, v.stock, 'migrasi reservasi'
+FROM order_items oi
+JOIN orders o ON o.id = oi.order_id
+JOIN product_variants v ON v.id = oi.variant_id
+WHERE o.status = 'PENDING';
+
+UPDATE orders
+SET reserved_until = DATE_ADD(order_date, INTERVAL 1 DAY)
+WHERE status = 'PENDING';
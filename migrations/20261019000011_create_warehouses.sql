@@ -0,0 +1,69 @@
+-- Gudang pengiriman. `priority` kecil = diutamakan jika jarak ke alamat tujuan sama.
+CREATE TABLE IF NOT EXISTS warehouses (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    code VARCHAR(10) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    province VARCHAR(100) NOT NULL,
+    city VARCHAR(100) NOT NULL,
+    address VARCHAR(255) NULL,
+    priority INT NOT NULL DEFAULT 0,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uq_warehouses_code (code)
+);
+
+INSERT IGNORE INTO warehouses (code, name, province, city, priority) VALUES
+    ('JKT', 'Gudang Jakarta', 'DKI Jakarta', 'Jakarta Utara', 0),
+    ('SBY', 'Gudang Surabaya', 'Jawa Timur', 'Surabaya', 1);
+
+-- Stok per gudang per SKU. `variant_id` NULL untuk produk tanpa varian; `variant_key`
+-- dipakai untuk UNIQUE karena NULL tidak dianggap duplikat di MySQL.
+-- Kolom stock/reserved_stock di products & product_variants tetap ada sebagai total semua gudang.
+CREATE TABLE IF NOT EXISTS warehouse_stock (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    warehouse_id BIGINT NOT NULL,
+    product_id BIGINT NOT NULL,
+    variant_id BIGINT NULL,
+    variant_key BIGINT AS (COALESCE(variant_id, 0)) STORED,
+    stock INT NOT NULL DEFAULT 0,
+    reserved_stock INT NOT NULL DEFAULT 0,
+    UNIQUE KEY uq_warehouse_stock_sku (product_id, variant_key, warehouse_id),
+    CONSTRAINT fk_warehouse_stock_warehouse
+        FOREIGN KEY (warehouse_id) REFERENCES warehouses (id),
+    CONSTRAINT fk_warehouse_stock_product
+        FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE,
+    CONSTRAINT fk_warehouse_stock_variant
+        FOREIGN KEY (variant_id) REFERENCES product_variants (id) ON DELETE CASCADE
+);
+
+-- Stok yang sudah ada dipindahkan ke gudang utama (Jakarta)
+INSERT INTO warehouse_stock (warehouse_id, product_id, variant_id, stock, reserved_stock)
+SELECT w.id, p.id, NULL, p.stock, p.reserved_stock
+FROM products p
+JOIN warehouses w ON w.code = 'JKT'
+WHERE p.stock > 0 OR p.reserved_stock > 0;
+
+INSERT INTO warehouse_stock (warehouse_id, product_id, variant_id, stock, reserved_stock)
+SELECT w.id, v.product_id, v.id, v.stock, v.reserved_stock
+FROM product_variants v
+JOIN warehouses w ON w.code = 'JKT'
+WHERE v.stock > 0 OR v.reserved_stock > 0;
+
+-- Gudang asal/tujuan setiap pergerakan stok dan gudang yang mengirim tiap item order.
+-- Item yang dipenuhi dari beberapa gudang disimpan sebagai beberapa baris order_items.
+ALTER TABLE stock_movements
+    ADD COLUMN warehouse_id BIGINT NULL AFTER variant_id;
+
+UPDATE stock_movements
+SET warehouse_id = (SELECT id FROM warehouses WHERE code = 'JKT');
+
+ALTER TABLE order_items
+    ADD COLUMN warehouse_id BIGINT NULL,
+    ADD CONSTRAINT fk_order_items_warehouse
+        FOREIGN KEY (warehouse_id) REFERENCES warehouses (id);
+
+-- Reservasi order PENDING yang sudah ada berada di gudang utama
+UPDATE order_items oi
+JOIN orders o ON o.id = oi.order_id
+SET oi.warehouse_id = (SELECT id FROM warehouses WHERE code = 'JKT')
+WHERE o.status = 'PENDING';
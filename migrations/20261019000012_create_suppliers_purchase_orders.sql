@@ -0,0 +1,63 @@
+-- Titik pemesanan ulang per produk. Untuk produk bervarian, ambang ini berlaku per varian.
+-- NULL = stok produk tidak dipantau.
+ALTER TABLE products
+    ADD COLUMN reorder_point INT NULL,
+    ADD COLUMN reorder_quantity INT NULL;
+
+CREATE TABLE IF NOT EXISTS suppliers (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    name VARCHAR(150) NOT NULL,
+    contact_name VARCHAR(100) NULL,
+    email VARCHAR(150) NULL,
+    phone VARCHAR(30) NULL,
+    address VARCHAR(255) NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Status: DRAFT -> ORDERED -> RECEIVED, atau CANCELLED sebelum diterima.
+CREATE TABLE IF NOT EXISTS purchase_orders (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    supplier_id BIGINT NOT NULL,
+    warehouse_id BIGINT NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'DRAFT',
+    note VARCHAR(255) NULL,
+    created_by BIGINT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    ordered_at DATETIME NULL,
+    received_at DATETIME NULL,
+    INDEX idx_purchase_orders_status (status),
+    CONSTRAINT fk_purchase_orders_supplier FOREIGN KEY (supplier_id) REFERENCES suppliers (id),
+    CONSTRAINT fk_purchase_orders_warehouse FOREIGN KEY (warehouse_id) REFERENCES warehouses (id)
+);
+
+CREATE TABLE IF NOT EXISTS purchase_order_items (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    purchase_order_id BIGINT NOT NULL,
+    product_id BIGINT NOT NULL,
+    variant_id BIGINT NULL,
+    quantity INT NOT NULL,
+    unit_cost DOUBLE NOT NULL,
+    received_quantity INT NOT NULL DEFAULT 0,
+    INDEX idx_purchase_order_items_sku (product_id, variant_id),
+    -- variant_id tanpa FK (sama seperti stock_movements) agar varian tetap bisa dihapus;
+    -- baris varian yang sudah dihapus dilewati saat penerimaan barang
+    CONSTRAINT fk_purchase_order_items_po
+        FOREIGN KEY (purchase_order_id) REFERENCES purchase_orders (id) ON DELETE CASCADE,
+    CONSTRAINT fk_purchase_order_items_product
+        FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE
+);
+
+-- Penerimaan barang dari PO dicatat sebagai RECEIPT di buku besar stok
+ALTER TABLE stock_movements
+    ADD COLUMN purchase_order_id BIGINT NULL AFTER order_id;
+
+-- Laporan stok menipis harian (satu baris per tanggal)
+CREATE TABLE IF NOT EXISTS low_stock_reports (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    report_date DATE NOT NULL,
+    item_count INT NOT NULL,
+    items JSON NOT NULL,
+    generated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uq_low_stock_reports_date (report_date)
+);
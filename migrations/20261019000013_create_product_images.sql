@@ -0,0 +1,25 @@
+-- Beberapa gambar per produk dengan urutan tampil. Gambar yang diunggah disimpan lewat
+-- storage (lokal / S3) dalam tiga ukuran; *_key NULL berarti URL eksternal lama.
+CREATE TABLE IF NOT EXISTS product_images (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    product_id BIGINT NOT NULL,
+    sort_order INT NOT NULL DEFAULT 0,
+    original_url VARCHAR(500) NOT NULL,
+    medium_url VARCHAR(500) NOT NULL,
+    thumbnail_url VARCHAR(500) NOT NULL,
+    original_key VARCHAR(255) NULL,
+    medium_key VARCHAR(255) NULL,
+    thumbnail_key VARCHAR(255) NULL,
+    width INT NULL,
+    height INT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_product_images_order (product_id, sort_order),
+    CONSTRAINT fk_product_images_product
+        FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE
+);
+
+-- image_url lama menjadi gambar pertama produk
+INSERT INTO product_images (product_id, sort_order, original_url, medium_url, thumbnail_url)
+SELECT id, 0, image_url, image_url, image_url
+FROM products
+WHERE image_url IS NOT NULL AND image_url <> '';
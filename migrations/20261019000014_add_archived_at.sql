@@ -0,0 +1,9 @@
+-- Produk & kategori tidak lagi dihapus permanen: order_items masih merujuk ke produk,
+-- dan produk merujuk ke kategori. Baris yang diarsipkan disembunyikan dari katalog
+-- dan bisa dipulihkan admin.
+ALTER TABLE products
+    ADD COLUMN archived_at DATETIME NULL,
+    ADD INDEX idx_products_archived_at (archived_at);
+
+ALTER TABLE kategori
+    ADD COLUMN archived_at DATETIME NULL;
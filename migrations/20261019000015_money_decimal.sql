@@ -0,0 +1,23 @@
+-- Semua nominal uang disimpan sebagai DECIMAL (bukan DOUBLE) agar tidak ada galat pembulatan
+-- biner. Aplikasi selalu menulis rupiah utuh (lihat src/utils/money.rs); dua digit desimal
+-- dipertahankan agar kompatibel dengan data order lama.
+ALTER TABLE products
+    MODIFY COLUMN price DECIMAL(15, 2) NOT NULL;
+
+ALTER TABLE product_variants
+    MODIFY COLUMN price DECIMAL(15, 2) NULL;
+
+ALTER TABLE purchase_order_items
+    MODIFY COLUMN unit_cost DECIMAL(15, 2) NOT NULL;
+
+ALTER TABLE orders
+    MODIFY COLUMN total_amount DECIMAL(15, 2) NOT NULL;
+
+ALTER TABLE order_items
+    MODIFY COLUMN price_at_order DECIMAL(15, 2) NOT NULL;
+
+-- Harga katalog yang masih pecahan dibulatkan ke rupiah terdekat (setengah ke atas),
+-- sama dengan aturan di aplikasi. Riwayat order dibiarkan apa adanya.
+UPDATE products SET price = ROUND(price) WHERE price <> ROUND(price);
+UPDATE product_variants SET price = ROUND(price) WHERE price <> ROUND(price);
+UPDATE purchase_order_items SET unit_cost = ROUND(unit_cost) WHERE unit_cost <> ROUND(unit_cost);
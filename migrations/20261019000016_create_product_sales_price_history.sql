@@ -0,0 +1,45 @@
+-- Harga sale terjadwal untuk satu produk (variant_id NULL) atau satu varian.
+-- Jadwal untuk target yang sama tidak boleh tumpang tindih (divalidasi aplikasi),
+-- jadi paling banyak satu sale yang berlaku untuk satu target pada satu waktu.
+CREATE TABLE IF NOT EXISTS product_sales (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    product_id BIGINT NOT NULL,
+    variant_id BIGINT NULL,
+    sale_price DECIMAL(15, 2) NOT NULL,
+    starts_at DATETIME NOT NULL,
+    ends_at DATETIME NOT NULL,
+    created_by BIGINT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_product_sales_window (product_id, variant_id, starts_at, ends_at),
+    CONSTRAINT fk_product_sales_product
+        FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE,
+    CONSTRAINT fk_product_sales_variant
+        FOREIGN KEY (variant_id) REFERENCES product_variants (id) ON DELETE CASCADE
+);
+
+-- Riwayat setiap perubahan harga normal dan jadwal sale.
+-- variant_id tanpa FK agar riwayat varian yang sudah dihapus tetap ada.
+CREATE TABLE IF NOT EXISTS price_history (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    product_id BIGINT NOT NULL,
+    variant_id BIGINT NULL,
+    -- PRICE, SALE_SCHEDULED, SALE_ENDED, SALE_CANCELLED
+    change_type VARCHAR(20) NOT NULL,
+    old_price DECIMAL(15, 2) NULL,
+    new_price DECIMAL(15, 2) NULL,
+    sale_id BIGINT NULL,
+    sale_starts_at DATETIME NULL,
+    sale_ends_at DATETIME NULL,
+    changed_by BIGINT NULL,
+    changed_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_price_history_product (product_id, changed_at),
+    CONSTRAINT fk_price_history_product
+        FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE
+);
+
+-- Harga yang berlaku saat ini menjadi titik awal riwayat
+INSERT INTO price_history (product_id, change_type, new_price)
+SELECT id, 'PRICE', price FROM products;
+
+INSERT INTO price_history (product_id, variant_id, change_type, new_price)
+SELECT product_id, id, 'PRICE', price FROM product_variants WHERE price IS NOT NULL;
@@ -0,0 +1,37 @@
+-- Flash sale: harga khusus satu SKU dalam jendela waktu, dengan kuota total dan batas per pelanggan.
+-- `sold` = unit di order PENDING + PAID; dikunci (FOR UPDATE) saat checkout agar kuota tidak terlampaui,
+-- dan dikembalikan saat order FAILED/kedaluwarsa.
+CREATE TABLE IF NOT EXISTS flash_sales (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    name VARCHAR(150) NOT NULL,
+    product_id BIGINT NOT NULL,
+    variant_id BIGINT NULL,
+    flash_price DECIMAL(15, 2) NOT NULL,
+    starts_at DATETIME NOT NULL,
+    ends_at DATETIME NOT NULL,
+    quota INT NOT NULL,
+    sold INT NOT NULL DEFAULT 0,
+    per_customer_limit INT NOT NULL,
+    created_by BIGINT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_flash_sales_window (starts_at, ends_at),
+    CONSTRAINT fk_flash_sales_product
+        FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE,
+    CONSTRAINT fk_flash_sales_variant
+        FOREIGN KEY (variant_id) REFERENCES product_variants (id) ON DELETE CASCADE
+);
+
+-- Jumlah unit per pelanggan per flash sale (order PENDING + PAID), untuk batas per pelanggan
+CREATE TABLE IF NOT EXISTS flash_sale_customers (
+    flash_sale_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    quantity INT NOT NULL DEFAULT 0,
+    PRIMARY KEY (flash_sale_id, user_id),
+    CONSTRAINT fk_flash_sale_customers_sale
+        FOREIGN KEY (flash_sale_id) REFERENCES flash_sales (id) ON DELETE CASCADE
+);
+
+-- Item order yang dibeli dengan harga flash sale
+ALTER TABLE order_items
+    ADD COLUMN flash_sale_id BIGINT NULL,
+    ADD INDEX idx_order_items_flash_sale (flash_sale_id);
@@ -0,0 +1,5 @@
+-- SKU tingkat produk, kunci upsert untuk impor katalog massal.
+-- Boleh kosong untuk produk lama; produk bervarian tetap memakai SKU per varian.
+ALTER TABLE products
+    ADD COLUMN sku VARCHAR(64) NULL AFTER id,
+    ADD UNIQUE INDEX uq_products_sku (sku);
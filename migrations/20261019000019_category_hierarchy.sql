@@ -0,0 +1,22 @@
+-- Kategori bertingkat: induk (parent_id), slug unik untuk URL, deskripsi, dan urutan tampil.
+ALTER TABLE kategori
+    ADD COLUMN parent_id INT NULL,
+    ADD COLUMN slug VARCHAR(120) NULL,
+    ADD COLUMN description TEXT NULL,
+    ADD COLUMN sort_order INT NOT NULL DEFAULT 0,
+    ADD INDEX idx_kategori_parent (parent_id, sort_order),
+    ADD CONSTRAINT fk_kategori_parent FOREIGN KEY (parent_id) REFERENCES kategori (id);
+
+-- Slug kategori lama dari namanya (aturan sama dengan utils::slug::slugify)
+UPDATE kategori SET slug = TRIM(BOTH '-' FROM REGEXP_REPLACE(LOWER(name), '[^a-z0-9]+', '-'));
+UPDATE kategori SET slug = CONCAT('kategori-', id) WHERE slug = '';
+
+-- Nama yang sama menghasilkan slug yang sama: bedakan dengan id
+UPDATE kategori k
+JOIN (SELECT slug FROM kategori GROUP BY slug HAVING COUNT(*) > 1) duplicate
+    ON k.slug = duplicate.slug
+SET k.slug = CONCAT(k.slug, '-', k.id);
+
+ALTER TABLE kategori
+    MODIFY slug VARCHAR(120) NOT NULL,
+    ADD UNIQUE INDEX uq_kategori_slug (slug);
@@ -0,0 +1,29 @@
+-- Slug unik untuk URL produk, riwayat slug lama (redirect), dan meta SEO untuk halaman SSR.
+ALTER TABLE products
+    ADD COLUMN slug VARCHAR(120) NULL,
+    ADD COLUMN meta_title VARCHAR(255) NULL,
+    ADD COLUMN meta_description VARCHAR(500) NULL;
+
+-- Slug produk lama dari namanya (aturan sama dengan utils::slug::slugify)
+UPDATE products SET slug = LEFT(TRIM(BOTH '-' FROM REGEXP_REPLACE(LOWER(name), '[^a-z0-9]+', '-')), 100);
+UPDATE products SET slug = CONCAT('produk-', id) WHERE slug = '';
+
+-- Nama yang sama menghasilkan slug yang sama: bedakan dengan id
+UPDATE products p
+JOIN (SELECT slug FROM products GROUP BY slug HAVING COUNT(*) > 1) duplicate
+    ON p.slug = duplicate.slug
+SET p.slug = CONCAT(p.slug, '-', p.id);
+
+ALTER TABLE products
+    MODIFY slug VARCHAR(120) NOT NULL,
+    ADD UNIQUE INDEX uq_products_slug (slug);
+
+-- Slug lama tetap bisa dibuka dan diarahkan (301) ke slug yang berlaku
+CREATE TABLE IF NOT EXISTS product_slug_redirects (
+    slug VARCHAR(120) NOT NULL PRIMARY KEY,
+    product_id BIGINT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_product_slug_redirects_product (product_id),
+    CONSTRAINT fk_product_slug_redirects_product
+        FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE
+);
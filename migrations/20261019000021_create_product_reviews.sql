@@ -0,0 +1,45 @@
+-- Ulasan produk dari pembeli terverifikasi (punya order PAID berisi produk tersebut).
+-- Ulasan baru berstatus PENDING dan baru tampil setelah disetujui admin.
+CREATE TABLE IF NOT EXISTS product_reviews (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    product_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    rating TINYINT NOT NULL,
+    title VARCHAR(150) NULL,
+    body TEXT NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'PENDING',
+    moderation_note VARCHAR(500) NULL,
+    moderated_by BIGINT NULL,
+    moderated_at DATETIME NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE INDEX uq_product_reviews_user (product_id, user_id),
+    INDEX idx_product_reviews_status (status, created_at),
+    INDEX idx_product_reviews_product (product_id, status, created_at),
+    CONSTRAINT fk_product_reviews_product
+        FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE,
+    CONSTRAINT fk_product_reviews_user
+        FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+    CONSTRAINT chk_product_reviews_rating CHECK (rating BETWEEN 1 AND 5)
+);
+
+-- Foto ulasan disimpan lewat storage dalam tiga ukuran, sama seperti galeri produk
+CREATE TABLE IF NOT EXISTS product_review_photos (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    review_id BIGINT NOT NULL,
+    sort_order INT NOT NULL DEFAULT 0,
+    original_url VARCHAR(500) NOT NULL,
+    medium_url VARCHAR(500) NOT NULL,
+    thumbnail_url VARCHAR(500) NOT NULL,
+    original_key VARCHAR(255) NULL,
+    medium_key VARCHAR(255) NULL,
+    thumbnail_key VARCHAR(255) NULL,
+    INDEX idx_product_review_photos_review (review_id, sort_order),
+    CONSTRAINT fk_product_review_photos_review
+        FOREIGN KEY (review_id) REFERENCES product_reviews (id) ON DELETE CASCADE
+);
+
+-- Ringkasan rating dari ulasan yang disetujui, disimpan di produk agar listing bisa diurutkan
+ALTER TABLE products
+    ADD COLUMN rating_average DOUBLE NULL,
+    ADD COLUMN rating_count INT NOT NULL DEFAULT 0,
+    ADD INDEX idx_products_rating (rating_average, rating_count);
@@ -0,0 +1,45 @@
+-- Notifikasi in-app per user (mis. pertanyaan produk dijawab).
+CREATE TABLE IF NOT EXISTS notifications (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    user_id BIGINT NOT NULL,
+    type VARCHAR(40) NOT NULL,
+    title VARCHAR(200) NOT NULL,
+    message TEXT NOT NULL,
+    data JSON NULL,
+    read_at DATETIME NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_notifications_user (user_id, read_at, created_at),
+    CONSTRAINT fk_notifications_user
+        FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+-- Tanya jawab produk. Pertanyaan dan jawaban langsung tampil; admin bisa menyembunyikannya.
+CREATE TABLE IF NOT EXISTS product_questions (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    product_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    question TEXT NOT NULL,
+    hidden_at DATETIME NULL,
+    hidden_by BIGINT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_product_questions_product (product_id, hidden_at, created_at),
+    CONSTRAINT fk_product_questions_product
+        FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE,
+    CONSTRAINT fk_product_questions_user
+        FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS product_answers (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    question_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    answer TEXT NOT NULL,
+    hidden_at DATETIME NULL,
+    hidden_by BIGINT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_product_answers_question (question_id, created_at),
+    CONSTRAINT fk_product_answers_question
+        FOREIGN KEY (question_id) REFERENCES product_questions (id) ON DELETE CASCADE,
+    CONSTRAINT fk_product_answers_user
+        FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
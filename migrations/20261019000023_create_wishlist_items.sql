@@ -0,0 +1,16 @@
+-- Wishlist user. last_price / last_in_stock adalah harga efektif dan status stok terakhir yang
+-- sudah diketahui user (saat ditambahkan atau saat notifikasi terakhir); job latar belakang
+-- membandingkannya dengan kondisi sekarang untuk notifikasi harga turun dan stok kembali.
+CREATE TABLE IF NOT EXISTS wishlist_items (
+    user_id BIGINT NOT NULL,
+    product_id BIGINT NOT NULL,
+    last_price DECIMAL(15, 2) NOT NULL,
+    last_in_stock BOOLEAN NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, product_id),
+    INDEX idx_wishlist_items_product (product_id, created_at),
+    CONSTRAINT fk_wishlist_items_user
+        FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+    CONSTRAINT fk_wishlist_items_product
+        FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE
+);
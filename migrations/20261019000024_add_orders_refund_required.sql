@@ -0,0 +1,5 @@
+-- Pembayaran yang masuk setelah order FAILED/kedaluwarsa tetapi stok atau kuota flash sale
+-- sudah tidak tersedia: order tetap FAILED dan ditandai agar dananya dikembalikan.
+ALTER TABLE orders
+    ADD COLUMN refund_required_at DATETIME NULL,
+    ADD INDEX idx_orders_refund_required (refund_required_at);
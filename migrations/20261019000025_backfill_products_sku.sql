@@ -0,0 +1,3 @@
+-- Isi SKU produk lama agar hasil ekspor katalog bisa diimpor ulang (impor wajib SKU).
+-- IGNORE: produk yang SKU turunannya kebetulan sudah dipakai dibiarkan kosong.
+UPDATE IGNORE products SET sku = CONCAT('P', id) WHERE sku IS NULL OR sku = '';
@@ -0,0 +1,98 @@
+// src/cli.rs
+//
+// Perintah administrasi yang dijalankan dari terminal, tanpa menyalakan server:
+//   back-app import-products <file.csv|file.json> [--dry-run]
+//   back-app export-products <file.csv|file.json>
+// Format file ditentukan dari ekstensinya.
+
+use crate::dtos::product::RodProduct;
+use crate::dtos::product_transfer::{ImportAction, TransferFormat};
+use crate::handlers::product::import::run_import;
+use crate::utils::product_transfer;
+use sqlx::{MySql, Pool};
+
+const USAGE: &str = "Perintah:\n  \
+    import-products <file.csv|file.json> [--dry-run]\n  \
+    export-products <file.csv|file.json>";
+
+/// Jalankan perintah CLI dan kembalikan exit code.
+pub async fn run(pool: &Pool<MySql>, args: &[String]) -> i32 {
+    let result = match args.first().map(String::as_str) {
+        Some("import-products") => import_products(pool, &args[1..]).await,
+        Some("export-products") => export_products(pool, &args[1..]).await,
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => 0,
+        Err(message) => {
+            eprintln!("❌ {}", message);
+            1
+        }
+    }
+}
+
+fn file_format(path: &str) -> Result<TransferFormat, String> {
+    TransferFormat::from_path(path)
+        .ok_or_else(|| format!("Ekstensi file harus .csv atau .json: {}", path))
+}
+
+async fn import_products(pool: &Pool<MySql>, args: &[String]) -> Result<(), String> {
+    let dry_run = args.iter().any(|a| a == "--dry-run");
+    let path = args
+        .iter()
+        .find(|a| !a.starts_with("--"))
+        .ok_or_else(|| USAGE.to_string())?;
+    let format = file_format(path)?;
+    let content =
+        std::fs::read_to_string(path).map_err(|e| format!("Gagal membaca {}: {}", path, e))?;
+
+    // Perubahan dari CLI dicatat tanpa admin (changed_by kosong) di riwayat harga
+    let report = run_import(pool, &content, format, dry_run, None)
+        .await
+        .map_err(|(_, message)| message)?;
+
+    for row in report
+        .rows
+        .iter()
+        .filter(|r| r.action == ImportAction::Error)
+    {
+        eprintln!(
+            "Baris {} ({}): {}",
+            row.row,
+            row.sku.as_deref().unwrap_or("-"),
+            row.error.as_deref().unwrap_or("")
+        );
+    }
+    if report.failed > 0 {
+        return Err(format!(
+            "{} dari {} baris tidak valid, tidak ada yang disimpan",
+            report.failed, report.total
+        ));
+    }
+
+    let prefix = if report.applied {
+        "✅ Impor selesai"
+    } else {
+        "🔍 Dry-run"
+    };
+    println!(
+        "{}: {} produk baru, {} diperbarui",
+        prefix, report.created, report.updated
+    );
+    Ok(())
+}
+
+async fn export_products(pool: &Pool<MySql>, args: &[String]) -> Result<(), String> {
+    let path = args.first().ok_or_else(|| USAGE.to_string())?;
+    let format = file_format(path)?;
+
+    let rows = RodProduct::find_for_export(pool)
+        .await
+        .map_err(|e| format!("Gagal membaca katalog: {}", e))?;
+    let total = rows.len();
+    let content = product_transfer::render(rows, format)?;
+    std::fs::write(path, content).map_err(|e| format!("Gagal menulis {}: {}", path, e))?;
+
+    println!("✅ {} produk diekspor ke {}", total, path);
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct AddressDto {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = 1)]
+    pub user_id: i64,
+    #[schema(example = "Rumah")]
+    pub label: Option<String>,
+    #[schema(example = "John Doe")]
+    pub recipient_name: String,
+    #[schema(example = "081234567890")]
+    pub phone: String,
+    #[schema(example = "DKI Jakarta")]
+    pub province: String,
+    #[schema(example = "Jakarta Selatan")]
+    pub city: String,
+    #[schema(example = "Kebayoran Baru")]
+    pub district: String,
+    #[schema(example = "12190")]
+    pub postal_code: String,
+    #[schema(example = "Jl. Sudirman No. 123")]
+    pub street: String,
+    pub is_default: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl AddressDto {
+    /// Format satu baris untuk kolom teks `orders.shipping_address`.
+    pub fn to_shipping_text(&self) -> String {
+        format!(
+            "{} ({}), {}, {}, {}, {} {}",
+            self.recipient_name,
+            self.phone,
+            self.street,
+            self.district,
+            self.city,
+            self.province,
+            self.postal_code
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "label": "Rumah",
+    "recipient_name": "John Doe",
+    "phone": "081234567890",
+    "province": "DKI Jakarta",
+    "city": "Jakarta Selatan",
+    "district": "Kebayoran Baru",
+    "postal_code": "12190",
+    "street": "Jl. Sudirman No. 123",
+    "is_default": true
+}))]
+pub struct NewAddressDto {
+    pub label: Option<String>,
+    pub recipient_name: String,
+    pub phone: String,
+    pub province: String,
+    pub city: String,
+    pub district: String,
+    pub postal_code: String,
+    pub street: String,
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+impl NewAddressDto {
+    /// Validasi dasar sebelum disimpan. Mengembalikan pesan error yang siap ditampilkan.
+    pub fn validate(&self) -> Result<(), String> {
+        let required = [
+            ("recipient_name", &self.recipient_name),
+            ("phone", &self.phone),
+            ("province", &self.province),
+            ("city", &self.city),
+            ("district", &self.district),
+            ("postal_code", &self.postal_code),
+            ("street", &self.street),
+        ];
+        if let Some((field, _)) = required.iter().find(|(_, v)| v.trim().is_empty()) {
+            return Err(format!("Field {} wajib diisi", field));
+        }
+
+        let phone = self.phone.trim();
+        let digits = phone.strip_prefix('+').unwrap_or(phone);
+        if digits.len() < 8 || digits.len() > 15 || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Nomor telepon tidak valid".to_string());
+        }
+
+        let postal = self.postal_code.trim();
+        if postal.len() != 5 || !postal.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Kode pos harus 5 digit angka".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "name": "warehouse-sync",
+    "user_email": "warehouse-bot@example.com",
+    "scopes": ["warehouses:read", "inventory:write"],
+    "expires_in_days": 90
+}))]
+pub struct NewApiKeyDto {
+    pub name: String,
+    /// Pemilik key; default admin yang membuat key.
+    pub user_email: Option<String>,
+    pub scopes: Vec<String>,
+    /// Kosongkan untuk key tanpa masa berlaku.
+    pub expires_in_days: Option<i64>,
+}
+
+/// Data API key yang aman ditampilkan (tanpa hash).
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct ApiKeyDto {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = "warehouse-sync")]
+    pub name: String,
+    #[schema(example = "aB3dE5fG")]
+    pub prefix: String,
+    #[schema(example = "warehouse-bot@example.com")]
+    pub user_email: String,
+    #[schema(example = "products:read,orders:read")]
+    pub scopes: String,
+    pub expires_at: Option<NaiveDateTime>,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub revoked: bool,
+    pub created_at: NaiveDateTime,
+}
+
+/// Response saat key dibuat. `key` hanya muncul sekali di sini.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedApiKey {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = "bk_aB3dE5fG_0123456789abcdefghijABCDEFGHIJ")]
+    pub key: String,
+    #[schema(example = "aB3dE5fG")]
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<NaiveDateTime>,
+}
+
+/// Baris hasil lookup key aktif beserta data pemiliknya, dipakai middleware auth.
+#[derive(Debug, FromRow)]
+pub struct ApiKeyOwner {
+    pub id: i64,
+    pub key_hash: String,
+    pub scopes: String,
+    pub expires_at: Option<NaiveDateTime>,
+    pub email: String,
+    pub role: String,
+}
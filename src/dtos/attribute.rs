@@ -0,0 +1,265 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AttributeType {
+    String,
+    Number,
+    Boolean,
+    /// Nilai harus salah satu dari `allowed_values`
+    Enum,
+}
+
+impl AttributeType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AttributeType::String => "string",
+            AttributeType::Number => "number",
+            AttributeType::Boolean => "boolean",
+            AttributeType::Enum => "enum",
+        }
+    }
+}
+
+impl TryFrom<String> for AttributeType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "string" => Ok(AttributeType::String),
+            "number" => Ok(AttributeType::Number),
+            "boolean" => Ok(AttributeType::Boolean),
+            "enum" => Ok(AttributeType::Enum),
+            other => Err(format!("Tipe atribut tidak dikenal: {}", other)),
+        }
+    }
+}
+
+/// Satu atribut dalam skema kategori, mis. `gear_ratio` (number) untuk kategori Reel.
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct AttributeDefinition {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = 2)]
+    pub category_id: i32,
+    #[schema(example = "gear_ratio")]
+    pub code: String,
+    #[schema(example = "Gear Ratio")]
+    pub label: String,
+    #[sqlx(try_from = "String")]
+    pub data_type: AttributeType,
+    #[schema(example = ":1")]
+    pub unit: Option<String>,
+    #[schema(value_type = Option<Vec<String>>)]
+    pub allowed_values: Option<Json<Vec<String>>>,
+    pub required: bool,
+    #[schema(example = 4.0)]
+    pub min_value: Option<f64>,
+    #[schema(example = 9.0)]
+    pub max_value: Option<f64>,
+    pub sort_order: i32,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "code": "bearing_count",
+    "label": "Jumlah Bearing",
+    "data_type": "number",
+    "unit": "bb",
+    "allowed_values": null,
+    "required": false,
+    "min_value": 1,
+    "max_value": 20,
+    "sort_order": 1
+}))]
+pub struct NewAttributeDto {
+    pub code: String,
+    pub label: String,
+    pub data_type: AttributeType,
+    pub unit: Option<String>,
+    pub allowed_values: Option<Vec<String>>,
+    #[serde(default)]
+    pub required: bool,
+    pub min_value: Option<f64>,
+    pub max_value: Option<f64>,
+    #[serde(default)]
+    pub sort_order: i32,
+}
+
+/// Kode atribut dipakai sebagai key JSON dan nama parameter filter, jadi dibatasi
+/// huruf kecil, angka, dan underscore.
+pub fn is_valid_code(code: &str) -> bool {
+    let mut chars = code.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && code.len() <= 50
+}
+
+impl NewAttributeDto {
+    pub fn validate(&self) -> Result<(), String> {
+        if !is_valid_code(&self.code) {
+            return Err(
+                "code hanya boleh huruf kecil, angka, dan underscore (diawali huruf)".to_string(),
+            );
+        }
+        if self.label.trim().is_empty() {
+            return Err("label wajib diisi".to_string());
+        }
+
+        let has_values = self.allowed_values.as_ref().is_some_and(|v| !v.is_empty());
+        match self.data_type {
+            AttributeType::Enum if !has_values => {
+                return Err("Atribut enum wajib punya allowed_values".to_string());
+            }
+            AttributeType::Enum => {}
+            _ if self.allowed_values.is_some() => {
+                return Err("allowed_values hanya untuk atribut bertipe enum".to_string());
+            }
+            _ => {}
+        }
+
+        if (self.min_value.is_some() || self.max_value.is_some())
+            && self.data_type != AttributeType::Number
+        {
+            return Err("min_value/max_value hanya untuk atribut bertipe number".to_string());
+        }
+        if let (Some(min), Some(max)) = (self.min_value, self.max_value)
+            && min > max
+        {
+            return Err("min_value tidak boleh lebih besar dari max_value".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl AttributeDefinition {
+    /// Periksa satu nilai terhadap definisi atribut ini.
+    fn check(&self, value: &Value) -> Result<(), String> {
+        let invalid = |expected: &str| {
+            Err(format!(
+                "Atribut '{}' harus bertipe {}",
+                self.code, expected
+            ))
+        };
+
+        match self.data_type {
+            AttributeType::String => match value {
+                Value::String(s) if !s.trim().is_empty() => Ok(()),
+                _ => invalid("string"),
+            },
+            AttributeType::Boolean => match value {
+                Value::Bool(_) => Ok(()),
+                _ => invalid("boolean"),
+            },
+            AttributeType::Number => {
+                let Some(number) = value.as_f64() else {
+                    return invalid("number");
+                };
+                if self.min_value.is_some_and(|min| number < min)
+                    || self.max_value.is_some_and(|max| number > max)
+                {
+                    return Err(format!(
+                        "Atribut '{}' harus di antara {} dan {}",
+                        self.code,
+                        self.min_value.map_or("-".to_string(), |v| v.to_string()),
+                        self.max_value.map_or("-".to_string(), |v| v.to_string())
+                    ));
+                }
+                Ok(())
+            }
+            AttributeType::Enum => {
+                let allowed = self
+                    .allowed_values
+                    .as_ref()
+                    .map(|v| v.0.as_slice())
+                    .unwrap_or(&[]);
+                match value {
+                    Value::String(s) if allowed.iter().any(|a| a == s) => Ok(()),
+                    _ => Err(format!(
+                        "Atribut '{}' harus salah satu dari: {}",
+                        self.code,
+                        allowed.join(", ")
+                    )),
+                }
+            }
+        }
+    }
+}
+
+/// Validasi atribut produk terhadap skema kategorinya: key harus terdaftar, tipe cocok,
+/// dan atribut wajib harus ada. Nilai `null` dianggap tidak diisi.
+pub fn validate_attributes(
+    schema: &[AttributeDefinition],
+    attributes: &Map<String, Value>,
+) -> Result<Map<String, Value>, String> {
+    let mut cleaned = Map::new();
+
+    for (code, value) in attributes {
+        if value.is_null() {
+            continue;
+        }
+        let definition = schema
+            .iter()
+            .find(|d| &d.code == code)
+            .ok_or_else(|| format!("Atribut '{}' tidak ada di skema kategori ini", code))?;
+        definition.check(value)?;
+        cleaned.insert(code.clone(), value.clone());
+    }
+
+    if let Some(missing) = schema
+        .iter()
+        .find(|d| d.required && !cleaned.contains_key(&d.code))
+    {
+        return Err(format!("Atribut '{}' wajib diisi", missing.code));
+    }
+
+    Ok(cleaned)
+}
+
+/// Filter atribut pada listing produk, dari query `attr.<code>=nilai`,
+/// `attr.<code>.min=angka`, dan `attr.<code>.max=angka`.
+#[derive(Debug, Clone)]
+pub enum AttributeFilter {
+    Equals(String, String),
+    Min(String, f64),
+    Max(String, f64),
+}
+
+impl AttributeFilter {
+    pub fn parse_all(query: &HashMap<String, String>) -> Result<Vec<Self>, String> {
+        let mut filters = Vec::new();
+
+        for (key, value) in query {
+            let Some(rest) = key.strip_prefix("attr.") else {
+                continue;
+            };
+            let (code, bound) = match rest.rsplit_once('.') {
+                Some((code, bound)) => (code, Some(bound)),
+                None => (rest, None),
+            };
+            if !is_valid_code(code) {
+                return Err(format!("Filter atribut '{}' tidak valid", key));
+            }
+
+            let number = || {
+                value
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|_| format!("Nilai filter '{}' harus berupa angka", key))
+            };
+            filters.push(match bound {
+                None => Self::Equals(code.to_string(), value.trim().to_string()),
+                Some("min") => Self::Min(code.to_string(), number()?),
+                Some("max") => Self::Max(code.to_string(), number()?),
+                Some(_) => return Err(format!("Filter atribut '{}' tidak valid", key)),
+            });
+        }
+
+        Ok(filters)
+    }
+}
@@ -19,6 +19,9 @@ pub struct LoginResponse {
     pub message: String,
     pub access_token: Option<String>,
     pub refresh_token: Option<String>,
+    /// Kirim ulang nilai ini di header `X-CSRF-Token` untuk request non-GET berbasis cookie.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csrf_token: Option<String>,
     pub user: Option<UserLoginData>,
 }
 
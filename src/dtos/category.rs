@@ -1,5 +1,7 @@
+use crate::utils::slug::validate_slug;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use std::collections::{HashMap, HashSet};
 use utoipa::ToSchema;
 
 #[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
@@ -8,12 +10,123 @@ pub struct KategoriDto {
     pub id: i32,
     #[schema(example = "Joran Casting")]
     pub name: String,
+    /// Dipakai di URL, mis. `/categories/joran-casting/products`
+    #[schema(example = "joran-casting")]
+    pub slug: String,
+    /// Kategori induk; kosong untuk kategori tingkat atas
+    #[schema(example = json!(null))]
+    pub parent_id: Option<i32>,
+    pub description: Option<String>,
+    /// Urutan tampil di antara kategori yang seinduk (kecil dulu)
+    #[schema(example = 0)]
+    pub sort_order: i32,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[schema(example = json!({
-    "name": "Joran Spinning"
+    "name": "Joran Spinning",
+    "slug": null,
+    "parent_id": 1,
+    "description": "Joran untuk reel spinning",
+    "sort_order": 0
 }))]
 pub struct NewKategoriDto {
     pub name: String,
+    /// Kosong = dibuat dari nama (saat update: slug lama dipertahankan)
+    #[serde(default)]
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub parent_id: Option<i32>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub sort_order: i32,
+}
+
+impl NewKategoriDto {
+    pub fn validate(&self) -> Result<(), String> {
+        let name = self.name.trim();
+        if name.is_empty() || name.len() > 100 {
+            return Err("Nama kategori wajib diisi (maksimal 100 karakter)".to_string());
+        }
+        if let Some(slug) = &self.slug {
+            validate_slug(slug)?;
+        }
+        Ok(())
+    }
+}
+
+/// Baris kategori aktif beserta jumlah produk aktif langsung di dalamnya.
+#[derive(Debug, FromRow)]
+pub struct CategoryCountRow {
+    pub id: i32,
+    pub parent_id: Option<i32>,
+    pub name: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub sort_order: i32,
+    pub product_count: i64,
+}
+
+/// Satu simpul pohon kategori.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CategoryTreeNode {
+    #[schema(example = 1)]
+    pub id: i32,
+    #[schema(example = "Joran")]
+    pub name: String,
+    #[schema(example = "joran")]
+    pub slug: String,
+    pub description: Option<String>,
+    pub sort_order: i32,
+    /// Produk aktif yang langsung berada di kategori ini
+    #[schema(example = 3)]
+    pub product_count: i64,
+    /// Produk aktif di kategori ini beserta seluruh subkategorinya
+    #[schema(example = 42)]
+    pub total_product_count: i64,
+    #[schema(no_recursion)]
+    pub children: Vec<CategoryTreeNode>,
+}
+
+/// Susun baris kategori (sudah terurut `sort_order`) menjadi pohon. Kategori yang induknya
+/// tidak ada di daftar (mis. diarsipkan) ditampilkan di tingkat atas.
+pub fn build_category_tree(rows: Vec<CategoryCountRow>) -> Vec<CategoryTreeNode> {
+    let ids: HashSet<i32> = rows.iter().map(|r| r.id).collect();
+    let mut children_of: HashMap<Option<i32>, Vec<CategoryCountRow>> = HashMap::new();
+    for row in rows {
+        let parent = row.parent_id.filter(|p| ids.contains(p));
+        children_of.entry(parent).or_default().push(row);
+    }
+    build_children(None, &mut children_of)
+}
+
+fn build_children(
+    parent: Option<i32>,
+    children_of: &mut HashMap<Option<i32>, Vec<CategoryCountRow>>,
+) -> Vec<CategoryTreeNode> {
+    let rows = children_of.remove(&parent).unwrap_or_default();
+    rows.into_iter()
+        .map(|row| {
+            let children = build_children(Some(row.id), children_of);
+            let total_product_count =
+                row.product_count + children.iter().map(|c| c.total_product_count).sum::<i64>();
+            CategoryTreeNode {
+                id: row.id,
+                name: row.name,
+                slug: row.slug,
+                description: row.description,
+                sort_order: row.sort_order,
+                product_count: row.product_count,
+                total_product_count,
+                children,
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteCategoryParams {
+    /// Pindahkan produk kategori ini ke kategori lain sebelum diarsipkan
+    pub reassign_to: Option<i32>,
 }
@@ -0,0 +1,87 @@
+use crate::dtos::pricing::SaleStatus;
+use crate::utils::money::Money;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+/// Flash sale satu produk/varian beserta sisa kuotanya.
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct FlashSale {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = "Flash Sale 10.10")]
+    pub name: String,
+    #[schema(example = 1)]
+    pub product_id: i64,
+    #[schema(example = "Rod Shimano")]
+    pub product_name: String,
+    /// Kosong = produk tanpa varian
+    #[schema(example = 4)]
+    pub variant_id: Option<i64>,
+    #[schema(example = "ABU-PMX-70M")]
+    pub sku: Option<String>,
+    /// Harga flash sale per unit
+    pub flash_price: Money,
+    /// Harga normal produk/varian (tanpa sale) sebagai pembanding
+    pub regular_price: Money,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+    #[schema(example = 100)]
+    pub quota: i32,
+    /// Kuota yang belum terjual atau ditahan order PENDING
+    #[schema(example = 37)]
+    pub remaining: i64,
+    #[schema(example = 2)]
+    pub per_customer_limit: i32,
+    #[sqlx(try_from = "String")]
+    pub status: SaleStatus,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "name": "Flash Sale 10.10",
+    "product_id": 1,
+    "variant_id": 4,
+    "flash_price": 999000,
+    "starts_at": "2026-10-10T10:00:00",
+    "ends_at": "2026-10-10T12:00:00",
+    "quota": 100,
+    "per_customer_limit": 2
+}))]
+pub struct NewFlashSaleDto {
+    pub name: String,
+    pub product_id: i64,
+    /// Wajib diisi jika produk punya varian aktif
+    pub variant_id: Option<i64>,
+    /// Harus lebih rendah dari harga normal target saat flash sale dibuat
+    pub flash_price: Money,
+    /// Waktu server (zona waktu database)
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+    /// Jumlah unit total yang dijual dengan harga flash sale
+    pub quota: i32,
+    /// Jumlah unit maksimal per pelanggan
+    pub per_customer_limit: i32,
+}
+
+impl NewFlashSaleDto {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Nama flash sale wajib diisi".to_string());
+        }
+        if !self.flash_price.is_positive() {
+            return Err("Harga flash sale harus lebih dari 0".to_string());
+        }
+        if self.ends_at <= self.starts_at {
+            return Err("ends_at harus setelah starts_at".to_string());
+        }
+        if self.quota <= 0 {
+            return Err("Kuota harus lebih dari 0".to_string());
+        }
+        if self.per_customer_limit <= 0 || self.per_customer_limit > self.quota {
+            return Err("Batas per pelanggan harus antara 1 dan kuota".to_string());
+        }
+        Ok(())
+    }
+}
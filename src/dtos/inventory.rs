@@ -0,0 +1,313 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum MovementType {
+    /// Stok ditahan untuk order PENDING
+    Reserve,
+    /// Reservasi dilepas (pembayaran gagal / order kedaluwarsa)
+    Release,
+    /// Order dibayar, stok fisik berkurang
+    Sale,
+    /// Koreksi manual oleh admin
+    Adjustment,
+    /// Pindah stok antar gudang (negatif di gudang asal, positif di gudang tujuan)
+    Transfer,
+    /// Barang dari purchase order diterima di gudang
+    Receipt,
+}
+
+impl MovementType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MovementType::Reserve => "RESERVE",
+            MovementType::Release => "RELEASE",
+            MovementType::Sale => "SALE",
+            MovementType::Adjustment => "ADJUSTMENT",
+            MovementType::Transfer => "TRANSFER",
+            MovementType::Receipt => "RECEIPT",
+        }
+    }
+}
+
+impl TryFrom<String> for MovementType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "RESERVE" => Ok(MovementType::Reserve),
+            "RELEASE" => Ok(MovementType::Release),
+            "SALE" => Ok(MovementType::Sale),
+            "ADJUSTMENT" => Ok(MovementType::Adjustment),
+            "TRANSFER" => Ok(MovementType::Transfer),
+            "RECEIPT" => Ok(MovementType::Receipt),
+            other => Err(format!("Jenis pergerakan stok tidak dikenal: {}", other)),
+        }
+    }
+}
+
+/// Satu baris buku besar stok.
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct StockMovement {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = 1)]
+    pub product_id: i64,
+    pub variant_id: Option<i64>,
+    #[schema(example = 1)]
+    pub warehouse_id: Option<i64>,
+    pub order_id: Option<i64>,
+    pub purchase_order_id: Option<i64>,
+    #[sqlx(try_from = "String")]
+    pub movement_type: MovementType,
+    /// Perubahan stok fisik (SALE/ADJUSTMENT/TRANSFER/RECEIPT) atau jumlah yang ditahan/dilepas (RESERVE/RELEASE)
+    #[schema(example = -2)]
+    pub quantity: i32,
+    /// Stok fisik gudang setelah pergerakan ini
+    #[schema(example = 10)]
+    pub stock_after: i32,
+    pub note: Option<String>,
+    pub created_by: Option<i64>,
+    pub created_at: NaiveDateTime,
+}
+
+/// Stok fisik dan yang sedang ditahan untuk satu produk atau varian.
+#[derive(Debug, Clone, Copy, FromRow)]
+pub struct StockCounts {
+    pub stock: i32,
+    pub reserved_stock: i32,
+}
+
+impl StockCounts {
+    pub fn available(self) -> i32 {
+        self.stock - self.reserved_stock
+    }
+}
+
+/// Baris warehouse_stock yang dikunci saat checkout.
+#[derive(Debug, Clone, Copy, FromRow)]
+pub struct WarehouseCounts {
+    pub warehouse_id: i64,
+    pub stock: i32,
+    pub reserved_stock: i32,
+}
+
+impl WarehouseCounts {
+    pub fn available(self) -> i32 {
+        self.stock - self.reserved_stock
+    }
+}
+
+/// Stok satu SKU di satu gudang.
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct WarehouseStockLevel {
+    #[schema(example = 1)]
+    pub warehouse_id: i64,
+    #[schema(example = "JKT")]
+    pub warehouse_code: String,
+    #[schema(example = 4)]
+    pub variant_id: Option<i64>,
+    #[schema(example = 7)]
+    pub stock: i32,
+    #[schema(example = 1)]
+    pub reserved_stock: i32,
+    #[schema(example = 6)]
+    pub available_stock: i64,
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct VariantStock {
+    #[schema(example = 4)]
+    pub variant_id: i64,
+    #[schema(example = "ABU-PMX-70M")]
+    pub sku: String,
+    #[schema(example = 12)]
+    pub stock: i32,
+    #[schema(example = 2)]
+    pub reserved_stock: i32,
+    #[schema(example = 10)]
+    pub available_stock: i64,
+}
+
+/// Ringkasan stok produk (total semua gudang); produk bervarian dihitung per varian.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProductStock {
+    #[schema(example = 1)]
+    pub product_id: i64,
+    #[schema(example = 5)]
+    pub stock: i32,
+    #[schema(example = 1)]
+    pub reserved_stock: i32,
+    #[schema(example = 4)]
+    pub available_stock: i32,
+    pub variants: Vec<VariantStock>,
+    /// Rincian per gudang per SKU
+    pub warehouses: Vec<WarehouseStockLevel>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "variant_id": 4,
+    "warehouse_id": 2,
+    "quantity": 10,
+    "note": "Restock dari supplier"
+}))]
+pub struct StockAdjustmentDto {
+    /// Wajib untuk produk bervarian
+    pub variant_id: Option<i64>,
+    /// Gudang yang stoknya diubah; kosong berarti gudang utama (prioritas tertinggi)
+    pub warehouse_id: Option<i64>,
+    /// Perubahan stok fisik; negatif untuk pengurangan (rusak, hilang, dll.)
+    pub quantity: i32,
+    pub note: String,
+}
+
+impl StockAdjustmentDto {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.quantity == 0 {
+            return Err("quantity tidak boleh 0".to_string());
+        }
+        let note = self.note.trim();
+        if note.is_empty() || note.len() > 255 {
+            return Err("note wajib diisi (maksimal 255 karakter)".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "variant_id": 4,
+    "from_warehouse_id": 1,
+    "to_warehouse_id": 2,
+    "quantity": 5,
+    "note": "Isi ulang stok Surabaya"
+}))]
+pub struct StockTransferDto {
+    /// Wajib untuk produk bervarian
+    pub variant_id: Option<i64>,
+    pub from_warehouse_id: i64,
+    pub to_warehouse_id: i64,
+    pub quantity: i32,
+    pub note: String,
+}
+
+impl StockTransferDto {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.quantity <= 0 {
+            return Err("quantity harus lebih dari 0".to_string());
+        }
+        if self.from_warehouse_id == self.to_warehouse_id {
+            return Err("Gudang asal dan tujuan tidak boleh sama".to_string());
+        }
+        let note = self.note.trim();
+        if note.is_empty() || note.len() > 255 {
+            return Err("note wajib diisi (maksimal 255 karakter)".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Item checkout yang stoknya tidak mencukupi.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StockShortage {
+    #[schema(example = 1)]
+    pub product_id: i64,
+    #[schema(example = 4)]
+    pub variant_id: Option<i64>,
+    #[schema(example = "ABU-PMX-70M")]
+    pub sku: Option<String>,
+    #[schema(example = 3)]
+    pub requested: i32,
+    #[schema(example = 1)]
+    pub available: i32,
+}
+
+/// Kegagalan operasi stok oleh admin (penyesuaian, transfer, purchase order).
+#[derive(Debug)]
+pub enum StockError {
+    NotFound(String),
+    Invalid(String),
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for StockError {
+    fn from(e: sqlx::Error) -> Self {
+        StockError::Database(e)
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "reorder_point": 5,
+    "reorder_quantity": 20
+}))]
+pub struct ReorderSettingsDto {
+    /// Pesan ulang jika stok tersedia + stok dalam pemesanan <= nilai ini; kosong = tidak dipantau
+    pub reorder_point: Option<i32>,
+    /// Jumlah pesanan standar ke supplier
+    pub reorder_quantity: Option<i32>,
+}
+
+impl ReorderSettingsDto {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.reorder_point.is_some_and(|p| p < 0) {
+            return Err("reorder_point tidak boleh negatif".to_string());
+        }
+        if self.reorder_quantity.is_some_and(|q| q <= 0) {
+            return Err("reorder_quantity harus lebih dari 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// SKU yang stoknya sudah di bawah titik pemesanan ulang.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct LowStockItem {
+    #[schema(example = 1)]
+    pub product_id: i64,
+    #[schema(example = "Abu Garcia Pro Max")]
+    pub product_name: String,
+    #[schema(example = 4)]
+    pub variant_id: Option<i64>,
+    #[schema(example = "ABU-PMX-70M")]
+    pub sku: Option<String>,
+    #[schema(example = 3)]
+    pub available_stock: i64,
+    /// Jumlah di purchase order yang sudah dipesan tapi belum diterima
+    #[schema(example = 0)]
+    pub on_order: i64,
+    #[schema(example = 5)]
+    pub reorder_point: i32,
+    pub reorder_quantity: Option<i32>,
+    /// Saran jumlah pesanan: `reorder_quantity`, atau cukup untuk melewati titik pemesanan ulang
+    #[sqlx(skip)]
+    #[schema(example = 20)]
+    pub suggested_quantity: i64,
+}
+
+impl LowStockItem {
+    pub fn with_suggestion(mut self) -> Self {
+        let shortfall = i64::from(self.reorder_point) - (self.available_stock + self.on_order) + 1;
+        self.suggested_quantity = i64::from(self.reorder_quantity.unwrap_or(0)).max(shortfall);
+        self
+    }
+}
+
+/// Laporan stok menipis yang dibuat job harian.
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct LowStockReport {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(value_type = String, example = "2026-10-19")]
+    pub report_date: NaiveDate,
+    #[schema(example = 2)]
+    pub item_count: i32,
+    #[schema(value_type = Vec<LowStockItem>)]
+    pub items: Json<Vec<LowStockItem>>,
+    pub generated_at: NaiveDateTime,
+}
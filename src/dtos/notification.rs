@@ -0,0 +1,84 @@
+use crate::dtos::pagination::PaginationMeta;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use sqlx::types::Json;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NotificationType {
+    /// Pertanyaan produk yang diajukan user sudah dijawab
+    QuestionAnswered,
+    /// Harga efektif produk di wishlist turun
+    PriceDropped,
+    /// Produk di wishlist yang sempat habis tersedia lagi
+    BackInStock,
+}
+
+impl NotificationType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NotificationType::QuestionAnswered => "QUESTION_ANSWERED",
+            NotificationType::PriceDropped => "PRICE_DROPPED",
+            NotificationType::BackInStock => "BACK_IN_STOCK",
+        }
+    }
+}
+
+impl TryFrom<String> for NotificationType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "QUESTION_ANSWERED" => Ok(NotificationType::QuestionAnswered),
+            "PRICE_DROPPED" => Ok(NotificationType::PriceDropped),
+            "BACK_IN_STOCK" => Ok(NotificationType::BackInStock),
+            other => Err(format!("Tipe notifikasi tidak dikenal: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct Notification {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[sqlx(rename = "type", try_from = "String")]
+    #[serde(rename = "type")]
+    pub kind: NotificationType,
+    #[schema(example = "Pertanyaan Anda sudah dijawab")]
+    pub title: String,
+    pub message: String,
+    /// Data tambahan untuk membuka halaman terkait, mis. `{"product_id": 1, "product_slug": "..."}`
+    #[schema(value_type = Option<Object>)]
+    pub data: Option<Json<Value>>,
+    /// Kosong = belum dibaca
+    pub read_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+/// Notifikasi baru yang akan disimpan.
+pub struct NewNotification {
+    pub user_id: i64,
+    pub kind: NotificationType,
+    pub title: String,
+    pub message: String,
+    pub data: Option<Value>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationPage {
+    #[schema(example = 3)]
+    pub unread_count: i64,
+    pub data: Vec<Notification>,
+    pub pagination: PaginationMeta,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct NotificationParams {
+    /// Hanya notifikasi yang belum dibaca
+    #[serde(default)]
+    pub unread_only: bool,
+}
@@ -1,7 +1,8 @@
+use crate::dtos::inventory::StockShortage;
+use crate::utils::money::Money;
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use sqlx::types::BigDecimal;
 use utoipa::ToSchema;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -10,29 +11,99 @@ pub struct OrderItem {
     pub product_id: i64,
     #[schema(example = 2)]
     pub quantity: i32,
+    /// Wajib diisi jika produk punya varian aktif (lihat `/products/{id}/variants`)
+    #[serde(default)]
+    #[schema(example = 4)]
+    pub variant_id: Option<i64>,
+    /// Isi untuk membeli dengan harga flash sale (lihat `/flash-sales`)
+    #[serde(default)]
+    #[schema(example = json!(null))]
+    pub flash_sale_id: Option<i64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[schema(example = json!({
     "items": [
-        {"product_id": 1, "quantity": 2},
-        {"product_id": 3, "quantity": 1}
+        {"product_id": 1, "quantity": 2, "variant_id": 4},
+        {"product_id": 3, "quantity": 1, "flash_sale_id": 7}
     ],
-    "shipping_address": "Jl. Sudirman No. 123, Jakarta",
+    "address_id": 1,
     "payment_method": "midtrans"
 }))]
 pub struct NewOrderDto {
     pub items: Vec<OrderItem>,
-    pub shipping_address: String,
+    /// Id alamat dari buku alamat user (`/user/addresses`). Diutamakan jika diisi.
+    pub address_id: Option<i64>,
+    /// Alamat teks bebas, hanya dipakai jika `address_id` kosong.
+    pub shipping_address: Option<String>,
     pub payment_method: String,
 }
 
+/// Alamat pengiriman final yang disimpan di order.
+/// `snapshot` berisi JSON alamat terstruktur saat checkout (jika memakai `address_id`).
+#[derive(Debug)]
+pub struct ShippingDetails {
+    pub address_id: Option<i64>,
+    pub text: String,
+    pub snapshot: Option<String>,
+    /// Provinsi & kota tujuan untuk memilih gudang terdekat (kosong untuk alamat teks bebas)
+    pub province: Option<String>,
+    pub city: Option<String>,
+}
+
+/// Kegagalan saat membuat order, dibedakan agar handler bisa memilih status HTTP.
+#[derive(Debug)]
+pub enum CheckoutError {
+    /// Produk/varian tidak valid atau kuantitas salah (400)
+    InvalidItem(String),
+    /// Stok tidak mencukupi, dengan rincian per item (409)
+    OutOfStock(Vec<StockShortage>),
+    /// Flash sale belum/sudah tidak berlangsung, kuota habis, atau batas pelanggan terlampaui (409)
+    FlashSaleUnavailable(String),
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for CheckoutError {
+    fn from(e: sqlx::Error) -> Self {
+        CheckoutError::Database(e)
+    }
+}
+
+/// Hasil pembaruan status pembayaran order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PaymentUpdate {
+    /// Order PENDING menjadi PAID atau FAILED
+    Updated,
+    /// Pembayaran masuk setelah order kedaluwarsa; stok dan kuota flash sale berhasil diambil
+    /// lagi sehingga order menjadi PAID
+    LatePaid,
+    /// Pembayaran masuk setelah order kedaluwarsa tapi stok/kuota sudah habis; order ditandai
+    /// perlu refund
+    RefundRequired,
+    /// Order tidak ada atau sudah diproses
+    Unchanged,
+}
+
+/// Order yang dibayar setelah kedaluwarsa tanpa stok/kuota tersisa; dananya harus dikembalikan.
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct RefundRequiredOrder {
+    #[schema(example = 1)]
+    pub order_id: i64,
+    #[schema(example = 1)]
+    pub user_id: i64,
+    #[schema(example = "budi@example.com")]
+    pub email: String,
+    pub total_amount: Money,
+    pub order_date: NaiveDateTime,
+    pub refund_required_at: NaiveDateTime,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, FromRow)]
 pub struct Order {
     pub id: i64,
     pub user_id: i64,
-    pub total_amount: BigDecimal,
+    pub total_amount: Money,
     pub shipping_address: String,
     pub payment_method: String,
     pub status: String,
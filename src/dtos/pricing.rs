@@ -0,0 +1,153 @@
+use crate::utils::money::Money;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SaleStatus {
+    /// Belum dimulai
+    Scheduled,
+    /// Sedang berlaku
+    Active,
+    /// Sudah lewat (atau dihentikan admin)
+    Ended,
+}
+
+impl TryFrom<String> for SaleStatus {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "SCHEDULED" => Ok(SaleStatus::Scheduled),
+            "ACTIVE" => Ok(SaleStatus::Active),
+            "ENDED" => Ok(SaleStatus::Ended),
+            other => Err(format!("Status sale tidak dikenal: {}", other)),
+        }
+    }
+}
+
+/// Jadwal harga sale untuk produk atau salah satu variannya.
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct ProductSale {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = 1)]
+    pub product_id: i64,
+    /// Kosong = berlaku untuk produk (dan varian yang tidak punya harga sendiri)
+    #[schema(example = 4)]
+    pub variant_id: Option<i64>,
+    #[schema(example = "ABU-PMX-70M")]
+    pub sku: Option<String>,
+    pub sale_price: Money,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+    #[sqlx(try_from = "String")]
+    pub status: SaleStatus,
+    pub created_by: Option<i64>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "variant_id": null,
+    "sale_price": 1250000,
+    "starts_at": "2026-10-24T00:00:00",
+    "ends_at": "2026-10-26T00:00:00"
+}))]
+pub struct NewProductSaleDto {
+    /// Isi untuk sale satu varian saja
+    pub variant_id: Option<i64>,
+    /// Harus lebih rendah dari harga normal target saat sale dibuat
+    pub sale_price: Money,
+    /// Waktu server (zona waktu database)
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+}
+
+impl NewProductSaleDto {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.sale_price.is_positive() {
+            return Err("Harga sale harus lebih dari 0".to_string());
+        }
+        if self.ends_at <= self.starts_at {
+            return Err("ends_at harus setelah starts_at".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PriceChangeType {
+    /// Harga normal diubah (atau diisi pertama kali)
+    Price,
+    /// Sale dijadwalkan
+    SaleScheduled,
+    /// Sale yang sedang berlaku dihentikan lebih awal
+    SaleEnded,
+    /// Sale yang belum dimulai dibatalkan
+    SaleCancelled,
+}
+
+impl PriceChangeType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PriceChangeType::Price => "PRICE",
+            PriceChangeType::SaleScheduled => "SALE_SCHEDULED",
+            PriceChangeType::SaleEnded => "SALE_ENDED",
+            PriceChangeType::SaleCancelled => "SALE_CANCELLED",
+        }
+    }
+}
+
+impl TryFrom<String> for PriceChangeType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "PRICE" => Ok(PriceChangeType::Price),
+            "SALE_SCHEDULED" => Ok(PriceChangeType::SaleScheduled),
+            "SALE_ENDED" => Ok(PriceChangeType::SaleEnded),
+            "SALE_CANCELLED" => Ok(PriceChangeType::SaleCancelled),
+            other => Err(format!("Jenis perubahan harga tidak dikenal: {}", other)),
+        }
+    }
+}
+
+/// Satu baris riwayat harga.
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct PriceHistoryEntry {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = 1)]
+    pub product_id: i64,
+    pub variant_id: Option<i64>,
+    pub sku: Option<String>,
+    #[sqlx(try_from = "String")]
+    pub change_type: PriceChangeType,
+    /// Harga normal sebelumnya (untuk sale yang dijadwalkan: harga normal saat itu)
+    pub old_price: Option<Money>,
+    /// Harga normal baru, atau harga sale
+    pub new_price: Option<Money>,
+    pub sale_id: Option<i64>,
+    pub sale_starts_at: Option<NaiveDateTime>,
+    pub sale_ends_at: Option<NaiveDateTime>,
+    pub changed_by: Option<i64>,
+    pub changed_at: NaiveDateTime,
+}
+
+/// Kegagalan saat mengatur jadwal harga.
+#[derive(Debug)]
+pub enum PricingError {
+    NotFound(String),
+    Invalid(String),
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for PricingError {
+    fn from(e: sqlx::Error) -> Self {
+        PricingError::Database(e)
+    }
+}
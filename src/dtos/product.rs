@@ -1,10 +1,22 @@
+use crate::dtos::pagination::PaginationMeta;
+use crate::dtos::product_image::ProductImage;
+use crate::dtos::variant::{ProductVariant, collect_variant_options};
+use crate::dtos::warehouse::WarehouseAvailability;
+use crate::utils::money::Money;
+use crate::utils::rod_specs::display_specs;
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use sqlx::FromRow;
-use utoipa::ToSchema;
+use sqlx::types::Json;
+use std::collections::BTreeMap;
+use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[schema(example = json!({
+    "sku": "ABU-PMX-70",
     "name": "Abu Garcia Pro Max Casting Rod",
+    "slug": null,
     "description": "7ft medium power casting rod for bass fishing",
     "category_id": 1,
     "rod_length": "7ft",
@@ -14,11 +26,25 @@ use utoipa::ToSchema;
     "material": "Carbon Fiber",
     "power": "Medium",
     "reel_size": null,
-    "price": 1500000.0,
-    "image_url": "https://example.com/rod.jpg"
+    "price": 1500000,
+    "image_url": "https://example.com/rod.jpg",
+    "attributes": {},
+    "meta_title": null,
+    "meta_description": null
 }))]
+/// `rod_length`, `line_weight`, dan `cast_weight` divalidasi dan di-parse ke angka
+/// (lihat `utils::rod_specs`); format yang tidak dikenali ditolak dengan 400.
+/// Produk non-joran (reel, senar, umpan, ...) mengisi `attributes` sesuai skema kategorinya.
 pub struct NewRodProductDto {
+    /// SKU produk (unik), kunci upsert impor massal. Kosong saat create = `P<id>`,
+    /// kosong saat update = tidak diubah.
+    #[serde(default)]
+    pub sku: Option<String>,
     pub name: String,
+    /// Slug URL (unik). Kosong = dibuat dari nama; saat update slug lama dipertahankan,
+    /// kecuali nama berubah. Slug lama disimpan sebagai redirect.
+    #[serde(default)]
+    pub slug: Option<String>,
     pub description: String,
     pub category_id: i32,
     pub rod_length: Option<String>,
@@ -28,8 +54,18 @@ pub struct NewRodProductDto {
     pub material: Option<String>,
     pub power: Option<String>,
     pub reel_size: Option<String>,
-    pub price: f64,
+    pub price: Money,
     pub image_url: Option<String>,
+    /// Nilai atribut sesuai skema kategori, mis. `{"gear_ratio": 6.2, "hand": "left"}`
+    #[serde(default)]
+    #[schema(value_type = Option<Object>)]
+    pub attributes: Option<Map<String, Value>>,
+    /// Judul halaman untuk SEO; kosong = pakai nama produk
+    #[serde(default)]
+    pub meta_title: Option<String>,
+    /// Deskripsi halaman untuk SEO (meta description)
+    #[serde(default)]
+    pub meta_description: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
@@ -38,6 +74,9 @@ pub struct RodProductDetail {
     pub id: i64,
     #[schema(example = "Abu Garcia Pro Max")]
     pub name: String,
+    /// Slug yang berlaku (canonical), mis. `/products/slug/abu-garcia-pro-max`
+    #[schema(example = "abu-garcia-pro-max")]
+    pub slug: String,
     pub description: String,
     #[schema(example = "Joran Casting")]
     pub category_name: String,
@@ -48,9 +87,96 @@ pub struct RodProductDetail {
     pub material: Option<String>,
     pub power: Option<String>,
     pub reel_size: Option<String>,
-    #[schema(example = 1500000.0)]
-    pub price: f64,
+    /// Harga normal
+    pub price: Money,
+    /// Harga yang berlaku sekarang: harga sale jika ada sale aktif, selain itu harga normal
+    pub final_price: Money,
+    /// Akhir sale yang sedang berlaku; kosong jika tidak sedang sale
+    pub sale_ends_at: Option<NaiveDateTime>,
     pub image_url: Option<String>,
+    /// Nilai numerik hasil parsing spesifikasi (cm, lb, gram)
+    #[schema(example = 213.36)]
+    pub rod_length_cm: Option<f64>,
+    #[schema(example = 10.0)]
+    pub line_min_lb: Option<f64>,
+    #[schema(example = 20.0)]
+    pub line_max_lb: Option<f64>,
+    #[schema(example = 7.09)]
+    pub cast_min_g: Option<f64>,
+    #[schema(example = 21.26)]
+    pub cast_max_g: Option<f64>,
+    /// Atribut sesuai skema kategori (untuk produk selain joran)
+    #[schema(value_type = Option<Object>)]
+    pub attributes: Option<Json<Map<String, Value>>>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    /// Rata-rata bintang dari ulasan yang disetujui; kosong jika belum ada ulasan
+    #[schema(example = 4.6)]
+    pub rating_average: Option<f64>,
+    #[schema(example = 18)]
+    pub rating_count: i32,
+    /// Stok fisik produk tanpa varian (produk bervarian: lihat `variants`)
+    #[schema(example = 5)]
+    pub stock: i32,
+    /// Stok yang masih bisa dibeli setelah dikurangi reservasi order PENDING
+    #[schema(example = 4)]
+    pub available_stock: i64,
+    /// Spesifikasi dalam satuan imperial dan metrik, diisi dari kolom numerik
+    #[sqlx(skip)]
+    pub specs: RodSpecsDisplay,
+    /// Varian aktif (SKU, opsi, harga, stok); kosong untuk produk tanpa varian
+    #[sqlx(skip)]
+    pub variants: Vec<ProductVariant>,
+    /// Daftar nilai per opsi varian, mis. `{"length": ["6ft", "7ft"]}`
+    #[sqlx(skip)]
+    pub variant_options: BTreeMap<String, Vec<String>>,
+    /// Stok yang bisa dibeli per gudang (per varian untuk produk bervarian)
+    #[sqlx(skip)]
+    pub availability: Vec<WarehouseAvailability>,
+    /// Galeri gambar sesuai urutan tampil
+    #[sqlx(skip)]
+    pub images: Vec<ProductImage>,
+}
+
+impl RodProductDetail {
+    pub fn with_spec_display(mut self) -> Self {
+        self.specs = display_specs(
+            self.rod_length_cm,
+            (self.line_min_lb, self.line_max_lb),
+            (self.cast_min_g, self.cast_max_g),
+        );
+        self
+    }
+
+    pub fn with_variants(mut self, variants: Vec<ProductVariant>) -> Self {
+        self.variant_options = collect_variant_options(&variants);
+        self.variants = variants;
+        self
+    }
+}
+
+/// Teks spesifikasi produk yang kolom numeriknya belum terisi.
+#[derive(Debug, FromRow)]
+pub struct ProductSpecText {
+    pub id: i64,
+    pub rod_length: Option<String>,
+    pub line_weight: Option<String>,
+    pub cast_weight: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SpecDisplay {
+    #[schema(example = "7'0\"")]
+    pub imperial: String,
+    #[schema(example = "2.13 m")]
+    pub metric: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct RodSpecsDisplay {
+    pub length: Option<SpecDisplay>,
+    pub line_weight: Option<SpecDisplay>,
+    pub cast_weight: Option<SpecDisplay>,
 }
 
 #[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
@@ -58,6 +184,8 @@ pub struct RodProduct {
     #[schema(example = 1)]
     pub id: i64,
     pub name: String,
+    #[schema(example = "abu-garcia-pro-max")]
+    pub slug: String,
     pub description: String,
     #[schema(example = 1)]
     pub category_id: i32,
@@ -68,7 +196,142 @@ pub struct RodProduct {
     pub material: Option<String>,
     pub power: Option<String>,
     pub reel_size: Option<String>,
-    #[schema(example = 1500000.0)]
-    pub price: f64,
+    /// Harga normal
+    pub price: Money,
+    /// Harga yang berlaku sekarang: harga sale jika ada sale aktif, selain itu harga normal
+    pub final_price: Money,
+    /// Akhir sale yang sedang berlaku; kosong jika tidak sedang sale
+    pub sale_ends_at: Option<NaiveDateTime>,
+    pub image_url: Option<String>,
+    #[schema(value_type = Option<Object>)]
+    pub attributes: Option<Json<Map<String, Value>>>,
+    /// Rata-rata bintang dari ulasan yang disetujui; kosong jika belum ada ulasan
+    #[schema(example = 4.6)]
+    pub rating_average: Option<f64>,
+    #[schema(example = 18)]
+    pub rating_count: i32,
+}
+
+/// Urutan listing produk (`?sort=`).
+#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProductSort {
+    #[default]
+    Newest,
+    PriceAsc,
+    PriceDesc,
+    Name,
+    /// Jumlah unit terjual pada order yang sudah dibayar
+    Popularity,
+    /// Rata-rata rating ulasan (produk tanpa ulasan di akhir), lalu jumlah ulasan
+    Rating,
+}
+
+/// Slug lama: klien diarahkan ke slug yang berlaku.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProductSlugRedirect {
+    #[schema(example = 1)]
+    pub product_id: i64,
+    /// Slug yang berlaku (canonical)
+    #[schema(example = "abu-garcia-pro-max-casting-rod")]
+    pub slug: String,
+}
+
+/// Filter opsional untuk `GET /products`, dipakai bersama `PaginationParams`.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ProductFilterParams {
+    /// Cari di nama dan deskripsi produk
+    #[param(example = "casting")]
+    pub q: Option<String>,
+    #[param(example = 1)]
+    pub category_id: Option<i32>,
+    #[param(example = 500000)]
+    pub min_price: Option<Money>,
+    #[param(example = 2000000)]
+    pub max_price: Option<Money>,
+    #[param(example = "Fast")]
+    pub action: Option<String>,
+    #[param(example = "Medium")]
+    pub power: Option<String>,
+    #[param(example = "Carbon Fiber")]
+    pub material: Option<String>,
+    /// Panjang joran minimum dalam feet
+    #[param(example = 6.0)]
+    pub min_length: Option<f64>,
+    /// Panjang joran maksimum dalam feet
+    #[param(example = 8.0)]
+    pub max_length: Option<f64>,
+    #[param(inline)]
+    pub sort: Option<ProductSort>,
+}
+
+impl ProductFilterParams {
+    pub fn validate(&self) -> Result<(), String> {
+        if let (Some(min), Some(max)) = (self.min_price, self.max_price)
+            && min > max
+        {
+            return Err("min_price tidak boleh lebih besar dari max_price".to_string());
+        }
+        if let (Some(min), Some(max)) = (self.min_length, self.max_length)
+            && min > max
+        {
+            return Err("min_length tidak boleh lebih besar dari max_length".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ProductSearchParams {
+    /// Kata kunci pencarian
+    #[param(example = "shimano joran casting")]
+    pub q: String,
+}
+
+/// Baris mentah hasil query pencarian sebelum di-highlight.
+#[derive(Debug, FromRow)]
+pub struct ProductSearchRow {
+    pub id: i64,
+    pub name: String,
+    pub slug: String,
+    pub description: String,
+    pub category_name: String,
+    pub price: Money,
+    pub final_price: Money,
     pub image_url: Option<String>,
+    pub score: f64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProductSearchHit {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = "Shimano <mark>Joran</mark> Casting")]
+    pub name: String,
+    #[schema(example = "shimano-joran-casting")]
+    pub slug: String,
+    #[schema(example = "Joran Casting")]
+    pub category_name: String,
+    pub price: Money,
+    /// Harga setelah sale yang sedang berlaku
+    pub final_price: Money,
+    pub image_url: Option<String>,
+    /// Skor relevansi; makin besar makin relevan
+    #[schema(example = 3.42)]
+    pub score: f64,
+    /// Potongan deskripsi dengan kata yang cocok dibungkus `<mark>`
+    pub snippet: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProductSearchResponse {
+    #[schema(example = "shimano jorann")]
+    pub query: String,
+    /// Terisi jika query dikoreksi otomatis karena typo
+    #[schema(example = "shimano joran")]
+    pub corrected_query: Option<String>,
+    pub results: Vec<ProductSearchHit>,
+    pub pagination: PaginationMeta,
 }
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ProductImage {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = 1)]
+    pub product_id: i64,
+    /// Urutan tampil; gambar pertama menjadi `image_url` produk
+    #[schema(example = 0)]
+    pub sort_order: i32,
+    #[schema(example = "/uploads/products/1/3f9a0c1d2b4e5f60/original.png")]
+    pub original_url: String,
+    /// Sisi terpanjang maksimal 800px (JPEG)
+    #[schema(example = "/uploads/products/1/3f9a0c1d2b4e5f60/medium.jpg")]
+    pub medium_url: String,
+    /// Sisi terpanjang maksimal 200px (JPEG)
+    #[schema(example = "/uploads/products/1/3f9a0c1d2b4e5f60/thumbnail.jpg")]
+    pub thumbnail_url: String,
+    #[schema(example = 1600)]
+    pub width: Option<i32>,
+    #[schema(example = 1200)]
+    pub height: Option<i32>,
+}
+
+/// Lokasi file di storage; kosong untuk gambar URL eksternal lama.
+#[derive(Debug, FromRow)]
+pub struct StoredImageKeys {
+    pub original_key: Option<String>,
+    pub medium_key: Option<String>,
+    pub thumbnail_key: Option<String>,
+}
+
+impl StoredImageKeys {
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        [&self.original_key, &self.medium_key, &self.thumbnail_key]
+            .into_iter()
+            .filter_map(|k| k.as_deref())
+    }
+}
+
+/// Gambar yang sudah tersimpan di storage, siap dicatat ke database.
+pub struct NewProductImage {
+    pub original_url: String,
+    pub medium_url: String,
+    pub thumbnail_url: String,
+    pub keys: StoredImageKeys,
+    pub width: i32,
+    pub height: i32,
+}
+
+/// Form unggahan (hanya untuk dokumentasi OpenAPI).
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct ImageUploadForm {
+    /// Satu atau lebih file JPEG/PNG/WebP dengan nama field `image`
+    #[schema(value_type = Vec<String>, format = Binary)]
+    pub image: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({ "image_ids": [3, 1, 2] }))]
+pub struct ImageOrderDto {
+    /// Semua ID gambar produk dalam urutan tampil yang baru
+    pub image_ids: Vec<i64>,
+}
+
+impl ImageOrderDto {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.image_ids.is_empty() {
+            return Err("image_ids wajib diisi".to_string());
+        }
+        let mut ids = self.image_ids.clone();
+        ids.sort_unstable();
+        ids.dedup();
+        if ids.len() != self.image_ids.len() {
+            return Err("image_ids tidak boleh duplikat".to_string());
+        }
+        Ok(())
+    }
+}
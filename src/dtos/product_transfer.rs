@@ -0,0 +1,175 @@
+use crate::dtos::product::NewRodProductDto;
+use crate::utils::money::Money;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use utoipa::{IntoParams, ToSchema};
+
+/// Format file impor/ekspor katalog.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+impl TransferFormat {
+    /// Ditebak dari ekstensi file (`.csv` / `.json`), untuk perintah CLI.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let extension = path.rsplit_once('.')?.1.to_ascii_lowercase();
+        match extension.as_str() {
+            "csv" => Some(TransferFormat::Csv),
+            "json" => Some(TransferFormat::Json),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            TransferFormat::Json => "application/json",
+            TransferFormat::Csv => "text/csv; charset=utf-8",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            TransferFormat::Json => "json",
+            TransferFormat::Csv => "csv",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ImportParams {
+    /// `json` (array baris, default) atau `csv` (baris header + satu produk per baris)
+    #[serde(default)]
+    #[param(inline)]
+    pub format: TransferFormat,
+    /// Hanya validasi dan laporan, tanpa menyimpan apa pun
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportParams {
+    #[serde(default)]
+    #[param(inline)]
+    pub format: TransferFormat,
+}
+
+/// Satu produk di file impor/ekspor. Ekspor menghasilkan format yang sama persis,
+/// jadi file ekspor bisa diubah lalu diimpor kembali.
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+#[schema(example = json!({
+    "sku": "ABU-PMX-70",
+    "name": "Abu Garcia Pro Max Casting Rod",
+    "description": "7ft medium power casting rod for bass fishing",
+    "category_id": 1,
+    "rod_length": "7ft",
+    "line_weight": "10-20lb",
+    "cast_weight": "1/4-3/4oz",
+    "action": "Fast",
+    "material": "Carbon Fiber",
+    "power": "Medium",
+    "reel_size": null,
+    "price": 1500000,
+    "image_url": null,
+    "attributes": null,
+    "slug": "abu-garcia-pro-max-casting-rod",
+    "meta_title": null,
+    "meta_description": null
+}))]
+pub struct ProductTransferRow {
+    /// Kunci upsert: SKU yang sudah ada diperbarui, selain itu produk baru dibuat
+    pub sku: String,
+    pub name: String,
+    pub description: String,
+    pub category_id: i32,
+    pub rod_length: Option<String>,
+    pub line_weight: Option<String>,
+    pub cast_weight: Option<String>,
+    pub action: Option<String>,
+    pub material: Option<String>,
+    pub power: Option<String>,
+    pub reel_size: Option<String>,
+    /// Harga normal (harga sale tidak ikut diekspor/diimpor)
+    pub price: Money,
+    pub image_url: Option<String>,
+    /// Di CSV berupa teks JSON, mis. `{"gear_ratio": 6.2}`
+    #[serde(default)]
+    #[schema(value_type = Option<Object>)]
+    pub attributes: Option<Json<Map<String, Value>>>,
+    /// Kosong = slug dibuat dari nama (produk baru) atau tidak diubah
+    #[serde(default)]
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub meta_title: Option<String>,
+    #[serde(default)]
+    pub meta_description: Option<String>,
+}
+
+impl From<ProductTransferRow> for NewRodProductDto {
+    fn from(row: ProductTransferRow) -> Self {
+        NewRodProductDto {
+            sku: Some(row.sku),
+            name: row.name,
+            slug: row.slug,
+            description: row.description,
+            category_id: row.category_id,
+            rod_length: row.rod_length,
+            line_weight: row.line_weight,
+            cast_weight: row.cast_weight,
+            action: row.action,
+            material: row.material,
+            power: row.power,
+            reel_size: row.reel_size,
+            price: row.price,
+            image_url: row.image_url,
+            attributes: row.attributes.map(|a| a.0),
+            meta_title: row.meta_title,
+            meta_description: row.meta_description,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ImportAction {
+    /// Produk baru (SKU belum ada)
+    Create,
+    /// Produk dengan SKU ini diperbarui
+    Update,
+    /// Baris tidak valid, lihat `error`
+    Error,
+}
+
+/// Hasil validasi/penerapan satu baris impor.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportRowResult {
+    /// Nomor baris data, mulai dari 1 (baris header CSV tidak dihitung)
+    #[schema(example = 1)]
+    pub row: usize,
+    #[schema(example = "ABU-PMX-70")]
+    pub sku: Option<String>,
+    pub action: ImportAction,
+    /// Id produk yang diperbarui, atau yang dibuat (setelah impor diterapkan)
+    pub product_id: Option<i64>,
+    pub error: Option<String>,
+}
+
+/// Laporan impor per baris. Impor hanya diterapkan jika semua baris valid.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    /// `true` jika perubahan sudah disimpan
+    pub applied: bool,
+    pub total: usize,
+    pub created: usize,
+    pub updated: usize,
+    pub failed: usize,
+    pub rows: Vec<ImportRowResult>,
+}
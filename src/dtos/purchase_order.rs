@@ -0,0 +1,143 @@
+use crate::utils::money::Money;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PurchaseOrderStatus {
+    /// Masih disusun, belum dikirim ke supplier
+    Draft,
+    /// Sudah dipesan; jumlahnya dihitung sebagai stok dalam pemesanan
+    Ordered,
+    /// Barang sudah diterima dan masuk stok gudang
+    Received,
+    Cancelled,
+}
+
+impl PurchaseOrderStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PurchaseOrderStatus::Draft => "DRAFT",
+            PurchaseOrderStatus::Ordered => "ORDERED",
+            PurchaseOrderStatus::Received => "RECEIVED",
+            PurchaseOrderStatus::Cancelled => "CANCELLED",
+        }
+    }
+}
+
+impl TryFrom<String> for PurchaseOrderStatus {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "DRAFT" => Ok(PurchaseOrderStatus::Draft),
+            "ORDERED" => Ok(PurchaseOrderStatus::Ordered),
+            "RECEIVED" => Ok(PurchaseOrderStatus::Received),
+            "CANCELLED" => Ok(PurchaseOrderStatus::Cancelled),
+            other => Err(format!("Status purchase order tidak dikenal: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct PurchaseOrder {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = 1)]
+    pub supplier_id: i64,
+    #[schema(example = "PT Pancing Nusantara")]
+    pub supplier_name: String,
+    #[schema(example = 1)]
+    pub warehouse_id: i64,
+    #[schema(example = "JKT")]
+    pub warehouse_code: String,
+    #[sqlx(try_from = "String")]
+    pub status: PurchaseOrderStatus,
+    pub note: Option<String>,
+    /// Total nilai pesanan (jumlah x harga beli)
+    pub total_cost: Money,
+    pub created_by: Option<i64>,
+    pub created_at: NaiveDateTime,
+    pub ordered_at: Option<NaiveDateTime>,
+    pub received_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct PurchaseOrderItem {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = 1)]
+    pub product_id: i64,
+    #[schema(example = "Abu Garcia Pro Max")]
+    pub product_name: Option<String>,
+    #[schema(example = 4)]
+    pub variant_id: Option<i64>,
+    #[schema(example = "ABU-PMX-70M")]
+    pub sku: Option<String>,
+    #[schema(example = 10)]
+    pub quantity: i32,
+    pub unit_cost: Money,
+    #[schema(example = 0)]
+    pub received_quantity: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PurchaseOrderDetail {
+    #[serde(flatten)]
+    pub order: PurchaseOrder,
+    pub items: Vec<PurchaseOrderItem>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewPurchaseOrderItem {
+    pub product_id: i64,
+    /// Wajib untuk produk bervarian
+    pub variant_id: Option<i64>,
+    pub quantity: i32,
+    /// Harga beli per unit dari supplier
+    pub unit_cost: Money,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "supplier_id": 1,
+    "warehouse_id": 1,
+    "note": "Restock bulanan",
+    "items": [
+        {"product_id": 1, "variant_id": 4, "quantity": 10, "unit_cost": 900000}
+    ]
+}))]
+pub struct NewPurchaseOrderDto {
+    pub supplier_id: i64,
+    /// Gudang tujuan penerimaan barang
+    pub warehouse_id: i64,
+    pub note: Option<String>,
+    pub items: Vec<NewPurchaseOrderItem>,
+}
+
+impl NewPurchaseOrderDto {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.items.is_empty() {
+            return Err("Purchase order minimal berisi satu item".to_string());
+        }
+        if self.items.iter().any(|i| i.quantity <= 0) {
+            return Err("quantity setiap item harus lebih dari 0".to_string());
+        }
+        if self.items.iter().any(|i| i.unit_cost.is_negative()) {
+            return Err("unit_cost tidak boleh negatif".to_string());
+        }
+        if self.note.as_deref().is_some_and(|n| n.len() > 255) {
+            return Err("note maksimal 255 karakter".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PurchaseOrderListParams {
+    /// Filter status: DRAFT | ORDERED | RECEIVED | CANCELLED
+    pub status: Option<PurchaseOrderStatus>,
+}
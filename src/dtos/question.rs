@@ -0,0 +1,104 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+
+/// Panjang maksimal pertanyaan dan jawaban.
+const MAX_QUESTION_LEN: usize = 1000;
+const MAX_ANSWER_LEN: usize = 3000;
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct ProductAnswer {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[serde(skip)]
+    pub question_id: i64,
+    #[schema(example = "Admin Toko")]
+    pub answerer_name: String,
+    #[schema(example = "Cocok untuk reel ukuran 2500-3000.")]
+    pub answer: String,
+    /// Hanya terlihat di antrean admin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct ProductQuestion {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = 1)]
+    pub product_id: i64,
+    #[schema(example = "Budi")]
+    pub asker_name: String,
+    #[schema(example = "Action-nya fast atau moderate? Cocok untuk reel 3000?")]
+    pub question: String,
+    /// Hanya terlihat di antrean admin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    #[sqlx(skip)]
+    pub answers: Vec<ProductAnswer>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({ "question": "Action-nya fast atau moderate? Cocok untuk reel 3000?" }))]
+pub struct NewQuestionDto {
+    pub question: String,
+}
+
+impl NewQuestionDto {
+    pub fn validate(&self) -> Result<(), String> {
+        let len = self.question.trim().chars().count();
+        if !(5..=MAX_QUESTION_LEN).contains(&len) {
+            return Err(format!("Pertanyaan harus 5-{} karakter", MAX_QUESTION_LEN));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({ "answer": "Action fast, cocok untuk reel ukuran 2500-3000." }))]
+pub struct NewAnswerDto {
+    pub answer: String,
+}
+
+impl NewAnswerDto {
+    pub fn validate(&self) -> Result<(), String> {
+        let len = self.answer.trim().chars().count();
+        if len == 0 || len > MAX_ANSWER_LEN {
+            return Err(format!(
+                "Jawaban wajib diisi (maksimal {} karakter)",
+                MAX_ANSWER_LEN
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({ "hidden": true }))]
+pub struct ModerateQnaDto {
+    /// `true` = sembunyikan dari publik, `false` = tampilkan lagi
+    pub hidden: bool,
+}
+
+/// Filter antrean tanya jawab admin.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum QuestionQueueFilter {
+    /// Pertanyaan tampil yang belum punya jawaban tampil (default)
+    #[default]
+    Unanswered,
+    Answered,
+    Hidden,
+    All,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QuestionQueueParams {
+    #[serde(default)]
+    #[param(inline)]
+    pub filter: QuestionQueueFilter,
+}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+/// Satu wilayah (provinsi, kabupaten/kota, kecamatan, atau kelurahan/desa).
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct RegionDto {
+    #[schema(example = "3171")]
+    pub id: String,
+    #[schema(example = "Kota Jakarta Selatan")]
+    pub name: String,
+}
@@ -0,0 +1,206 @@
+use crate::dtos::pagination::PaginationMeta;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+
+/// Panjang maksimal judul dan isi ulasan.
+const MAX_TITLE_LEN: usize = 150;
+const MAX_BODY_LEN: usize = 5000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ReviewStatus {
+    /// Menunggu moderasi admin, belum tampil
+    Pending,
+    /// Tampil di halaman produk dan dihitung di rating
+    Approved,
+    Rejected,
+}
+
+impl ReviewStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ReviewStatus::Pending => "PENDING",
+            ReviewStatus::Approved => "APPROVED",
+            ReviewStatus::Rejected => "REJECTED",
+        }
+    }
+}
+
+impl TryFrom<String> for ReviewStatus {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "PENDING" => Ok(ReviewStatus::Pending),
+            "APPROVED" => Ok(ReviewStatus::Approved),
+            "REJECTED" => Ok(ReviewStatus::Rejected),
+            other => Err(format!("Status ulasan tidak dikenal: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct ReviewPhoto {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[serde(skip)]
+    pub review_id: i64,
+    #[schema(example = "/uploads/reviews/1/3f9a0c1d2b4e5f60/original.jpg")]
+    pub original_url: String,
+    #[schema(example = "/uploads/reviews/1/3f9a0c1d2b4e5f60/medium.jpg")]
+    pub medium_url: String,
+    #[schema(example = "/uploads/reviews/1/3f9a0c1d2b4e5f60/thumbnail.jpg")]
+    pub thumbnail_url: String,
+}
+
+/// Ulasan yang tampil di halaman produk.
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct ProductReview {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = 1)]
+    pub product_id: i64,
+    #[schema(example = "Budi")]
+    pub reviewer_name: String,
+    /// Bintang 1-5
+    #[schema(example = 5)]
+    pub rating: i32,
+    #[schema(example = "Ringan dan sensitif")]
+    pub title: Option<String>,
+    #[schema(example = "Dipakai casting di waduk, getaran strike terasa jelas.")]
+    pub body: String,
+    pub created_at: NaiveDateTime,
+    #[sqlx(skip)]
+    pub photos: Vec<ReviewPhoto>,
+}
+
+/// Ulasan di antrean moderasi admin.
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct ReviewForModeration {
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub review: ProductReview,
+    #[schema(example = "Abu Garcia Pro Max")]
+    pub product_name: String,
+    #[schema(example = "budi@example.com")]
+    pub reviewer_email: String,
+    #[sqlx(try_from = "String")]
+    pub status: ReviewStatus,
+    pub moderation_note: Option<String>,
+    pub moderated_at: Option<NaiveDateTime>,
+}
+
+/// Ulasan milik user di arsip `/user/export`, termasuk yang belum/tidak disetujui.
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct ExportedReview {
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub review: ProductReview,
+    #[schema(example = "Abu Garcia Pro Max")]
+    pub product_name: String,
+    #[sqlx(try_from = "String")]
+    pub status: ReviewStatus,
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct RatingBucket {
+    #[schema(example = 5)]
+    pub rating: i32,
+    #[schema(example = 12)]
+    pub count: i64,
+}
+
+/// Ringkasan rating dari ulasan yang disetujui.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RatingSummary {
+    /// Rata-rata bintang (2 desimal); kosong jika belum ada ulasan
+    #[schema(example = 4.6)]
+    pub average: Option<f64>,
+    #[schema(example = 18)]
+    pub count: i64,
+    /// Jumlah ulasan per bintang, 5 sampai 1
+    pub distribution: Vec<RatingBucket>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProductReviewPage {
+    pub summary: RatingSummary,
+    pub data: Vec<ProductReview>,
+    pub pagination: PaginationMeta,
+}
+
+/// Isi ulasan baru setelah dibaca dari form multipart.
+#[derive(Debug, Default)]
+pub struct NewReview {
+    pub rating: i32,
+    pub title: Option<String>,
+    pub body: String,
+}
+
+impl NewReview {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(1..=5).contains(&self.rating) {
+            return Err("rating harus antara 1 dan 5".to_string());
+        }
+        if self
+            .title
+            .as_ref()
+            .is_some_and(|t| t.chars().count() > MAX_TITLE_LEN)
+        {
+            return Err(format!("Judul ulasan maksimal {} karakter", MAX_TITLE_LEN));
+        }
+        let body_len = self.body.trim().chars().count();
+        if body_len == 0 || body_len > MAX_BODY_LEN {
+            return Err(format!(
+                "Isi ulasan wajib diisi (maksimal {} karakter)",
+                MAX_BODY_LEN
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Form ulasan (hanya untuk dokumentasi OpenAPI).
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct NewReviewForm {
+    /// Bintang 1-5
+    #[schema(example = 5)]
+    pub rating: i32,
+    pub title: Option<String>,
+    pub body: String,
+    /// Foto opsional (JPEG/PNG/WebP) dengan nama field `photo`, maksimal 5
+    #[schema(value_type = Vec<String>, format = Binary)]
+    pub photo: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({ "status": "APPROVED", "note": null }))]
+pub struct ModerateReviewDto {
+    /// `APPROVED` atau `REJECTED`
+    pub status: ReviewStatus,
+    /// Alasan penolakan (tidak ditampilkan ke publik)
+    pub note: Option<String>,
+}
+
+impl ModerateReviewDto {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.status == ReviewStatus::Pending {
+            return Err("status harus APPROVED atau REJECTED".to_string());
+        }
+        if self.note.as_ref().is_some_and(|n| n.chars().count() > 500) {
+            return Err("Catatan moderasi maksimal 500 karakter".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReviewQueueParams {
+    /// Default `PENDING`
+    #[param(inline)]
+    pub status: Option<ReviewStatus>,
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SuggestParams {
+    /// Awalan yang sedang diketik user
+    #[param(example = "shim")]
+    pub q: String,
+    /// Jumlah saran maksimum per kelompok (default 5, maksimum 10)
+    #[param(example = 5)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Suggestion {
+    #[schema(example = "Shimano Joran Casting")]
+    pub text: String,
+    /// ID produk/kategori; kosong untuk saran query populer
+    #[schema(example = 1)]
+    pub id: Option<i64>,
+}
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct SuggestResponse {
+    pub products: Vec<Suggestion>,
+    pub categories: Vec<Suggestion>,
+    pub queries: Vec<Suggestion>,
+}
+
+/// Sumber data untuk membangun index saran (produk atau kategori).
+#[derive(Debug, FromRow)]
+pub struct SuggestSource {
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Debug, FromRow)]
+pub struct PopularQuery {
+    pub query: String,
+    pub hits: i64,
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct Supplier {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = "PT Pancing Nusantara")]
+    pub name: String,
+    #[schema(example = "Budi")]
+    pub contact_name: Option<String>,
+    #[schema(example = "sales@pancingnusantara.co.id")]
+    pub email: Option<String>,
+    #[schema(example = "021-5550123")]
+    pub phone: Option<String>,
+    pub address: Option<String>,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "name": "PT Pancing Nusantara",
+    "contact_name": "Budi",
+    "email": "sales@pancingnusantara.co.id",
+    "phone": "021-5550123",
+    "address": "Jl. Industri No. 10, Tangerang",
+    "is_active": true
+}))]
+pub struct NewSupplierDto {
+    pub name: String,
+    pub contact_name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub address: Option<String>,
+    #[serde(default = "default_active")]
+    pub is_active: bool,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+impl NewSupplierDto {
+    pub fn validate(&self) -> Result<(), String> {
+        let name = self.name.trim();
+        if name.is_empty() || name.len() > 150 {
+            return Err("name wajib diisi (maksimal 150 karakter)".to_string());
+        }
+        if self
+            .email
+            .as_deref()
+            .is_some_and(|e| !e.is_empty() && !e.contains('@'))
+        {
+            return Err("Format email supplier tidak valid".to_string());
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,13 @@
-use serde::Deserialize;
+use crate::dtos::address::AddressDto;
+use crate::dtos::api_key::ApiKeyDto;
+use crate::dtos::notification::Notification;
+use crate::dtos::question::ProductQuestion;
+use crate::dtos::review::ExportedReview;
+use crate::dtos::wishlist::WishlistItem;
+use crate::utils::money::Money;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
 use utoipa::ToSchema;
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -12,3 +21,81 @@ pub struct UpdateProfile {
     pub email: Option<String>,
     pub password: Option<String>,
 }
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "password": "Password123!"
+}))]
+pub struct DeleteAccountRequest {
+    /// Wajib untuk akun dengan password; akun Google boleh dikosongkan.
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ExportedProfile {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = "John Doe")]
+    pub name: String,
+    #[schema(example = "john@example.com")]
+    pub email: String,
+    #[schema(example = "Jakarta, Indonesia")]
+    pub address: Option<String>,
+    #[schema(example = "user")]
+    pub role: String,
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct ExportedOrderItem {
+    #[schema(example = 1)]
+    pub product_id: i64,
+    #[schema(example = "Abu Garcia Pro Max")]
+    pub product_name: Option<String>,
+    /// SKU varian yang dibeli, jika produk bervarian
+    #[schema(example = "ABU-PMX-70M")]
+    pub sku: Option<String>,
+    /// Gudang yang mengirim item ini
+    #[schema(example = "JKT")]
+    pub warehouse_code: Option<String>,
+    #[schema(example = 2)]
+    pub quantity: i32,
+    pub price_at_order: Money,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ExportedOrder {
+    #[schema(example = 1)]
+    pub id: i64,
+    pub total_amount: Money,
+    pub shipping_address: String,
+    pub payment_method: String,
+    #[schema(example = "PAID")]
+    pub status: String,
+    pub order_date: NaiveDateTime,
+    pub items: Vec<ExportedOrderItem>,
+}
+
+/// Sesi login (refresh token) tanpa nilai token-nya.
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct ExportedSession {
+    #[schema(example = 1)]
+    pub id: i64,
+    pub revoked: bool,
+    pub expires_at: NaiveDateTime,
+}
+
+/// Arsip data pribadi pengguna untuk `/user/export`.
+/// Chatbot tidak menyimpan percakapan, jadi tidak ada data chatbot yang bisa diekspor.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserDataExport {
+    pub exported_at: NaiveDateTime,
+    pub profile: ExportedProfile,
+    pub addresses: Vec<AddressDto>,
+    pub orders: Vec<ExportedOrder>,
+    pub sessions: Vec<ExportedSession>,
+    pub api_keys: Vec<ApiKeyDto>,
+    pub reviews: Vec<ExportedReview>,
+    pub questions: Vec<ProductQuestion>,
+    pub notifications: Vec<Notification>,
+    pub wishlist: Vec<WishlistItem>,
+}
@@ -0,0 +1,112 @@
+use crate::utils::money::Money;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ProductVariant {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = 1)]
+    pub product_id: i64,
+    #[schema(example = "ABU-PMX-70M")]
+    pub sku: String,
+    /// Kombinasi opsi varian, mis. `{"length": "7ft", "power": "Medium"}`
+    #[schema(value_type = BTreeMap<String, String>)]
+    pub options: Json<BTreeMap<String, String>>,
+    /// Harga khusus varian; kosong berarti mengikuti harga produk induk
+    pub price: Option<Money>,
+    /// Harga yang berlaku: sale varian, harga varian, sale produk induk, lalu harga produk induk
+    pub final_price: Money,
+    /// Akhir sale yang menentukan `final_price`; kosong jika tidak sedang sale
+    pub sale_ends_at: Option<NaiveDateTime>,
+    /// Stok fisik
+    #[schema(example = 12)]
+    pub stock: i32,
+    /// Stok yang masih bisa dibeli (stok fisik dikurangi reservasi order PENDING)
+    #[schema(example = 10)]
+    pub available_stock: i64,
+    pub image_url: Option<String>,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "sku": "ABU-PMX-70M",
+    "options": {"length": "7ft", "power": "Medium"},
+    "price": 1650000,
+    "stock": 12,
+    "image_url": null,
+    "is_active": true
+}))]
+pub struct NewVariantDto {
+    pub sku: String,
+    pub options: BTreeMap<String, String>,
+    pub price: Option<Money>,
+    /// Stok awal di gudang utama; diabaikan saat update (gunakan `/products/{id}/stock/adjust`)
+    #[serde(default)]
+    pub stock: i32,
+    pub image_url: Option<String>,
+    #[serde(default = "default_active")]
+    pub is_active: bool,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+/// Aturan SKU yang sama untuk varian dan produk.
+pub(crate) fn validate_sku(sku: &str) -> Result<(), String> {
+    let sku = sku.trim();
+    if sku.is_empty() || sku.len() > 64 {
+        return Err("SKU wajib diisi (maksimal 64 karakter)".to_string());
+    }
+    if !sku
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("SKU hanya boleh huruf, angka, '-' dan '_'".to_string());
+    }
+    Ok(())
+}
+
+impl NewVariantDto {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_sku(&self.sku)?;
+        if self.options.is_empty() {
+            return Err("options varian wajib diisi, mis. {\"length\": \"7ft\"}".to_string());
+        }
+        if self
+            .options
+            .iter()
+            .any(|(k, v)| k.trim().is_empty() || v.trim().is_empty())
+        {
+            return Err("Nama dan nilai opsi varian tidak boleh kosong".to_string());
+        }
+        if self.price.is_some_and(|p| !p.is_positive()) {
+            return Err("Harga varian harus lebih dari 0".to_string());
+        }
+        if self.stock < 0 {
+            return Err("Stok tidak boleh negatif".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Ringkasan pilihan opsi dari varian aktif, untuk selector di halaman produk,
+/// mis. `{"length": ["6ft", "7ft"], "power": ["Medium", "Heavy"]}`.
+pub fn collect_variant_options(variants: &[ProductVariant]) -> BTreeMap<String, Vec<String>> {
+    let mut options: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for variant in variants {
+        for (name, value) in variant.options.iter() {
+            let values = options.entry(name.clone()).or_default();
+            if !values.contains(value) {
+                values.push(value.clone());
+            }
+        }
+    }
+    options
+}
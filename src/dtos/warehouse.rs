@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct Warehouse {
+    #[schema(example = 1)]
+    pub id: i64,
+    #[schema(example = "JKT")]
+    pub code: String,
+    #[schema(example = "Gudang Jakarta")]
+    pub name: String,
+    #[schema(example = "DKI Jakarta")]
+    pub province: String,
+    #[schema(example = "Jakarta Utara")]
+    pub city: String,
+    pub address: Option<String>,
+    /// Urutan prioritas jika jarak ke alamat tujuan sama (kecil = diutamakan)
+    #[schema(example = 0)]
+    pub priority: i32,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "code": "SBY",
+    "name": "Gudang Surabaya",
+    "province": "Jawa Timur",
+    "city": "Surabaya",
+    "address": "Jl. Rungkut Industri No. 5",
+    "priority": 1,
+    "is_active": true
+}))]
+pub struct NewWarehouseDto {
+    pub code: String,
+    pub name: String,
+    pub province: String,
+    pub city: String,
+    pub address: Option<String>,
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default = "default_active")]
+    pub is_active: bool,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+impl NewWarehouseDto {
+    pub fn validate(&self) -> Result<(), String> {
+        let code = self.code.trim();
+        if code.is_empty()
+            || code.len() > 10
+            || !code
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        {
+            return Err("code wajib diisi, huruf besar/angka, maksimal 10 karakter".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return Err("name wajib diisi".to_string());
+        }
+        if self.province.trim().is_empty() || self.city.trim().is_empty() {
+            return Err("province dan city wajib diisi".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Stok yang bisa dibeli di satu gudang, ditampilkan di detail produk.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct WarehouseAvailability {
+    #[schema(example = 1)]
+    pub warehouse_id: i64,
+    #[schema(example = "JKT")]
+    pub warehouse_code: String,
+    #[schema(example = "Jakarta Utara")]
+    pub city: String,
+    /// Kosong untuk produk tanpa varian
+    #[schema(example = 4)]
+    pub variant_id: Option<i64>,
+    #[schema(example = 7)]
+    pub available_stock: i64,
+}
@@ -0,0 +1,61 @@
+use crate::utils::money::Money;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+/// Produk di wishlist user beserta harga dan stok saat ini.
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct WishlistItem {
+    #[schema(example = 1)]
+    pub product_id: i64,
+    #[schema(example = "Abu Garcia Pro Max")]
+    pub name: String,
+    #[schema(example = "abu-garcia-pro-max")]
+    pub slug: String,
+    pub image_url: Option<String>,
+    pub price: Money,
+    /// Harga setelah sale yang sedang berlaku
+    pub final_price: Money,
+    pub sale_ends_at: Option<NaiveDateTime>,
+    /// Ada stok yang bisa dibeli (produk atau salah satu varian aktifnya)
+    pub in_stock: bool,
+    pub added_at: NaiveDateTime,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(example = json!({ "product_id": 1 }))]
+pub struct AddWishlistDto {
+    pub product_id: i64,
+}
+
+/// Jumlah user yang menyimpan satu produk di wishlist (sinyal permintaan untuk admin).
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct WishlistDemand {
+    #[schema(example = 1)]
+    pub product_id: i64,
+    #[schema(example = "Abu Garcia Pro Max")]
+    pub name: String,
+    #[schema(example = "abu-garcia-pro-max")]
+    pub slug: String,
+    pub final_price: Money,
+    pub in_stock: bool,
+    #[schema(example = 42)]
+    pub wishlist_count: i64,
+    /// Ditambahkan dalam 30 hari terakhir
+    #[schema(example = 9)]
+    pub added_last_30_days: i64,
+}
+
+/// Baris wishlist yang harga efektif atau status stoknya berbeda dari yang terakhir diketahui.
+#[derive(Debug, FromRow)]
+pub struct WishlistChange {
+    pub user_id: i64,
+    pub product_id: i64,
+    pub name: String,
+    pub slug: String,
+    pub last_price: Money,
+    pub last_in_stock: bool,
+    pub final_price: Money,
+    pub in_stock: bool,
+}
@@ -0,0 +1,70 @@
+use super::{current_user_id, validate_region};
+use crate::AppState;
+use crate::dtos::address::NewAddressDto;
+use crate::middleware::auth::AuthUser;
+use crate::models::address::AddressModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Add a shipping address
+///
+/// Saves a new structured address to the authenticated user's address book.
+/// The first address automatically becomes the default.
+#[utoipa::path(
+    post,
+    path = "/user/addresses/create",
+    tag = "user",
+    request_body = NewAddressDto,
+    responses(
+        (status = 201, description = "Address created successfully"),
+        (status = 400, description = "Invalid address data"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn create_address(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+    Json(new_address): Json<NewAddressDto>,
+) -> HandlerResult<impl IntoResponse> {
+    new_address
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    validate_region(
+        &state.db,
+        &new_address.province,
+        &new_address.city,
+        &new_address.district,
+    )
+    .await?;
+
+    let user_id = current_user_id(&state.db, &email).await?;
+
+    match AddressModel::insert(&state.db, user_id, new_address).await {
+        Ok(id) => Ok((
+            StatusCode::CREATED,
+            Json(ApiResponse::success_data_with_message(
+                format!("Alamat berhasil ditambahkan dengan ID: {}", id),
+                id,
+            )),
+        )),
+        Err(e) => {
+            eprintln!("Error creating address: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Gagal menambahkan alamat: {}", e),
+            ))
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use super::current_user_id;
+use crate::AppState;
+use crate::middleware::auth::AuthUser;
+use crate::models::address::AddressModel;
+use crate::utils::ApiResponse;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// List shipping addresses
+///
+/// Returns the authenticated user's address book, default address first.
+#[utoipa::path(
+    get,
+    path = "/user/addresses",
+    tag = "user",
+    responses(
+        (status = 200, description = "List of addresses retrieved successfully"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_all_addresses(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+) -> HandlerResult<impl IntoResponse> {
+    let user_id = current_user_id(&state.db, &email).await?;
+
+    match AddressModel::find_by_user(&state.db, user_id).await {
+        Ok(addresses) => Ok(Json(ApiResponse::success_data(
+            "Daftar alamat berhasil diambil",
+            addresses,
+        ))),
+        Err(e) => {
+            eprintln!("Error fetching addresses: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Gagal mengambil daftar alamat: {}", e),
+            ))
+        }
+    }
+}
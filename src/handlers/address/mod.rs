@@ -0,0 +1,49 @@
+pub mod create;
+pub mod delete;
+pub mod get_all;
+pub mod get_by_id;
+pub mod set_default;
+pub mod update;
+
+use crate::models::region::RegionModel;
+use crate::models::user::User;
+use axum::http::StatusCode;
+use sqlx::{MySql, Pool};
+
+/// Cari id user yang sedang login; alamat selalu dibatasi per user.
+pub(crate) async fn current_user_id(
+    pool: &Pool<MySql>,
+    email: &str,
+) -> Result<i64, (StatusCode, String)> {
+    match User::find_id_by_email(pool, email).await {
+        Ok(Some(id)) => Ok(id),
+        Ok(None) => Err((StatusCode::UNAUTHORIZED, "User tidak ditemukan".to_string())),
+        Err(e) => {
+            eprintln!("Database Error: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Terjadi kesalahan internal pada server.".to_string(),
+            ))
+        }
+    }
+}
+
+/// Cocokkan provinsi/kota/kecamatan dengan dataset wilayah; 400 jika tidak dikenal.
+pub(crate) async fn validate_region(
+    pool: &Pool<MySql>,
+    province: &str,
+    city: &str,
+    district: &str,
+) -> Result<(), (StatusCode, String)> {
+    match RegionModel::validate_address(pool, province, city, district).await {
+        Ok(None) => Ok(()),
+        Ok(Some(msg)) => Err((StatusCode::BAD_REQUEST, msg)),
+        Err(e) => {
+            eprintln!("Database Error: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Terjadi kesalahan internal pada server.".to_string(),
+            ))
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use super::current_user_id;
+use crate::AppState;
+use crate::middleware::auth::AuthUser;
+use crate::models::address::AddressModel;
+use crate::utils::ApiResponse;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+pub async fn set_default_address(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+    Path(address_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    let user_id = current_user_id(&state.db, &email).await?;
+
+    match AddressModel::set_default(&state.db, address_id, user_id).await {
+        Ok(rows_affected) if rows_affected > 0 => Ok((
+            StatusCode::OK,
+            Json(ApiResponse::<()>::success("Alamat default berhasil diubah")),
+        )),
+        Ok(_) => Err((StatusCode::NOT_FOUND, "Alamat tidak ditemukan.".to_string())),
+        Err(e) => {
+            eprintln!("Error setting default address: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Gagal mengubah alamat default: {}", e),
+            ))
+        }
+    }
+}
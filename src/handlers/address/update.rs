@@ -0,0 +1,50 @@
+use super::{current_user_id, validate_region};
+use crate::AppState;
+use crate::dtos::address::NewAddressDto;
+use crate::middleware::auth::AuthUser;
+use crate::models::address::AddressModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+pub async fn update_address(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+    Path(address_id): Path<i64>,
+    Json(updated_address): Json<NewAddressDto>,
+) -> HandlerResult<impl IntoResponse> {
+    updated_address
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    validate_region(
+        &state.db,
+        &updated_address.province,
+        &updated_address.city,
+        &updated_address.district,
+    )
+    .await?;
+
+    let user_id = current_user_id(&state.db, &email).await?;
+
+    match AddressModel::update(&state.db, address_id, user_id, updated_address).await {
+        Ok(rows_affected) if rows_affected > 0 => Ok((
+            StatusCode::OK,
+            Json(ApiResponse::<()>::success("Alamat berhasil diperbarui")),
+        )),
+        Ok(_) => Err((StatusCode::NOT_FOUND, "Alamat tidak ditemukan.".to_string())),
+        Err(e) => {
+            eprintln!("Error updating address: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Gagal memperbarui alamat: {}", e),
+            ))
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::AppState;
+use crate::dtos::api_key::{CreatedApiKey, NewApiKeyDto};
+use crate::middleware::auth::AdminAuth;
+use crate::models::api_key::ApiKeyModel;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use crate::utils::api_key::{ALLOWED_SCOPES, generate_api_key, hash_api_key};
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::{Duration as ChronoDuration, Utc};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Create an API key (Admin only)
+///
+/// Issues a scoped API key for service-to-service access.
+/// The plain key is only returned once in this response; only its hash is stored.
+#[utoipa::path(
+    post,
+    path = "/api-keys/create",
+    tag = "api-keys",
+    request_body = NewApiKeyDto,
+    responses(
+        (status = 201, description = "API key created", body = CreatedApiKey),
+        (status = 400, description = "Invalid scopes, owner or expiry"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn create_api_key(
+    State(state): State<Arc<AppState>>,
+    AdminAuth(admin): AdminAuth,
+    Json(payload): Json<NewApiKeyDto>,
+) -> HandlerResult<impl IntoResponse> {
+    if payload.name.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Nama API key wajib diisi".into()));
+    }
+
+    if payload.scopes.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Minimal satu scope wajib diisi".into(),
+        ));
+    }
+
+    if let Some(invalid) = payload
+        .scopes
+        .iter()
+        .find(|s| !ALLOWED_SCOPES.contains(&s.as_str()))
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Scope tidak dikenal: {}", invalid),
+        ));
+    }
+
+    let expires_at = match payload.expires_in_days {
+        Some(days) if days <= 0 => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "expires_in_days harus lebih dari 0".into(),
+            ));
+        }
+        Some(days) => Some(Utc::now().naive_utc() + ChronoDuration::days(days)),
+        None => None,
+    };
+
+    let creator = User::find_by_email(&state.db, &admin.email)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::UNAUTHORIZED, "User tidak ditemukan".to_string()))?;
+
+    let owner_id = match payload.user_email.as_deref() {
+        Some(email) if email != creator.email => {
+            User::find_by_email(&state.db, email)
+                .await
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+                .ok_or((
+                    StatusCode::BAD_REQUEST,
+                    format!("User {} tidak ditemukan", email),
+                ))?
+                .id
+        }
+        _ => creator.id,
+    };
+
+    let (key, prefix) = generate_api_key();
+    let scopes = payload.scopes.join(",");
+
+    let id = ApiKeyModel::insert(
+        &state.db,
+        owner_id,
+        creator.id,
+        payload.name.trim(),
+        &prefix,
+        &hash_api_key(&key),
+        &scopes,
+        expires_at,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Error creating api key: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Gagal membuat API key: {}", e),
+        )
+    })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_data(
+            "API key berhasil dibuat. Simpan key ini, key tidak akan ditampilkan lagi.",
+            CreatedApiKey {
+                id,
+                key,
+                prefix,
+                scopes: payload.scopes,
+                expires_at,
+            },
+        )),
+    ))
+}
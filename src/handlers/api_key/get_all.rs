@@ -0,0 +1,48 @@
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::api_key::ApiKeyModel;
+use crate::utils::ApiResponse;
+use axum::{Json, extract::State, response::IntoResponse};
+use std::sync::Arc;
+
+/// List API keys (Admin only)
+///
+/// Returns all issued API keys with their scopes, expiry and last-used time.
+/// Key hashes are never returned.
+#[utoipa::path(
+    get,
+    path = "/api-keys",
+    tag = "api-keys",
+    responses(
+        (status = 200, description = "List of API keys retrieved successfully"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_all_api_keys(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+) -> impl IntoResponse {
+    match ApiKeyModel::find_all(&state.db).await {
+        Ok(keys) => Json(ApiResponse::success_data(
+            "Daftar API key berhasil diambil",
+            keys,
+        ))
+        .into_response(),
+        Err(e) => {
+            eprintln!("Error fetching api keys: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()> {
+                    status: "error".to_string(),
+                    message: format!("Gagal mengambil daftar API key: {}", e),
+                    data: None,
+                }),
+            )
+                .into_response()
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::api_key::ApiKeyModel;
+use crate::utils::ApiResponse;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Revoke an API key (Admin only)
+///
+/// Requests using a revoked key are rejected immediately.
+#[utoipa::path(
+    delete,
+    path = "/api-keys/{id}/revoke",
+    tag = "api-keys",
+    params(
+        ("id" = i64, Path, description = "API key ID")
+    ),
+    responses(
+        (status = 200, description = "API key revoked"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "API key not found or already revoked"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn revoke_api_key(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(key_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    match ApiKeyModel::revoke(&state.db, key_id).await {
+        Ok(rows_affected) => {
+            if rows_affected > 0 {
+                Ok((
+                    StatusCode::OK,
+                    Json(ApiResponse::<()>::success("API key berhasil dicabut")),
+                ))
+            } else {
+                Err((
+                    StatusCode::NOT_FOUND,
+                    "API key tidak ditemukan atau sudah dicabut.".to_string(),
+                ))
+            }
+        }
+        Err(e) => {
+            eprintln!("Error revoking api key: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Gagal mencabut API key: {}", e),
+            ))
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use super::{HandlerResult, attribute_db_error};
+use crate::AppState;
+use crate::dtos::attribute::NewAttributeDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::attribute::AttributeModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Add an attribute to a category schema (Admin only)
+///
+/// Defines a typed attribute (string, number, boolean or enum) with optional unit,
+/// allowed values and numeric range for products in this category.
+#[utoipa::path(
+    post,
+    path = "/categories/{id}/attributes/create",
+    tag = "categories",
+    params(
+        ("id" = i32, Path, description = "Category ID")
+    ),
+    request_body = NewAttributeDto,
+    responses(
+        (status = 201, description = "Attribute created successfully"),
+        (status = 400, description = "Invalid attribute definition"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Category not found"),
+        (status = 409, description = "Attribute code already exists in this category"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn create_category_attribute(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(category_id): Path<i32>,
+    Json(attribute): Json<NewAttributeDto>,
+) -> HandlerResult<impl IntoResponse> {
+    attribute
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let id = AttributeModel::insert(&state.db, category_id, attribute)
+        .await
+        .map_err(attribute_db_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_data_with_message(
+            format!("Atribut berhasil ditambahkan dengan ID: {}", id),
+            id,
+        )),
+    ))
+}
@@ -0,0 +1,27 @@
+use super::{HandlerResult, attribute_db_error};
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::attribute::AttributeModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Nilai atribut yang sudah tersimpan di produk tidak dihapus; key tersebut akan ditolak
+/// saat produk disimpan ulang.
+pub async fn delete_category_attribute(
+    State(state): State<Arc<AppState>>,
+    AdminAuth(_): AdminAuth,
+    Path((category_id, attribute_id)): Path<(i32, i64)>,
+) -> HandlerResult<impl IntoResponse> {
+    match AttributeModel::delete(&state.db, category_id, attribute_id)
+        .await
+        .map_err(attribute_db_error)?
+    {
+        0 => Err((StatusCode::NOT_FOUND, "Atribut tidak ditemukan".to_string())),
+        _ => Ok(Json(ApiResponse::<()>::success("Atribut berhasil dihapus"))),
+    }
+}
@@ -0,0 +1,39 @@
+use super::{HandlerResult, attribute_db_error};
+use crate::AppState;
+use crate::dtos::attribute::AttributeDefinition;
+use crate::models::attribute::AttributeModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get category attribute schema
+///
+/// Returns the typed attributes that products in this category can (or must) define.
+#[utoipa::path(
+    get,
+    path = "/categories/{id}/attributes",
+    tag = "categories",
+    params(
+        ("id" = i32, Path, description = "Category ID")
+    ),
+    responses(
+        (status = 200, description = "Attribute schema", body = [AttributeDefinition]),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_category_attributes(
+    State(state): State<Arc<AppState>>,
+    Path(category_id): Path<i32>,
+) -> HandlerResult<impl IntoResponse> {
+    let attributes = AttributeModel::find_by_category(&state.db, category_id)
+        .await
+        .map_err(attribute_db_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Skema atribut kategori berhasil diambil",
+        attributes,
+    )))
+}
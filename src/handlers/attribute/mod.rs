@@ -0,0 +1,31 @@
+pub mod create;
+pub mod delete;
+pub mod get_all;
+pub mod update;
+
+use axum::http::StatusCode;
+
+pub(crate) type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Terjemahkan error database untuk skema atribut ke status HTTP yang sesuai.
+pub(crate) fn attribute_db_error(e: sqlx::Error) -> (StatusCode, String) {
+    if let Some(db_error) = e.as_database_error() {
+        if db_error.is_unique_violation() {
+            return (
+                StatusCode::CONFLICT,
+                "Kode atribut sudah dipakai di kategori ini".to_string(),
+            );
+        }
+        if db_error.is_foreign_key_violation() {
+            return (
+                StatusCode::NOT_FOUND,
+                "Kategori tidak ditemukan".to_string(),
+            );
+        }
+    }
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
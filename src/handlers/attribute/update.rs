@@ -0,0 +1,33 @@
+use super::{HandlerResult, attribute_db_error};
+use crate::AppState;
+use crate::dtos::attribute::NewAttributeDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::attribute::AttributeModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn update_category_attribute(
+    State(state): State<Arc<AppState>>,
+    AdminAuth(_): AdminAuth,
+    Path((category_id, attribute_id)): Path<(i32, i64)>,
+    Json(attribute): Json<NewAttributeDto>,
+) -> HandlerResult<impl IntoResponse> {
+    attribute
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    match AttributeModel::update(&state.db, category_id, attribute_id, attribute)
+        .await
+        .map_err(attribute_db_error)?
+    {
+        0 => Err((StatusCode::NOT_FOUND, "Atribut tidak ditemukan".to_string())),
+        _ => Ok(Json(ApiResponse::<()>::success(
+            "Atribut berhasil diperbarui",
+        ))),
+    }
+}
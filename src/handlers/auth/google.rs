@@ -2,6 +2,8 @@ use crate::AppState;
 use crate::dtos::auth::LoginResponse;
 use crate::dtos::auth::UserLoginData;
 use crate::models::user::User;
+use crate::utils::cookie::build_cookie;
+use crate::utils::csrf::{CSRF_COOKIE, generate_csrf_token};
 use crate::utils::jwt::create_jwt;
 use axum::{
     Json,
@@ -9,7 +11,6 @@ use axum::{
     http::StatusCode,
     response::IntoResponse,
 };
-use axum_extra::extract::cookie::Cookie;
 use axum_extra::extract::cookie::CookieJar;
 use oauth2::TokenResponse as _OAuthTokenResponse;
 use oauth2::{
@@ -133,14 +134,11 @@ pub async fn google_callback_handler(
     let token = create_jwt(email.clone(), "user".to_string(), 5)
         .expect("Gagal membuat token JWT untuk Google Auth");
 
-    let cookie = Cookie::build(("jwt", token.clone()))
-        .http_only(true)
-        .secure(std::env::var("SECURE_COOKIE").unwrap_or_else(|_| "false".into()) == "true")
-        .path("/")
-        .max_age(Duration::hours(2))
-        .build();
+    let cookie = build_cookie("jwt", token.clone(), true, Duration::hours(2));
+    let csrf_token = generate_csrf_token();
+    let csrf_cookie = build_cookie(CSRF_COOKIE, csrf_token.clone(), false, Duration::hours(2));
 
-    let updated_jar = jar.add(cookie);
+    let updated_jar = jar.add(cookie).add(csrf_cookie);
 
     let user_data = UserLoginData {
         email: email.clone(),
@@ -154,6 +152,7 @@ pub async fn google_callback_handler(
             message: "Login berhasil".into(),
             access_token: Some(token),
             refresh_token: None,
+            csrf_token: Some(csrf_token),
             user: Some(user_data),
         }),
     )
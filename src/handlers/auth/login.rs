@@ -1,6 +1,8 @@
 use crate::AppState;
 use crate::dtos::auth::{LoginRequest, LoginResponse, UserLoginData};
 use crate::models::user::User;
+use crate::utils::cookie::build_cookie;
+use crate::utils::csrf::{CSRF_COOKIE, generate_csrf_token};
 use crate::utils::jwt::{create_jwt, create_refresh_token};
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use axum_extra::extract::cookie::CookieJar;
@@ -8,13 +10,13 @@ use bcrypt::verify;
 use chrono::{Duration as ChronoDuration, Utc};
 use std::sync::Arc;
 use time::Duration;
-// removed unused imports: sqlx::Row, serde_json::json
-use axum_extra::extract::cookie::Cookie;
 
 /// User login
 ///
 /// Authenticates user with email and password.
-/// Returns JWT access token (5 min) and refresh token (5 days) as httpOnly cookies.
+/// Returns JWT access token (5 min) and refresh token (5 days) as httpOnly cookies,
+/// plus a readable `csrf_token` cookie that must be echoed in the `X-CSRF-Token` header
+/// on cookie-authenticated non-GET requests.
 #[utoipa::path(
     post,
     path = "/auth/login",
@@ -67,23 +69,20 @@ pub async fn login_handler(
     .await
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
 
-    let secure_cookie = std::env::var("SECURE_COOKIE").unwrap_or_else(|_| "false".into()) == "true";
+    let access_cookie = build_cookie("jwt", access_token.clone(), true, Duration::minutes(5));
+    let refresh_cookie = build_cookie(
+        "refresh_token",
+        refresh_token.clone(),
+        true,
+        Duration::days(30),
+    );
 
-    let access_cookie = Cookie::build(("jwt", access_token.clone()))
-        .http_only(true)
-        .secure(secure_cookie)
-        .path("/")
-        .max_age(Duration::minutes(5))
-        .build();
+    // Token CSRF double-submit: frontend membaca cookie ini lalu mengirimnya lewat
+    // header X-CSRF-Token pada setiap request non-GET yang memakai cookie jwt.
+    let csrf_token = generate_csrf_token();
+    let csrf_cookie = build_cookie(CSRF_COOKIE, csrf_token.clone(), false, Duration::days(30));
 
-    let refresh_cookie = Cookie::build(("refresh_token", refresh_token.clone()))
-        .http_only(true)
-        .secure(secure_cookie)
-        .path("/")
-        .max_age(Duration::days(30))
-        .build();
-
-    let updated_jar = jar.add(access_cookie).add(refresh_cookie);
+    let updated_jar = jar.add(access_cookie).add(refresh_cookie).add(csrf_cookie);
 
     Ok((
         updated_jar,
@@ -92,6 +91,7 @@ pub async fn login_handler(
             message: "Login berhasil!".into(),
             access_token: Some(access_token),
             refresh_token: Some(refresh_token),
+            csrf_token: Some(csrf_token),
             user: Some(UserLoginData {
                 email: payload.email,
                 role: role.clone(),
@@ -1,11 +1,12 @@
 use crate::AppState;
+use crate::utils::cookie::removal_cookie;
+use crate::utils::csrf::CSRF_COOKIE;
 use crate::utils::jwt::verify_jwt;
 use axum::{Json, extract::State, response::IntoResponse};
-use axum_extra::extract::cookie::{Cookie, CookieJar};
+use axum_extra::extract::cookie::CookieJar;
 use serde_json::json;
 use sqlx::Row;
 use std::sync::Arc;
-use time::Duration;
 
 pub async fn logout_handler(
     State(state): State<Arc<AppState>>,
@@ -34,21 +35,10 @@ pub async fn logout_handler(
         }
     }
 
-    let cookie = Cookie::build(("jwt", ""))
-        .http_only(true)
-        .path("/")
-        .secure(std::env::var("SECURE_COOKIE").unwrap_or_else(|_| "false".into()) == "true")
-        .max_age(Duration::seconds(0))
-        .build();
-
-    let refresh_del = Cookie::build(("refresh_token", ""))
-        .http_only(true)
-        .path("/")
-        .secure(std::env::var("SECURE_COOKIE").unwrap_or_else(|_| "false".into()) == "true")
-        .max_age(Duration::seconds(0))
-        .build();
-
-    let jar = jar.add(cookie).add(refresh_del);
+    let jar = jar
+        .add(removal_cookie("jwt", true))
+        .add(removal_cookie("refresh_token", true))
+        .add(removal_cookie(CSRF_COOKIE, false));
 
     (
         jar,
@@ -1,37 +1,22 @@
 use crate::AppState;
 use crate::dtos::auth::UpdateRoleRequest;
+use crate::middleware::auth::AdminAuth;
 use crate::models::user::User;
-use crate::utils::jwt::verify_jwt;
 use axum::{
     Json,
     extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
 };
-use axum_extra::extract::cookie::CookieJar;
 use serde_json::json;
 use std::sync::Arc;
 
 pub async fn update_role_handler(
     State(state): State<Arc<AppState>>,
+    AdminAuth(_): AdminAuth,
     Path(email): Path<String>,
-    jar: CookieJar,
     Json(payload): Json<UpdateRoleRequest>,
 ) -> Result<impl IntoResponse, (StatusCode, String)> {
-    let token = match jar.get("jwt") {
-        Some(cookie) => cookie.value().to_string(),
-        None => return Err((StatusCode::UNAUTHORIZED, "Token tidak ditemukan".into())),
-    };
-
-    let claims = verify_jwt(&token)?;
-
-    if claims.role != "admin" {
-        return Err((
-            StatusCode::FORBIDDEN,
-            "Kamu bukan admin, gak boleh ubah role!".into(),
-        ));
-    }
-
     if payload.role != "admin" && payload.role != "user" {
         return Err((StatusCode::BAD_REQUEST, "Role tidak valid".into()));
     }
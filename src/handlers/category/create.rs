@@ -1,3 +1,4 @@
+use super::{category_db_error, check_parent};
 use crate::AppState;
 use crate::dtos::category::NewKategoriDto;
 use crate::middleware::auth::AdminAuth;
@@ -14,7 +15,8 @@ type HandlerResult<T> = Result<T, (StatusCode, String)>;
 
 /// Create a new category (Admin only)
 ///
-/// Creates a new product category.
+/// Creates a new product category, optionally under a parent category.
+/// The slug is generated from the name when omitted.
 /// Requires admin authentication.
 #[utoipa::path(
     post,
@@ -23,7 +25,9 @@ type HandlerResult<T> = Result<T, (StatusCode, String)>;
     request_body = NewKategoriDto,
     responses(
         (status = 201, description = "Category created successfully"),
+        (status = 400, description = "Invalid name or slug, or parent category not found"),
         (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 409, description = "Slug already used by another category"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -35,21 +39,20 @@ pub async fn create_category(
     _admin: AdminAuth,
     Json(new_kategori_dto): Json<NewKategoriDto>,
 ) -> HandlerResult<impl IntoResponse> {
-    match KategoriModel::insert(&state.db, new_kategori_dto).await {
-        Ok(id) => Ok((
-            StatusCode::CREATED,
-            Json(ApiResponse::success_data_with_message(
-                format!("Kategori berhasil dibuat dengan ID: {}", id),
-                id,
-            )),
-        )
-            .into_response()),
-        Err(e) => {
-            eprintln!("Error creating category: {}", e);
-            Err((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Gagal membuat kategori: {}", e),
-            ))
-        }
-    }
+    new_kategori_dto
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+    check_parent(&state.db, None, new_kategori_dto.parent_id).await?;
+
+    let id = KategoriModel::insert(&state.db, new_kategori_dto)
+        .await
+        .map_err(category_db_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_data_with_message(
+            format!("Kategori berhasil dibuat dengan ID: {}", id),
+            id,
+        )),
+    ))
 }
@@ -1,10 +1,12 @@
+use super::internal_server_error;
 use crate::AppState;
+use crate::dtos::category::DeleteCategoryParams;
 use crate::middleware::auth::AdminAuth;
 use crate::models::category::KategoriModel;
 use crate::utils::ApiResponse;
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
 };
@@ -12,32 +14,69 @@ use std::sync::Arc;
 
 type HandlerResult<T> = Result<T, (StatusCode, String)>;
 
+/// Kategori diarsipkan, bukan dihapus. Ditolak selama masih punya subkategori aktif, atau
+/// masih ada produk aktif di dalamnya kecuali `reassign_to` diisi (produknya dipindah ke sana).
 pub async fn delete_category(
     State(state): State<Arc<AppState>>,
     AdminAuth(_): AdminAuth,
     Path(category_id): Path<i32>,
+    Query(params): Query<DeleteCategoryParams>,
 ) -> HandlerResult<impl IntoResponse> {
-    match KategoriModel::delete(&state.db, category_id).await {
-        Ok(rows_affected) => {
-            if rows_affected > 0 {
-                Ok((
-                    StatusCode::OK,
-                    Json(ApiResponse::<()>::success("Kategori berhasil dihapus")),
-                )
-                    .into_response())
-            } else {
-                Err((
-                    StatusCode::NOT_FOUND,
-                    "Kategori tidak ditemukan.".to_string(),
-                ))
+    let active_children = KategoriModel::count_active_children(&state.db, category_id)
+        .await
+        .map_err(internal_server_error)?;
+    if active_children > 0 {
+        return Err((
+            StatusCode::CONFLICT,
+            format!(
+                "Kategori masih punya {} subkategori aktif. Pindahkan atau arsipkan subkategorinya dulu.",
+                active_children
+            ),
+        ));
+    }
+
+    match params.reassign_to {
+        Some(target_id) => {
+            if target_id == category_id
+                || KategoriModel::find_by_id(&state.db, target_id)
+                    .await
+                    .map_err(internal_server_error)?
+                    .is_none()
+            {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    "Kategori tujuan reassign_to tidak valid".to_string(),
+                ));
             }
         }
-        Err(e) => {
-            eprintln!("Error deleting category: {}", e);
-            Err((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Gagal menghapus kategori: {}", e),
-            ))
+        None => {
+            let active_products = KategoriModel::count_active_products(&state.db, category_id)
+                .await
+                .map_err(internal_server_error)?;
+            if active_products > 0 {
+                return Err((
+                    StatusCode::CONFLICT,
+                    format!(
+                        "Kategori masih dipakai {} produk aktif. Pindahkan produknya (reassign_to) atau arsipkan dulu.",
+                        active_products
+                    ),
+                ));
+            }
         }
     }
+
+    let rows_affected = KategoriModel::delete(&state.db, category_id, params.reassign_to)
+        .await
+        .map_err(internal_server_error)?;
+    if rows_affected == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            "Kategori tidak ditemukan.".to_string(),
+        ));
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::<()>::success("Kategori berhasil diarsipkan")),
+    ))
 }
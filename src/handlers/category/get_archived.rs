@@ -0,0 +1,44 @@
+use crate::AppState;
+use crate::dtos::category::KategoriDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::category::KategoriModel;
+use crate::utils::ApiResponse;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Get archived categories (Admin only)
+///
+/// Lists categories hidden by the delete endpoint. They can be brought back with
+/// `POST /categories/{id}/restore`.
+#[utoipa::path(
+    get,
+    path = "/categories/archived",
+    tag = "categories",
+    responses(
+        (status = 200, description = "Archived categories", body = [KategoriDto]),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_archived_categories(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+) -> HandlerResult<impl IntoResponse> {
+    let categories = KategoriModel::find_archived(&state.db).await.map_err(|e| {
+        eprintln!("Error fetching archived categories: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Gagal mengambil arsip kategori: {}", e),
+        )
+    })?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar kategori yang diarsipkan berhasil diambil",
+        categories,
+    )))
+}
@@ -0,0 +1,65 @@
+use super::internal_server_error;
+use crate::AppState;
+use crate::dtos::pagination::PaginationParams;
+use crate::dtos::product::{ProductFilterParams, RodProduct};
+use crate::models::category::KategoriModel;
+use crate::utils::ApiResponse;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Get products of a category by slug
+///
+/// Returns a paginated list of active products in the category and all of its
+/// subcategories. Accepts the same filters and sorting as `GET /products`
+/// (`category_id` is ignored).
+#[utoipa::path(
+    get,
+    path = "/categories/{slug}/products",
+    tag = "categories",
+    params(
+        ("slug" = String, Path, description = "Category slug"),
+        PaginationParams,
+        ProductFilterParams
+    ),
+    responses(
+        (status = 200, description = "Products in the category and its subcategories"),
+        (status = 400, description = "Invalid filter parameters"),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_category_products(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+    Query(params): Query<PaginationParams>,
+    Query(mut filter): Query<ProductFilterParams>,
+) -> HandlerResult<impl IntoResponse> {
+    filter
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let category = KategoriModel::find_by_slug(&state.db, &slug)
+        .await
+        .map_err(internal_server_error)?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            "Kategori tidak ditemukan.".to_string(),
+        ))?;
+    filter.category_id = Some(category.id);
+
+    let products = RodProduct::get_all_paginated(&state.db, params, &filter, &[], false)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar produk kategori berhasil diambil",
+        products,
+    )))
+}
@@ -0,0 +1,35 @@
+use super::internal_server_error;
+use crate::AppState;
+use crate::models::category::KategoriModel;
+use crate::utils::ApiResponse;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Get the category tree
+///
+/// Returns active categories nested under their parents, ordered by `sort_order`,
+/// with the number of active products directly in each category (`product_count`)
+/// and including all subcategories (`total_product_count`).
+#[utoipa::path(
+    get,
+    path = "/categories/tree",
+    tag = "categories",
+    responses(
+        (status = 200, description = "Category tree with product counts"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_category_tree(
+    State(state): State<Arc<AppState>>,
+) -> HandlerResult<impl IntoResponse> {
+    let tree = KategoriModel::find_tree(&state.db)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Pohon kategori berhasil diambil",
+        tree,
+    )))
+}
@@ -1,5 +1,66 @@
 pub mod create;
 pub mod delete;
 pub mod get_all;
+pub mod get_archived;
 pub mod get_by_id;
+pub mod get_products;
+pub mod get_tree;
+pub mod restore;
 pub mod update;
+
+use crate::models::category::KategoriModel;
+use axum::http::StatusCode;
+use sqlx::{MySql, Pool};
+
+fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
+
+/// Slug manual yang bentrok ditolak unique index `uq_kategori_slug`.
+pub(crate) fn category_db_error(e: sqlx::Error) -> (StatusCode, String) {
+    if e.as_database_error()
+        .is_some_and(|db_error| db_error.is_unique_violation())
+    {
+        return (
+            StatusCode::CONFLICT,
+            "Slug sudah dipakai kategori lain".to_string(),
+        );
+    }
+    internal_server_error(e)
+}
+
+/// Induk harus kategori aktif, dan (saat update) bukan kategori itu sendiri atau turunannya.
+pub(crate) async fn check_parent(
+    pool: &Pool<MySql>,
+    category_id: Option<i32>,
+    parent_id: Option<i32>,
+) -> Result<(), (StatusCode, String)> {
+    let Some(parent_id) = parent_id else {
+        return Ok(());
+    };
+    if KategoriModel::find_by_id(pool, parent_id)
+        .await
+        .map_err(internal_server_error)?
+        .is_none()
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Kategori induk tidak ditemukan".to_string(),
+        ));
+    }
+    if let Some(category_id) = category_id
+        && KategoriModel::is_in_subtree(pool, category_id, parent_id)
+            .await
+            .map_err(internal_server_error)?
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Kategori induk tidak boleh kategori ini sendiri atau subkategorinya".to_string(),
+        ));
+    }
+    Ok(())
+}
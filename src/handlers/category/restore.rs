@@ -0,0 +1,59 @@
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::category::KategoriModel;
+use crate::utils::ApiResponse;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Restore an archived category (Admin only)
+#[utoipa::path(
+    post,
+    path = "/categories/{id}/restore",
+    tag = "categories",
+    params(
+        ("id" = i32, Path, description = "Category ID")
+    ),
+    responses(
+        (status = 200, description = "Category restored"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "No archived category with this ID"),
+        (status = 409, description = "Parent category is still archived"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn restore_category(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(category_id): Path<i32>,
+) -> HandlerResult<impl IntoResponse> {
+    match KategoriModel::restore(&state.db, category_id).await {
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            "Kategori tidak ditemukan di arsip.".to_string(),
+        )),
+        Ok(Some(false)) => Err((
+            StatusCode::CONFLICT,
+            "Kategori induknya masih diarsipkan. Pulihkan kategori induk dulu.".to_string(),
+        )),
+        Ok(Some(true)) => Ok(Json(ApiResponse::<()>::success(
+            "Kategori berhasil dipulihkan",
+        ))),
+        Err(e) => {
+            eprintln!("Error restoring category: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Gagal memulihkan kategori: {}", e),
+            ))
+        }
+    }
+}
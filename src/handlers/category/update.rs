@@ -1,3 +1,4 @@
+use super::{category_db_error, check_parent};
 use crate::AppState;
 use crate::dtos::category::NewKategoriDto;
 use crate::middleware::auth::AdminAuth;
@@ -18,18 +19,23 @@ pub async fn update_category(
     Path(category_id): Path<i32>,
     Json(updated_kategori_dto): Json<NewKategoriDto>,
 ) -> HandlerResult<impl IntoResponse> {
-    match KategoriModel::update(&state.db, category_id, &updated_kategori_dto.name).await {
-        Ok(_) => Ok((
-            StatusCode::OK,
-            Json(ApiResponse::<()>::success("Kategori berhasil diperbarui")),
-        )
-            .into_response()),
-        Err(e) => {
-            eprintln!("Error updating category: {}", e);
-            Err((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Gagal memperbarui kategori: {}", e),
-            ))
-        }
+    updated_kategori_dto
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+    check_parent(&state.db, Some(category_id), updated_kategori_dto.parent_id).await?;
+
+    let rows_affected = KategoriModel::update(&state.db, category_id, updated_kategori_dto)
+        .await
+        .map_err(category_db_error)?;
+    if rows_affected == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            "Kategori tidak ditemukan.".to_string(),
+        ));
     }
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::<()>::success("Kategori berhasil diperbarui")),
+    ))
 }
@@ -42,8 +42,18 @@ pub async fn chatbot_recommend(
     let products_context = products
         .iter()
         .map(|p| {
+            // Harga promo disebutkan bersama harga normal dan batas waktunya
+            let price = match p.sale_ends_at {
+                Some(ends_at) if p.final_price < p.price => format!(
+                    "Rp {} (promo, normal Rp {}, sampai {})",
+                    p.final_price,
+                    p.price,
+                    ends_at.format("%d-%m-%Y %H:%M")
+                ),
+                _ => format!("Rp {}", p.final_price),
+            };
             format!(
-                "- {} ({}): {} | Panjang: {} | Line: {} | Cast: {} | Action: {} | Material: {} | Power: {} | Reel: {} | Harga: Rp {}",
+                "- {} ({}): {} | Panjang: {} | Line: {} | Cast: {} | Action: {} | Material: {} | Power: {} | Reel: {} | Harga: {}",
                 p.name,
                 p.category_name,
                 p.description,
@@ -54,7 +64,7 @@ pub async fn chatbot_recommend(
                 p.material.as_deref().unwrap_or("-"),
                 p.power.as_deref().unwrap_or("-"),
                 p.reel_size.as_deref().unwrap_or("-"),
-                p.price as i64  // Remove comma formatting
+                price
             )
         })
         .collect::<Vec<_>>()
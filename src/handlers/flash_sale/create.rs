@@ -0,0 +1,59 @@
+use super::{HandlerResult, internal_server_error, pricing_error};
+use crate::AppState;
+use crate::dtos::flash_sale::NewFlashSaleDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::flash_sale::FlashSaleModel;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Create a flash sale (Admin only)
+///
+/// Sells up to `quota` units of one product or variant at `flash_price` between
+/// `starts_at` and `ends_at`, at most `per_customer_limit` units per customer.
+#[utoipa::path(
+    post,
+    path = "/flash-sales/create",
+    tag = "flash-sales",
+    request_body = NewFlashSaleDto,
+    responses(
+        (status = 201, description = "Flash sale created"),
+        (status = 400, description = "Invalid window, quota or limit, price not below the regular price, or variant_id missing"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Product or variant not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn create_flash_sale(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Json(flash_sale): Json<NewFlashSaleDto>,
+) -> HandlerResult<impl IntoResponse> {
+    flash_sale
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(internal_server_error)?;
+
+    let id = FlashSaleModel::create(&state.db, flash_sale, admin_id)
+        .await
+        .map_err(|e| pricing_error(e, StatusCode::BAD_REQUEST))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_data_with_message(
+            format!("Flash sale berhasil dibuat dengan ID: {}", id),
+            id,
+        )),
+    ))
+}
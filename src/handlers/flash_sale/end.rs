@@ -0,0 +1,49 @@
+use super::{HandlerResult, pricing_error};
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::flash_sale::FlashSaleModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// End a flash sale (Admin only)
+///
+/// Cancels a flash sale that has not started yet, or ends a running one immediately.
+/// Orders already placed keep the flash price.
+#[utoipa::path(
+    post,
+    path = "/flash-sales/{id}/end",
+    tag = "flash-sales",
+    params(
+        ("id" = i64, Path, description = "Flash sale ID")
+    ),
+    responses(
+        (status = 200, description = "Flash sale cancelled or ended"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Flash sale not found"),
+        (status = 409, description = "Flash sale has already ended"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn end_flash_sale(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    let cancelled = FlashSaleModel::end(&state.db, id)
+        .await
+        .map_err(|e| pricing_error(e, StatusCode::CONFLICT))?;
+
+    Ok(Json(ApiResponse::<()>::success(if cancelled {
+        "Flash sale dibatalkan"
+    } else {
+        "Flash sale dihentikan"
+    })))
+}
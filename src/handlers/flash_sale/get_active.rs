@@ -0,0 +1,36 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::models::flash_sale::FlashSaleModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get running flash sales
+///
+/// Lists flash sales that are running now with their price, per-customer limit and
+/// remaining quota, ending soonest first. Buy at the flash price by sending
+/// `flash_sale_id` on the checkout item.
+#[utoipa::path(
+    get,
+    path = "/flash-sales",
+    tag = "flash-sales",
+    responses(
+        (status = 200, description = "Running flash sales with remaining quota"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_active_flash_sales(
+    State(state): State<Arc<AppState>>,
+) -> HandlerResult<impl IntoResponse> {
+    let flash_sales = FlashSaleModel::find_active(&state.db)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar flash sale berhasil diambil",
+        flash_sales,
+    )))
+}
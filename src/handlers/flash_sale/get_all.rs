@@ -0,0 +1,40 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::flash_sale::FlashSaleModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get all flash sales (Admin only)
+///
+/// Lists scheduled, running and past flash sales, latest start first.
+#[utoipa::path(
+    get,
+    path = "/flash-sales/all",
+    tag = "flash-sales",
+    responses(
+        (status = 200, description = "All flash sales with their status"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_all_flash_sales(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+) -> HandlerResult<impl IntoResponse> {
+    let flash_sales = FlashSaleModel::find_all(&state.db)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar flash sale berhasil diambil",
+        flash_sales,
+    )))
+}
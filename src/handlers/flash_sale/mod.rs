@@ -0,0 +1,18 @@
+pub mod create;
+pub mod end;
+pub mod get_active;
+pub mod get_all;
+
+use axum::http::StatusCode;
+
+pub(crate) use crate::handlers::sale::pricing_error;
+
+pub(crate) type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+pub(crate) fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
@@ -0,0 +1,68 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::inventory::{StockAdjustmentDto, StockError};
+use crate::middleware::auth::AdminAuth;
+use crate::models::inventory::InventoryModel;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+/// Adjust product stock (Admin only)
+///
+/// Adds or removes on-hand stock (restock, damage, stock take) and records the change
+/// in the stock movement ledger. Without `warehouse_id` the primary warehouse is used.
+/// Stock cannot drop below the quantity reserved by pending orders.
+#[utoipa::path(
+    post,
+    path = "/products/{id}/stock/adjust",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    request_body = StockAdjustmentDto,
+    responses(
+        (status = 200, description = "Stock adjusted"),
+        (status = 400, description = "Invalid adjustment"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Product or variant not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn adjust_stock(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Path(product_id): Path<i64>,
+    Json(adjustment): Json<StockAdjustmentDto>,
+) -> HandlerResult<impl IntoResponse> {
+    adjustment
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(internal_server_error)?;
+
+    match InventoryModel::adjust(&state.db, product_id, &adjustment, admin_id).await {
+        Ok(counts) => Ok(Json(ApiResponse::success_data(
+            "Stok berhasil disesuaikan",
+            json!({
+                "warehouse_id": counts.warehouse_id,
+                "stock": counts.stock,
+                "reserved_stock": counts.reserved_stock,
+                "available_stock": counts.available(),
+            }),
+        ))),
+        Err(StockError::NotFound(message)) => Err((StatusCode::NOT_FOUND, message)),
+        Err(StockError::Invalid(message)) => Err((StatusCode::BAD_REQUEST, message)),
+        Err(StockError::Database(e)) => Err(internal_server_error(e)),
+    }
+}
@@ -0,0 +1,48 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::inventory::ProductStock;
+use crate::middleware::auth::AdminAuth;
+use crate::models::inventory::InventoryModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get product stock levels (Admin only)
+///
+/// Returns on-hand, reserved and available stock for the product and each of its variants.
+#[utoipa::path(
+    get,
+    path = "/products/{id}/stock",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Stock levels", body = ProductStock),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Product not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_product_stock(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(product_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    let stock = InventoryModel::find_product_stock(&state.db, product_id)
+        .await
+        .map_err(internal_server_error)?
+        .ok_or((StatusCode::NOT_FOUND, "Produk tidak ditemukan".to_string()))?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Stok produk berhasil diambil",
+        stock,
+    )))
+}
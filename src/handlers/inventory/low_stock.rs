@@ -0,0 +1,41 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::inventory::LowStockItem;
+use crate::middleware::auth::AdminAuth;
+use crate::models::inventory::InventoryModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get low-stock items (Admin only)
+///
+/// Live list of SKUs at or below their reorder point, with a suggested order quantity.
+#[utoipa::path(
+    get,
+    path = "/inventory/low-stock",
+    tag = "products",
+    responses(
+        (status = 200, description = "Low-stock items", body = [LowStockItem]),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_low_stock(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+) -> HandlerResult<impl IntoResponse> {
+    let items = InventoryModel::find_low_stock(&state.db)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar stok menipis berhasil diambil",
+        items,
+    )))
+}
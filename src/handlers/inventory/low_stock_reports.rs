@@ -0,0 +1,43 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::pagination::PaginationParams;
+use crate::middleware::auth::AdminAuth;
+use crate::models::inventory::InventoryModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Query, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get daily low-stock reports (Admin only)
+///
+/// Snapshots generated once a day by the background job, newest first.
+#[utoipa::path(
+    get,
+    path = "/inventory/low-stock/reports",
+    tag = "products",
+    params(PaginationParams),
+    responses(
+        (status = 200, description = "Paginated low-stock reports"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_low_stock_reports(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    let reports = InventoryModel::find_low_stock_reports(&state.db, params)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Laporan stok menipis berhasil diambil",
+        reports,
+    )))
+}
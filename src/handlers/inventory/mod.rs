@@ -0,0 +1,19 @@
+pub mod adjust;
+pub mod get_stock;
+pub mod low_stock;
+pub mod low_stock_reports;
+pub mod movements;
+pub mod reorder;
+pub mod transfer;
+
+use axum::http::StatusCode;
+
+pub(crate) type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+pub(crate) fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
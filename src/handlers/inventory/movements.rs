@@ -0,0 +1,47 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::pagination::PaginationParams;
+use crate::middleware::auth::AdminAuth;
+use crate::models::inventory::InventoryModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, Query, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get stock movement ledger (Admin only)
+///
+/// Lists reservations, releases, sales and manual adjustments for the product, newest first.
+#[utoipa::path(
+    get,
+    path = "/products/{id}/stock/movements",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID"),
+        PaginationParams
+    ),
+    responses(
+        (status = 200, description = "Paginated stock movements"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_stock_movements(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(product_id): Path<i64>,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    let movements = InventoryModel::find_movements(&state.db, product_id, params)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Riwayat stok berhasil diambil",
+        movements,
+    )))
+}
@@ -0,0 +1,56 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::inventory::ReorderSettingsDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::inventory::InventoryModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Set product reorder threshold (Admin only)
+///
+/// A product (or each of its variants) appears in the low-stock report once available
+/// stock plus stock on order falls to `reorder_point`. Send `null` to stop monitoring.
+#[utoipa::path(
+    put,
+    path = "/products/{id}/stock/reorder",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    request_body = ReorderSettingsDto,
+    responses(
+        (status = 200, description = "Reorder threshold updated"),
+        (status = 400, description = "Invalid threshold"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Product not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn set_reorder_threshold(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(product_id): Path<i64>,
+    Json(settings): Json<ReorderSettingsDto>,
+) -> HandlerResult<impl IntoResponse> {
+    settings
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    match InventoryModel::set_reorder(&state.db, product_id, &settings)
+        .await
+        .map_err(internal_server_error)?
+    {
+        0 => Err((StatusCode::NOT_FOUND, "Produk tidak ditemukan".to_string())),
+        _ => Ok(Json(ApiResponse::<()>::success(
+            "Titik pemesanan ulang berhasil disimpan",
+        ))),
+    }
+}
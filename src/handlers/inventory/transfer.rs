@@ -0,0 +1,60 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::inventory::{StockError, StockTransferDto};
+use crate::middleware::auth::AdminAuth;
+use crate::models::inventory::InventoryModel;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Transfer stock between warehouses (Admin only)
+///
+/// Moves unreserved stock of one SKU from one warehouse to another and records both
+/// sides in the stock movement ledger.
+#[utoipa::path(
+    post,
+    path = "/products/{id}/stock/transfer",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    request_body = StockTransferDto,
+    responses(
+        (status = 200, description = "Stock transferred"),
+        (status = 400, description = "Invalid transfer or insufficient stock at source"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Product, variant or warehouse not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn transfer_stock(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Path(product_id): Path<i64>,
+    Json(transfer): Json<StockTransferDto>,
+) -> HandlerResult<impl IntoResponse> {
+    transfer
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(internal_server_error)?;
+
+    match InventoryModel::transfer(&state.db, product_id, &transfer, admin_id).await {
+        Ok(()) => Ok(Json(ApiResponse::<()>::success(
+            "Stok berhasil dipindahkan antar gudang",
+        ))),
+        Err(StockError::NotFound(message)) => Err((StatusCode::NOT_FOUND, message)),
+        Err(StockError::Invalid(message)) => Err((StatusCode::BAD_REQUEST, message)),
+        Err(StockError::Database(e)) => Err(internal_server_error(e)),
+    }
+}
@@ -0,0 +1,54 @@
+use super::{HandlerResult, current_user_id, internal_server_error};
+use crate::AppState;
+use crate::dtos::notification::{NotificationPage, NotificationParams};
+use crate::dtos::pagination::PaginationParams;
+use crate::middleware::auth::AuthUser;
+use crate::models::notification::NotificationModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Query, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get my notifications
+///
+/// The authenticated user's notifications, newest first, with the number of unread ones.
+#[utoipa::path(
+    get,
+    path = "/user/notifications",
+    tag = "user",
+    params(
+        NotificationParams,
+        PaginationParams
+    ),
+    responses(
+        (status = 200, description = "Paginated notifications and unread count", body = NotificationPage),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_notifications(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+    Query(filter): Query<NotificationParams>,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    let user_id = current_user_id(&state.db, &email).await?;
+    let (notifications, pagination, unread_count) =
+        NotificationModel::find_by_user(&state.db, user_id, filter.unread_only, &params)
+            .await
+            .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Notifikasi berhasil diambil",
+        NotificationPage {
+            unread_count,
+            data: notifications,
+            pagination,
+        },
+    )))
+}
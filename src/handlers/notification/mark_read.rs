@@ -0,0 +1,79 @@
+use super::{HandlerResult, current_user_id, internal_server_error};
+use crate::AppState;
+use crate::middleware::auth::AuthUser;
+use crate::models::notification::NotificationModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Mark a notification as read
+#[utoipa::path(
+    post,
+    path = "/user/notifications/{id}/read",
+    tag = "user",
+    params(
+        ("id" = i64, Path, description = "Notification ID")
+    ),
+    responses(
+        (status = 200, description = "Notification marked as read"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Notification not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn mark_notification_read(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+    Path(notification_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    let user_id = current_user_id(&state.db, &email).await?;
+    if !NotificationModel::mark_read(&state.db, user_id, notification_id)
+        .await
+        .map_err(internal_server_error)?
+    {
+        return Err((
+            StatusCode::NOT_FOUND,
+            "Notifikasi tidak ditemukan".to_string(),
+        ));
+    }
+
+    Ok(Json(ApiResponse::<()>::success(
+        "Notifikasi ditandai sudah dibaca",
+    )))
+}
+
+/// Mark all notifications as read
+#[utoipa::path(
+    post,
+    path = "/user/notifications/read-all",
+    tag = "user",
+    responses(
+        (status = 200, description = "Number of notifications marked as read"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn mark_all_notifications_read(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+) -> HandlerResult<impl IntoResponse> {
+    let user_id = current_user_id(&state.db, &email).await?;
+    let updated = NotificationModel::mark_all_read(&state.db, user_id)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data_with_message(
+        format!("{} notifikasi ditandai sudah dibaca", updated),
+        updated,
+    )))
+}
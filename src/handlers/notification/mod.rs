@@ -0,0 +1,27 @@
+pub mod get_all;
+pub mod mark_read;
+
+use crate::models::user::User;
+use axum::http::StatusCode;
+use sqlx::{MySql, Pool};
+
+pub(crate) type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+pub(crate) fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
+
+/// Cari id user yang sedang login; notifikasi selalu dibatasi per user.
+pub(crate) async fn current_user_id(
+    pool: &Pool<MySql>,
+    email: &str,
+) -> Result<i64, (StatusCode, String)> {
+    User::find_id_by_email(pool, email)
+        .await
+        .map_err(internal_server_error)?
+        .ok_or((StatusCode::UNAUTHORIZED, "User tidak ditemukan".to_string()))
+}
@@ -1,5 +1,9 @@
 use crate::{
-    AppState, dtos::order::NewOrderDto, middleware::auth::AuthUser, models::user::User,
+    AppState,
+    dtos::order::{CheckoutError, NewOrderDto, ShippingDetails},
+    handlers::address::validate_region,
+    middleware::auth::AuthUser,
+    models::{address::AddressModel, order::reservation_minutes, user::User},
     utils::ApiResponse,
 };
 use axum::{
@@ -23,6 +27,58 @@ fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
     )
 }
 
+/// Tentukan alamat pengiriman order: `address_id` dari buku alamat user (disimpan juga
+/// snapshot-nya), atau teks `shipping_address` untuk client lama.
+async fn resolve_shipping(
+    state: &AppState,
+    user_id: i64,
+    new_order_dto: &NewOrderDto,
+) -> HandlerResult<ShippingDetails> {
+    if let Some(address_id) = new_order_dto.address_id {
+        let address = AddressModel::find_by_id_for_user(&state.db, address_id, user_id)
+            .await
+            .map_err(internal_server_error)?
+            .ok_or((
+                StatusCode::BAD_REQUEST,
+                "Alamat pengiriman tidak ditemukan.".to_string(),
+            ))?;
+
+        // Alamat lama (sebelum dataset wilayah dimuat) bisa saja tidak valid lagi
+        validate_region(
+            &state.db,
+            &address.province,
+            &address.city,
+            &address.district,
+        )
+        .await?;
+
+        let snapshot = serde_json::to_string(&address)
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+        return Ok(ShippingDetails {
+            address_id: Some(address.id),
+            text: address.to_shipping_text(),
+            snapshot: Some(snapshot),
+            province: Some(address.province),
+            city: Some(address.city),
+        });
+    }
+
+    match new_order_dto.shipping_address.as_deref().map(str::trim) {
+        Some(text) if !text.is_empty() => Ok(ShippingDetails {
+            address_id: None,
+            text: text.to_string(),
+            snapshot: None,
+            province: None,
+            city: None,
+        }),
+        _ => Err((
+            StatusCode::BAD_REQUEST,
+            "address_id atau shipping_address wajib diisi.".to_string(),
+        )),
+    }
+}
+
 /// Checkout and create order
 ///
 /// Creates a new order and returns Midtrans payment URL.
@@ -34,7 +90,9 @@ fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
     request_body = NewOrderDto,
     responses(
         (status = 201, description = "Order created successfully with payment URL"),
+        (status = 400, description = "Invalid product, variant or shipping address"),
         (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Insufficient stock (per-item shortages in data), or flash sale not running, sold out or over the per-customer limit"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -52,20 +110,34 @@ pub async fn checkout(
         Err(e) => return Err(internal_server_error(e)),
     };
 
-    match crate::dtos::order::Order::create_order(&state.db, user_record.id, new_order_dto).await {
-        Ok(order_id) => {
+    let shipping = resolve_shipping(&state, user_record.id, &new_order_dto).await?;
+
+    match crate::dtos::order::Order::create_order(
+        &state.db,
+        user_record.id,
+        new_order_dto,
+        shipping,
+    )
+    .await
+    {
+        Ok((order_id, total_amount)) => {
             let client = Client::new();
 
             let payload = json!({
                 "transaction_details": {
                     "order_id": order_id.to_string(),
-                    "gross_amount": 100000
+                    "gross_amount": total_amount.rupiah()
                 },
                 "customer_details": {
                     "first_name": user_record.name,
                     "email": user_record.email
                 },
-                "credit_card": { "secure": true }
+                "credit_card": { "secure": true },
+                // Halaman bayar ditutup bersamaan dengan berakhirnya reservasi stok
+                "expiry": {
+                    "unit": "minute",
+                    "duration": reservation_minutes()
+                }
             });
 
             let url = format!("{}/snap/v1/transactions", state.midtrans_base_url);
@@ -105,7 +177,11 @@ pub async fn checkout(
                             Json(ApiResponse::success_data_with_message(
                                 "Checkout berhasil. Silakan lanjutkan pembayaran melalui Midtrans."
                                     .to_string(),
-                                json!({ "order_id": order_id, "payment_url": redirect_url }),
+                                json!({
+                                    "order_id": order_id,
+                                    "total_amount": total_amount,
+                                    "payment_url": redirect_url
+                                }),
                             )),
                         ))
                     } else {
@@ -133,10 +209,16 @@ pub async fn checkout(
                 }
             }
         }
-        Err(sqlx::Error::RowNotFound) => Err((
-            StatusCode::BAD_REQUEST,
-            "Gagal checkout. Salah satu produk tidak valid.".to_string(),
+        Err(CheckoutError::InvalidItem(message)) => Err((StatusCode::BAD_REQUEST, message)),
+        // Rincian per item dikirim sebagai data agar client bisa menandai item yang bermasalah
+        Err(CheckoutError::OutOfStock(shortages)) => Ok((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::error_data(
+                "Stok tidak mencukupi untuk beberapa item.".to_string(),
+                json!(shortages),
+            )),
         )),
-        Err(e) => Err(internal_server_error(e)),
+        Err(CheckoutError::FlashSaleUnavailable(message)) => Err((StatusCode::CONFLICT, message)),
+        Err(CheckoutError::Database(e)) => Err(internal_server_error(e)),
     }
 }
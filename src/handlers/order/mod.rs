@@ -1,5 +1,6 @@
 pub mod checkout;
 pub mod process_payment;
 pub mod query_midtrans;
+pub mod refunds;
 pub mod status_db;
 pub mod webhook;
@@ -1,4 +1,9 @@
-use crate::{AppState, dtos::order::Order, middleware::auth::AdminAuth, utils::ApiResponse};
+use crate::{
+    AppState,
+    dtos::order::{Order, PaymentUpdate},
+    middleware::auth::AdminAuth,
+    utils::ApiResponse,
+};
 use axum::{
     extract::{Json, Path, State},
     http::StatusCode,
@@ -28,23 +33,27 @@ pub async fn process_payment(
         .unwrap_or(false);
 
     match Order::process_payment(&state.db, order_id, is_success).await {
-        Ok(rows_affected) => {
-            if rows_affected > 0 {
-                let status_msg = if is_success { "PAID" } else { "FAILED" };
-                Ok((
-                    StatusCode::OK,
-                    Json(ApiResponse::<()>::success(&format!(
-                        "Status Order {} diperbarui menjadi {}",
-                        order_id, status_msg
-                    ))),
-                ))
-            } else {
-                Err((
-                    StatusCode::NOT_FOUND,
-                    "Order tidak ditemukan atau sudah diproses.".to_string(),
-                ))
-            }
+        Ok(PaymentUpdate::Updated | PaymentUpdate::LatePaid) => {
+            let status_msg = if is_success { "PAID" } else { "FAILED" };
+            Ok((
+                StatusCode::OK,
+                Json(ApiResponse::<()>::success(&format!(
+                    "Status Order {} diperbarui menjadi {}",
+                    order_id, status_msg
+                ))),
+            ))
         }
+        Ok(PaymentUpdate::RefundRequired) => Ok((
+            StatusCode::OK,
+            Json(ApiResponse::<()>::success(&format!(
+                "Order {} sudah kedaluwarsa dan stoknya habis; order ditandai perlu refund",
+                order_id
+            ))),
+        )),
+        Ok(PaymentUpdate::Unchanged) => Err((
+            StatusCode::NOT_FOUND,
+            "Order tidak ditemukan atau sudah diproses.".to_string(),
+        )),
         Err(e) => Err(internal_server_error(e)),
     }
 }
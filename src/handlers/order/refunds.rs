@@ -0,0 +1,53 @@
+use crate::{
+    AppState,
+    dtos::order::{Order, RefundRequiredOrder},
+    middleware::auth::AdminAuth,
+    utils::ApiResponse,
+};
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
+
+/// Get orders that need a refund (Admin only)
+///
+/// Orders paid after their reservation expired when the stock or flash-sale quota was no
+/// longer available. They stay `FAILED` and the payment must be refunded manually.
+#[utoipa::path(
+    get,
+    path = "/orders/refunds",
+    tag = "orders",
+    responses(
+        (status = 200, description = "Orders flagged for refund, newest first", body = Vec<RefundRequiredOrder>),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_refund_required_orders(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+) -> HandlerResult<impl IntoResponse> {
+    let orders = Order::find_refund_required(&state.db)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar order yang perlu refund berhasil diambil",
+        orders,
+    )))
+}
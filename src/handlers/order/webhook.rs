@@ -5,7 +5,11 @@ use axum::{
 };
 use std::sync::Arc;
 // removed unused import: serde_json::json
-use crate::{AppState, dtos::order::Order, utils::ApiResponse};
+use crate::{
+    AppState,
+    dtos::order::{Order, PaymentUpdate},
+    utils::ApiResponse,
+};
 
 type HandlerResult<T> = Result<T, (StatusCode, String)>;
 
@@ -17,6 +21,18 @@ fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
     )
 }
 
+/// Arti `transaction_status` Midtrans bagi order: `Some(true)` dibayar, `Some(false)` gagal
+/// final, `None` belum final atau tidak mengubah order (mis. `pending`, `authorize`, `refund`).
+fn payment_result(payload: &serde_json::Value) -> Option<bool> {
+    match payload["transaction_status"].as_str().unwrap_or("") {
+        "settlement" => Some(true),
+        // Kartu yang masih ditinjau fraud detection belum boleh dianggap lunas
+        "capture" => (payload["fraud_status"].as_str() != Some("challenge")).then_some(true),
+        "deny" | "cancel" | "expire" | "failure" => Some(false),
+        _ => None,
+    }
+}
+
 pub async fn webhook_payment(
     State(state): State<Arc<AppState>>,
     Json(payload): Json<serde_json::Value>,
@@ -27,7 +43,6 @@ pub async fn webhook_payment(
     };
 
     let transaction_status = payload["transaction_status"].as_str().unwrap_or("");
-    let is_success = transaction_status == "settlement" || transaction_status == "capture";
 
     if order_id <= 0 {
         return Err((StatusCode::BAD_REQUEST, "order_id tidak valid".to_string()));
@@ -39,28 +54,47 @@ pub async fn webhook_payment(
         transaction_status
     );
 
-    match Order::process_payment(&state.db, order_id, is_success).await {
-        Ok(rows_affected) => {
-            if rows_affected > 0 {
-                Ok((
-                    StatusCode::OK,
-                    Json(ApiResponse::<()>::success(&format!(
-                        "Webhook: Order {} diperbarui menjadi {}",
-                        order_id,
-                        if is_success { "PAID" } else { "FAILED" }
-                    ))),
-                ))
-            } else {
-                eprintln!(
-                    "[Webhook] Order {} tidak ditemukan atau sudah diproses (Status tidak diubah). Mengembalikan 200 OK.",
-                    order_id
-                );
-                Ok((
-                    StatusCode::OK,
-                    Json(ApiResponse::<()>::success("Order sudah diproses.")),
-                ))
-            }
+    let Some(is_success) = payment_result(&payload) else {
+        return Ok((
+            StatusCode::OK,
+            Json(ApiResponse::<()>::success(&format!(
+                "Status {} tidak mengubah order.",
+                transaction_status
+            ))),
+        ));
+    };
+
+    let message = match Order::process_payment(&state.db, order_id, is_success)
+        .await
+        .map_err(internal_server_error)?
+    {
+        PaymentUpdate::Updated => format!(
+            "Webhook: Order {} diperbarui menjadi {}",
+            order_id,
+            if is_success { "PAID" } else { "FAILED" }
+        ),
+        PaymentUpdate::LatePaid => {
+            eprintln!(
+                "[Webhook] Order {} dibayar setelah kedaluwarsa; stok diambil lagi, order PAID.",
+                order_id
+            );
+            format!("Webhook: Order {} diperbarui menjadi PAID", order_id)
         }
-        Err(e) => Err(internal_server_error(e)),
-    }
+        PaymentUpdate::RefundRequired => {
+            eprintln!(
+                "[Webhook] Order {} dibayar setelah kedaluwarsa tetapi stok/kuota sudah habis. PERLU REFUND.",
+                order_id
+            );
+            format!("Webhook: Order {} ditandai perlu refund", order_id)
+        }
+        PaymentUpdate::Unchanged => {
+            eprintln!(
+                "[Webhook] Order {} tidak ditemukan atau sudah diproses (Status tidak diubah). Mengembalikan 200 OK.",
+                order_id
+            );
+            "Order sudah diproses.".to_string()
+        }
+    };
+
+    Ok((StatusCode::OK, Json(ApiResponse::<()>::success(&message))))
 }
@@ -1,7 +1,9 @@
+use super::{duplicate_key_message, prepare_product};
 use crate::AppState;
 use crate::dtos::product::NewRodProductDto;
 use crate::dtos::product::RodProduct;
 use crate::middleware::auth::AdminAuth;
+use crate::models::user::User;
 use crate::utils::ApiResponse;
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use std::sync::Arc;
@@ -21,6 +23,7 @@ type HandlerResult<T> = Result<T, (StatusCode, String)>;
         (status = 201, description = "Product created successfully"),
         (status = 400, description = "Invalid input or category_id"),
         (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 409, description = "SKU already used by another product"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -29,10 +32,22 @@ type HandlerResult<T> = Result<T, (StatusCode, String)>;
 )]
 pub async fn create_product(
     State(state): State<Arc<AppState>>,
-    _admin: AdminAuth,
-    Json(new_product_dto): Json<NewRodProductDto>,
+    admin: AdminAuth,
+    Json(mut new_product_dto): Json<NewRodProductDto>,
 ) -> HandlerResult<impl IntoResponse> {
-    match RodProduct::insert(&state.db, new_product_dto).await {
+    let specs = prepare_product(&state.db, &mut new_product_dto).await?;
+
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Terjadi kesalahan internal pada server.".to_string(),
+            )
+        })?;
+
+    match RodProduct::insert(&state.db, new_product_dto, specs, admin_id).await {
         Ok(id) => Ok((
             StatusCode::CREATED,
             Json(ApiResponse::success_data_with_message(
@@ -41,6 +56,9 @@ pub async fn create_product(
             )),
         )),
         Err(e) => {
+            if let Some(message) = duplicate_key_message(&e) {
+                return Err((StatusCode::CONFLICT, message.to_string()));
+            }
             eprintln!("Error creating product: {}", e);
             Err((
                 StatusCode::BAD_REQUEST,
@@ -12,6 +12,7 @@ use std::sync::Arc;
 
 type HandlerResult<T> = Result<T, (StatusCode, String)>;
 
+/// Produk diarsipkan: hilang dari katalog dan checkout, tapi riwayat order tetap utuh.
 pub async fn delete_product(
     State(state): State<Arc<AppState>>,
     AdminAuth(_): AdminAuth,
@@ -22,7 +23,7 @@ pub async fn delete_product(
             if rows_affected > 0 {
                 Ok((
                     StatusCode::OK,
-                    Json(ApiResponse::<()>::success("Produk berhasil dihapus")),
+                    Json(ApiResponse::<()>::success("Produk berhasil diarsipkan")),
                 ))
             } else {
                 Err((StatusCode::NOT_FOUND, "Produk tidak ditemukan.".to_string()))
@@ -0,0 +1,67 @@
+use crate::AppState;
+use crate::dtos::product::RodProduct;
+use crate::dtos::product_transfer::ExportParams;
+use crate::middleware::auth::AdminAuth;
+use crate::utils::product_transfer;
+use axum::{
+    extract::{Query, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Export the product catalog (Admin only)
+///
+/// Downloads all non-archived products as CSV or JSON in the format accepted by
+/// `POST /products/import`. Prices are regular prices; sale prices are not included.
+#[utoipa::path(
+    get,
+    path = "/products/export",
+    tag = "products",
+    params(ExportParams),
+    responses(
+        (status = 200, description = "Catalog file (attachment)"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn export_products(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Query(params): Query<ExportParams>,
+) -> HandlerResult<impl IntoResponse> {
+    let internal_error = |e: String| {
+        eprintln!("Export Error: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Terjadi kesalahan internal pada server.".to_string(),
+        )
+    };
+
+    let rows = RodProduct::find_for_export(&state.db)
+        .await
+        .map_err(|e| internal_error(e.to_string()))?;
+    let body = product_transfer::render(rows, params.format).map_err(internal_error)?;
+
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                params.format.content_type().to_string(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"products.{}\"",
+                    params.format.extension()
+                ),
+            ),
+        ],
+        body,
+    ))
+}
@@ -1,5 +1,7 @@
 use crate::AppState;
-use crate::dtos::product::RodProduct;
+use crate::dtos::attribute::AttributeFilter;
+use crate::dtos::product::{ProductFilterParams, RodProduct};
+use std::collections::HashMap;
 use crate::utils::ApiResponse;
 use axum::{Json, extract::{State, Query}, response::IntoResponse};
 use std::sync::Arc;
@@ -7,24 +9,46 @@ use crate::dtos::pagination::PaginationParams;
 
 /// Get all products with pagination
 ///
-/// Returns a paginated list of all fishing rod products with their details.
+/// Returns a paginated list of fishing rod products. Supports filtering by category,
+/// price range, action, power, material, rod length (feet) and a text query, and
+/// sorting by `newest`, `price_asc`, `price_desc`, `name` or `popularity`.
+/// Category attributes can be filtered with `attr.<code>=value`, `attr.<code>.min=n`
+/// and `attr.<code>.max=n` (e.g. `attr.gear_ratio.min=6`).
 #[utoipa::path(
     get,
     path = "/products",
     tag = "products",
     params(
-        PaginationParams
+        PaginationParams,
+        ProductFilterParams
     ),
     responses(
         (status = 200, description = "List of products retrieved successfully"),
+        (status = 400, description = "Invalid filter parameters"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn get_all_products(
     State(state): State<Arc<AppState>>,
     Query(params): Query<PaginationParams>,
+    Query(filter): Query<ProductFilterParams>,
+    Query(raw_query): Query<HashMap<String, String>>,
 ) -> impl IntoResponse {
-    match RodProduct::get_all_paginated(&state.db, params).await {
+    let attribute_filters = match filter
+        .validate()
+        .and_then(|_| AttributeFilter::parse_all(&raw_query))
+    {
+        Ok(filters) => filters,
+        Err(msg) => {
+            return (
+                axum::http::StatusCode::BAD_REQUEST,
+                Json(ApiResponse::<()>::bad_request(&msg)),
+            )
+                .into_response();
+        }
+    };
+
+    match RodProduct::get_all_paginated(&state.db, params, &filter, &attribute_filters, false).await {
         Ok(paginated) => Json(ApiResponse::success_data(
             "Daftar produk berhasil diambil",
             paginated,
@@ -0,0 +1,59 @@
+use crate::AppState;
+use crate::dtos::pagination::PaginationParams;
+use crate::dtos::product::{ProductFilterParams, RodProduct};
+use crate::middleware::auth::AdminAuth;
+use crate::utils::ApiResponse;
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Get archived products (Admin only)
+///
+/// Lists products hidden from the catalog by the delete endpoint, newest first.
+/// They can be brought back with `POST /products/{id}/restore`.
+#[utoipa::path(
+    get,
+    path = "/products/archived",
+    tag = "products",
+    params(PaginationParams),
+    responses(
+        (status = 200, description = "Paginated archived products"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_archived_products(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    let products = RodProduct::get_all_paginated(
+        &state.db,
+        params,
+        &ProductFilterParams::default(),
+        &[],
+        true,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Error fetching archived products: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Gagal mengambil arsip produk: {}", e),
+        )
+    })?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar produk yang diarsipkan berhasil diambil",
+        products,
+    )))
+}
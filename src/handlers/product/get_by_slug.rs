@@ -0,0 +1,77 @@
+use crate::AppState;
+use crate::dtos::product::{ProductSlugRedirect, RodProduct};
+use crate::utils::ApiResponse;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Get product by slug
+///
+/// Returns the same details as `GET /products/{id}`, looked up by the product's URL slug.
+/// When an old slug is requested (the product was renamed or its slug changed), responds with
+/// `301 Moved Permanently`, a `Location` header pointing to the current slug, and the
+/// canonical slug in `data`.
+#[utoipa::path(
+    get,
+    path = "/products/slug/{slug}",
+    tag = "products",
+    params(
+        ("slug" = String, Path, description = "Product slug (current or old)")
+    ),
+    responses(
+        (status = 200, description = "Product details retrieved successfully"),
+        (status = 301, description = "Old slug; redirect to the canonical slug", body = ProductSlugRedirect),
+        (status = 404, description = "Product not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn find_product_by_slug(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+) -> HandlerResult<Response> {
+    let internal_error = |e: sqlx::Error| {
+        eprintln!("Database Error: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Terjadi kesalahan internal pada server.".to_string(),
+        )
+    };
+    let not_found = || (StatusCode::NOT_FOUND, "Produk tidak ditemukan.".to_string());
+
+    let (product_id, canonical_slug) = RodProduct::find_by_slug(&state.db, &slug)
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(not_found)?;
+
+    if canonical_slug != slug {
+        let location = format!("/products/slug/{}", canonical_slug);
+        return Ok((
+            StatusCode::MOVED_PERMANENTLY,
+            [(header::LOCATION, location)],
+            Json(ApiResponse::success_data(
+                "Produk pindah ke slug baru",
+                ProductSlugRedirect {
+                    product_id,
+                    slug: canonical_slug,
+                },
+            )),
+        )
+            .into_response());
+    }
+
+    let product = RodProduct::find_detail_by_id(&state.db, product_id)
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(not_found)?;
+    Ok(Json(ApiResponse::success_data(
+        "Detail produk berhasil diambil",
+        product,
+    ))
+    .into_response())
+}
@@ -0,0 +1,242 @@
+use super::prepare_product;
+use crate::AppState;
+use crate::dtos::product::{NewRodProductDto, RodProduct};
+use crate::dtos::product_transfer::{
+    ImportAction, ImportParams, ImportReport, ImportRowResult, TransferFormat,
+};
+use crate::dtos::variant::validate_sku;
+use crate::middleware::auth::AdminAuth;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use crate::utils::product_transfer;
+use crate::utils::rod_specs::RodSpecs;
+use axum::{
+    extract::{Json, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use sqlx::{MySql, Pool};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Validasi seluruh file lalu (jika semua baris valid dan bukan dry-run) simpan dalam satu
+/// transaksi. Dipakai endpoint impor dan perintah CLI `import-products`.
+pub(crate) async fn run_import(
+    pool: &Pool<MySql>,
+    content: &str,
+    format: TransferFormat,
+    dry_run: bool,
+    changed_by: Option<i64>,
+) -> HandlerResult<ImportReport> {
+    let parsed =
+        product_transfer::parse(content, format).map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let mut results: Vec<ImportRowResult> = Vec::with_capacity(parsed.len());
+    let mut prepared: Vec<(Option<i64>, NewRodProductDto, RodSpecs)> = Vec::new();
+    let mut seen_skus: HashSet<String> = HashSet::new();
+    let mut seen_slugs: HashSet<String> = HashSet::new();
+
+    for (index, parsed_row) in parsed.into_iter().enumerate() {
+        let mut result = ImportRowResult {
+            row: index + 1,
+            sku: None,
+            action: ImportAction::Error,
+            product_id: None,
+            error: None,
+        };
+
+        let checked = match parsed_row {
+            Ok(row) => {
+                let sku = row.sku.trim().to_string();
+                result.sku = Some(sku.clone());
+                prepare_row(pool, row.into(), &sku, &mut seen_skus, &mut seen_slugs).await?
+            }
+            Err(message) => Err(message),
+        };
+        match checked {
+            Ok((existing_id, product, specs)) => {
+                result.action = if existing_id.is_some() {
+                    ImportAction::Update
+                } else {
+                    ImportAction::Create
+                };
+                result.product_id = existing_id;
+                prepared.push((existing_id, product, specs));
+            }
+            Err(message) => result.error = Some(message),
+        }
+        results.push(result);
+    }
+
+    let failed = results
+        .iter()
+        .filter(|r| r.action == ImportAction::Error)
+        .count();
+    let applied = failed == 0 && !dry_run;
+
+    if applied {
+        let ids = RodProduct::import(pool, prepared, changed_by)
+            .await
+            .map_err(|e| {
+                if let Some(message) = super::duplicate_key_message(&e) {
+                    return (
+                        StatusCode::CONFLICT,
+                        format!("{} (baru saja berubah), ulangi impor", message),
+                    );
+                }
+                eprintln!("Database Error: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Terjadi kesalahan internal pada server.".to_string(),
+                )
+            })?;
+        for (result, id) in results.iter_mut().zip(ids) {
+            result.product_id = Some(id);
+        }
+    }
+
+    Ok(ImportReport {
+        dry_run,
+        applied,
+        total: results.len(),
+        created: results
+            .iter()
+            .filter(|r| r.action == ImportAction::Create)
+            .count(),
+        updated: results
+            .iter()
+            .filter(|r| r.action == ImportAction::Update)
+            .count(),
+        failed,
+        rows: results,
+    })
+}
+
+/// Validasi satu baris dengan aturan yang sama seperti create/update produk.
+/// Error baris dikembalikan sebagai `Ok(Err(..))`; `Err` hanya untuk kegagalan server.
+async fn prepare_row(
+    pool: &Pool<MySql>,
+    mut product: NewRodProductDto,
+    sku: &str,
+    seen_skus: &mut HashSet<String>,
+    seen_slugs: &mut HashSet<String>,
+) -> HandlerResult<Result<(Option<i64>, NewRodProductDto, RodSpecs), String>> {
+    if let Err(message) = validate_sku(sku) {
+        return Ok(Err(message));
+    }
+    if !seen_skus.insert(sku.to_string()) {
+        return Ok(Err("SKU muncul lebih dari sekali di file".to_string()));
+    }
+
+    let specs = match prepare_product(pool, &mut product).await {
+        Ok(specs) => specs,
+        Err((StatusCode::BAD_REQUEST, message)) => return Ok(Err(message)),
+        Err(e) => return Err(e),
+    };
+
+    let internal_error = |e: sqlx::Error| {
+        eprintln!("Database Error: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Terjadi kesalahan internal pada server.".to_string(),
+        )
+    };
+    let existing_id = match RodProduct::find_by_sku(pool, sku)
+        .await
+        .map_err(internal_error)?
+    {
+        Some((_, true)) => {
+            return Ok(Err(
+                "Produk dengan SKU ini diarsipkan, pulihkan dulu".to_string()
+            ));
+        }
+        Some((id, false)) => Some(id),
+        None => None,
+    };
+
+    if let Some(slug) = &product.slug {
+        if !seen_slugs.insert(slug.clone()) {
+            return Ok(Err("Slug muncul lebih dari sekali di file".to_string()));
+        }
+        let owner = RodProduct::find_id_by_slug(pool, slug)
+            .await
+            .map_err(internal_error)?;
+        if owner.is_some_and(|owner| Some(owner) != existing_id) {
+            return Ok(Err("Slug sudah dipakai produk lain".to_string()));
+        }
+    }
+    Ok(Ok((existing_id, product, specs)))
+}
+
+/// Import products from CSV or JSON (Admin only)
+///
+/// Upserts products by `sku` from a file in the same format as `GET /products/export`.
+/// Every row is validated like `POST /products/create`; the import is applied in one
+/// transaction only when all rows are valid. Use `dry_run=true` to get the per-row
+/// report without saving anything. The request body is the raw file content.
+#[utoipa::path(
+    post,
+    path = "/products/import",
+    tag = "products",
+    params(ImportParams),
+    request_body(content = String, description = "CSV or JSON file content", content_type = "text/plain"),
+    responses(
+        (status = 200, description = "Per-row report; applied unless dry_run"),
+        (status = 400, description = "Unreadable file, or invalid rows (per-row report in data, nothing saved)"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 409, description = "A SKU was taken by another product while importing"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn import_products(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Query(params): Query<ImportParams>,
+    content: String,
+) -> HandlerResult<impl IntoResponse> {
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Terjadi kesalahan internal pada server.".to_string(),
+            )
+        })?;
+
+    let report = run_import(&state.db, &content, params.format, params.dry_run, admin_id).await?;
+
+    if report.failed > 0 {
+        return Ok((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error_data(
+                format!(
+                    "{} dari {} baris tidak valid, tidak ada yang disimpan.",
+                    report.failed, report.total
+                ),
+                report,
+            )),
+        ));
+    }
+
+    let message = if report.dry_run {
+        format!(
+            "Dry-run: {} produk baru dan {} diperbarui jika diimpor",
+            report.created, report.updated
+        )
+    } else {
+        format!(
+            "Impor selesai: {} produk baru, {} diperbarui",
+            report.created, report.updated
+        )
+    };
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success_data_with_message(message, report)),
+    ))
+}
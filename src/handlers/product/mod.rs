@@ -1,5 +1,135 @@
 pub mod create;
 pub mod delete;
+pub mod export;
 pub mod get_all;
+pub mod get_archived;
 pub mod get_by_id;
+pub mod get_by_slug;
+pub mod import;
+pub mod restore;
+pub mod search;
+pub mod suggest;
 pub mod update;
+
+use crate::dtos::attribute::validate_attributes;
+use crate::dtos::product::NewRodProductDto;
+use crate::dtos::variant::validate_sku;
+use crate::models::attribute::AttributeModel;
+use crate::models::category::KategoriModel;
+use crate::utils::rod_specs::RodSpecs;
+use crate::utils::slug::validate_slug;
+use axum::http::StatusCode;
+use sqlx::{MySql, Pool};
+
+/// Panjang maksimal meta SEO (kolom `meta_title` / `meta_description`).
+const MAX_META_TITLE_LEN: usize = 255;
+const MAX_META_DESCRIPTION_LEN: usize = 500;
+
+/// Validasi input create/update produk: spesifikasi joran di-parse ke angka, dan
+/// `attributes` dicek terhadap skema kategori (lalu disimpan dalam bentuk yang sudah bersih).
+pub(crate) async fn prepare_product(
+    pool: &Pool<MySql>,
+    product: &mut NewRodProductDto,
+) -> Result<RodSpecs, (StatusCode, String)> {
+    if !product.price.is_positive() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Harga produk harus lebih dari 0".to_string(),
+        ));
+    }
+
+    product.sku = product
+        .sku
+        .take()
+        .map(|sku| sku.trim().to_string())
+        .filter(|sku| !sku.is_empty());
+    if let Some(sku) = &product.sku {
+        validate_sku(sku).map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+    }
+
+    product.slug = non_empty(product.slug.take());
+    if let Some(slug) = &product.slug {
+        validate_slug(slug).map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+    }
+    product.meta_title = non_empty(product.meta_title.take());
+    product.meta_description = non_empty(product.meta_description.take());
+    if product
+        .meta_title
+        .as_ref()
+        .is_some_and(|t| t.chars().count() > MAX_META_TITLE_LEN)
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("meta_title maksimal {} karakter", MAX_META_TITLE_LEN),
+        ));
+    }
+    if product
+        .meta_description
+        .as_ref()
+        .is_some_and(|d| d.chars().count() > MAX_META_DESCRIPTION_LEN)
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "meta_description maksimal {} karakter",
+                MAX_META_DESCRIPTION_LEN
+            ),
+        ));
+    }
+
+    let specs = RodSpecs::parse(
+        product.rod_length.as_deref(),
+        product.line_weight.as_deref(),
+        product.cast_weight.as_deref(),
+    )
+    .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let internal_error = |e: sqlx::Error| {
+        eprintln!("Database Error: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Terjadi kesalahan internal pada server.".to_string(),
+        )
+    };
+    // Kategori yang diarsipkan tidak bisa dipakai produk baru
+    if KategoriModel::find_by_id(pool, product.category_id)
+        .await
+        .map_err(internal_error)?
+        .is_none()
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Kategori tidak ditemukan".to_string(),
+        ));
+    }
+
+    let schema = AttributeModel::find_by_category(pool, product.category_id)
+        .await
+        .map_err(internal_error)?;
+    let attributes = product.attributes.take().unwrap_or_default();
+    let cleaned =
+        validate_attributes(&schema, &attributes).map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+    product.attributes = (!cleaned.is_empty()).then_some(cleaned);
+
+    Ok(specs)
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Pesan 409 jika SKU atau slug produk bentrok dengan produk lain
+/// (unique index `uq_products_sku` / `uq_products_slug`).
+pub(crate) fn duplicate_key_message(e: &sqlx::Error) -> Option<&'static str> {
+    let db_error = e.as_database_error()?;
+    if !db_error.is_unique_violation() {
+        return None;
+    }
+    if db_error.message().contains("uq_products_slug") {
+        Some("Slug sudah dipakai produk lain")
+    } else {
+        Some("SKU sudah dipakai produk lain")
+    }
+}
@@ -0,0 +1,61 @@
+use crate::AppState;
+use crate::dtos::product::RodProduct;
+use crate::middleware::auth::AdminAuth;
+use crate::utils::ApiResponse;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Restore an archived product (Admin only)
+///
+/// Puts the product back in the catalog. Its category must not be archived.
+#[utoipa::path(
+    post,
+    path = "/products/{id}/restore",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Product restored"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "No archived product with this ID"),
+        (status = 409, description = "The product's category is archived"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn restore_product(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(product_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    match RodProduct::restore(&state.db, product_id).await {
+        Ok(Some(true)) => Ok(Json(ApiResponse::<()>::success(
+            "Produk berhasil dipulihkan",
+        ))),
+        Ok(Some(false)) => Err((
+            StatusCode::CONFLICT,
+            "Kategori produk ini masih diarsipkan. Pulihkan kategorinya dulu.".to_string(),
+        )),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            "Produk tidak ditemukan di arsip.".to_string(),
+        )),
+        Err(e) => {
+            eprintln!("Error restoring product: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Gagal memulihkan produk: {}", e),
+            ))
+        }
+    }
+}
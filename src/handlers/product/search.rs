@@ -0,0 +1,128 @@
+use crate::AppState;
+use crate::dtos::pagination::{PaginationMeta, PaginationParams};
+use crate::dtos::product::{
+    ProductSearchHit, ProductSearchParams, ProductSearchResponse, RodProduct,
+};
+use crate::models::search::SearchModel;
+use crate::utils::ApiResponse;
+use crate::utils::search::{correct_query, highlight, tokenize};
+use crate::utils::suggest;
+use axum::{
+    extract::{Json, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+const SNIPPET_LENGTH: usize = 160;
+
+fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
+
+/// Search products
+///
+/// Full-text search over product name, description, specs and category, ranked by
+/// relevance with highlighted snippets. When nothing matches, typos are corrected
+/// against the catalogue vocabulary (e.g. "shimano jorann" → "shimano joran").
+#[utoipa::path(
+    get,
+    path = "/products/search",
+    tag = "products",
+    params(
+        ProductSearchParams,
+        PaginationParams
+    ),
+    responses(
+        (status = 200, description = "Search results ranked by relevance", body = ProductSearchResponse),
+        (status = 400, description = "Missing search query"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn search_products(
+    State(state): State<Arc<AppState>>,
+    Query(search): Query<ProductSearchParams>,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    let query = search.q.trim().to_string();
+    if query.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Parameter q wajib diisi".to_string(),
+        ));
+    }
+
+    let limit = params.per_page;
+    let offset = params.offset();
+
+    let (mut rows, mut total) = RodProduct::search_fulltext(&state.db, &query, limit, offset)
+        .await
+        .map_err(internal_server_error)?;
+
+    // Tidak ada hasil: coba koreksi typo berdasarkan kosakata katalog
+    let mut corrected_query = None;
+    if total == 0 {
+        let vocabulary = RodProduct::search_vocabulary(&state.db)
+            .await
+            .map_err(internal_server_error)?;
+        if let Some(corrected) = correct_query(&query, &vocabulary) {
+            (rows, total) = RodProduct::search_fulltext(&state.db, &corrected, limit, offset)
+                .await
+                .map_err(internal_server_error)?;
+            corrected_query = Some(corrected);
+        }
+    }
+
+    let effective_query = corrected_query.as_deref().unwrap_or(&query);
+    let terms = tokenize(effective_query);
+
+    // Masih kosong (kata terlalu pendek / stopword FULLTEXT): pakai pencocokan LIKE
+    if total == 0 && !terms.is_empty() {
+        (rows, total) = RodProduct::search_like(&state.db, &terms, limit, offset)
+            .await
+            .map_err(internal_server_error)?;
+    }
+
+    // Query yang memberi hasil dicatat sebagai kandidat saran "pencarian populer"
+    if total > 0 {
+        let popular: String = suggest::normalize(effective_query)
+            .chars()
+            .take(100)
+            .collect();
+        if let Err(e) = SearchModel::record_query(&state.db, &popular).await {
+            eprintln!("Gagal mencatat query pencarian: {}", e);
+        }
+        suggest::record_query(&popular);
+    }
+
+    let results = rows
+        .into_iter()
+        .map(|row| ProductSearchHit {
+            id: row.id,
+            name: highlight(&row.name, &terms, row.name.chars().count()),
+            slug: row.slug,
+            category_name: row.category_name,
+            price: row.price,
+            final_price: row.final_price,
+            image_url: row.image_url,
+            score: row.score,
+            snippet: highlight(&row.description, &terms, SNIPPET_LENGTH),
+        })
+        .collect();
+
+    Ok(Json(ApiResponse::success_data(
+        "Hasil pencarian produk",
+        ProductSearchResponse {
+            query,
+            corrected_query,
+            results,
+            pagination: PaginationMeta::new(params.page, params.per_page, total as u32),
+        },
+    )))
+}
@@ -0,0 +1,34 @@
+use crate::dtos::search::{SuggestParams, SuggestResponse};
+use crate::utils::ApiResponse;
+use crate::utils::suggest;
+use axum::{
+    extract::{Json, Query},
+    response::IntoResponse,
+};
+
+const DEFAULT_LIMIT: usize = 5;
+const MAX_LIMIT: usize = 10;
+
+/// Search suggestions
+///
+/// Autocomplete for the search box. Returns product names, categories and popular
+/// queries starting with the typed prefix, served from an in-memory index.
+#[utoipa::path(
+    get,
+    path = "/products/suggest",
+    tag = "products",
+    params(
+        SuggestParams
+    ),
+    responses(
+        (status = 200, description = "Suggestions grouped by type", body = SuggestResponse)
+    )
+)]
+pub async fn suggest_products(Query(params): Query<SuggestParams>) -> impl IntoResponse {
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    Json(ApiResponse::success_data(
+        "Saran pencarian",
+        suggest::suggest(&params.q, limit),
+    ))
+}
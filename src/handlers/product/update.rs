@@ -1,6 +1,8 @@
+use super::{duplicate_key_message, prepare_product};
 use crate::AppState;
 use crate::dtos::product::{NewRodProductDto, RodProduct};
 use crate::middleware::auth::AdminAuth;
+use crate::models::user::User;
 use crate::utils::ApiResponse;
 use axum::{
     extract::{Json, Path, State},
@@ -13,16 +15,31 @@ type HandlerResult<T> = Result<T, (StatusCode, String)>;
 
 pub async fn update_product(
     State(state): State<Arc<AppState>>,
-    AdminAuth(_): AdminAuth,
+    AdminAuth(admin): AdminAuth,
     Path(product_id): Path<i64>,
-    Json(updated_product_dto): Json<NewRodProductDto>,
+    Json(mut updated_product_dto): Json<NewRodProductDto>,
 ) -> HandlerResult<impl IntoResponse> {
-    match RodProduct::update(&state.db, product_id, updated_product_dto).await {
+    let specs = prepare_product(&state.db, &mut updated_product_dto).await?;
+
+    let admin_id = User::find_id_by_email(&state.db, &admin.email)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Terjadi kesalahan internal pada server.".to_string(),
+            )
+        })?;
+
+    match RodProduct::update(&state.db, product_id, updated_product_dto, specs, admin_id).await {
         Ok(_) => Ok((
             StatusCode::OK,
             Json(ApiResponse::<()>::success("Produk berhasil diperbarui")),
         )),
         Err(e) => {
+            if let Some(message) = duplicate_key_message(&e) {
+                return Err((StatusCode::CONFLICT, message.to_string()));
+            }
             eprintln!("Error updating product: {}", e);
             Err((
                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -0,0 +1,31 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::product_image::ProductImageModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// File di storage dihapus setelah barisnya terhapus; kegagalan hanya dicatat di log.
+pub async fn delete_product_image(
+    State(state): State<Arc<AppState>>,
+    AdminAuth(_): AdminAuth,
+    Path((product_id, image_id)): Path<(i64, i64)>,
+) -> HandlerResult<impl IntoResponse> {
+    let keys = ProductImageModel::delete(&state.db, product_id, image_id)
+        .await
+        .map_err(internal_server_error)?
+        .ok_or((StatusCode::NOT_FOUND, "Gambar tidak ditemukan".to_string()))?;
+
+    for key in keys.keys() {
+        if let Err(e) = state.storage.delete(key).await {
+            eprintln!("Storage Error: {}", e);
+        }
+    }
+
+    Ok(Json(ApiResponse::<()>::success("Gambar berhasil dihapus")))
+}
@@ -0,0 +1,39 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::product_image::ProductImage;
+use crate::models::product_image::ProductImageModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get product images
+///
+/// Returns the product's images in display order, each in original, medium and thumbnail size.
+#[utoipa::path(
+    get,
+    path = "/products/{id}/images",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Product images", body = [ProductImage]),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_product_images(
+    State(state): State<Arc<AppState>>,
+    Path(product_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    let images = ProductImageModel::find_by_product(&state.db, product_id)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Gambar produk berhasil diambil",
+        images,
+    )))
+}
@@ -0,0 +1,27 @@
+pub mod delete;
+pub mod get_all;
+pub mod reorder;
+pub mod upload;
+
+use axum::http::StatusCode;
+
+pub(crate) type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Batas ukuran request unggahan (semua file dalam satu request).
+pub(crate) const MAX_UPLOAD_BYTES: usize = 20 * 1024 * 1024;
+
+pub(crate) fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
+
+pub(crate) fn storage_error(e: String) -> (StatusCode, String) {
+    eprintln!("Storage Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Gagal menyimpan file gambar.".to_string(),
+    )
+}
@@ -0,0 +1,58 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::product_image::ImageOrderDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::product_image::ProductImageModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Reorder product images (Admin only)
+///
+/// `image_ids` must list every image of the product exactly once, in the new display order.
+#[utoipa::path(
+    put,
+    path = "/products/{id}/images/order",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    request_body = ImageOrderDto,
+    responses(
+        (status = 200, description = "Image order updated"),
+        (status = 400, description = "image_ids does not match the product's images"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn reorder_product_images(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(product_id): Path<i64>,
+    Json(order): Json<ImageOrderDto>,
+) -> HandlerResult<impl IntoResponse> {
+    order
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    if !ProductImageModel::reorder(&state.db, product_id, &order.image_ids)
+        .await
+        .map_err(internal_server_error)?
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "image_ids harus berisi semua gambar produk ini".to_string(),
+        ));
+    }
+
+    Ok(Json(ApiResponse::<()>::success(
+        "Urutan gambar berhasil diperbarui",
+    )))
+}
@@ -0,0 +1,158 @@
+use super::{HandlerResult, internal_server_error, storage_error};
+use crate::AppState;
+use crate::dtos::product_image::{ImageUploadForm, NewProductImage, StoredImageKeys};
+use crate::middleware::auth::AdminAuth;
+use crate::models::product_image::ProductImageModel;
+use crate::utils::ApiResponse;
+use crate::utils::images::{self, ProcessedImage};
+use crate::utils::storage::Storage;
+use axum::{
+    extract::{Json, Multipart, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use rand::RngCore;
+use std::sync::Arc;
+
+const MAX_FILES_PER_UPLOAD: usize = 10;
+
+/// Upload product images (Admin only)
+///
+/// Accepts one or more JPEG/PNG/WebP files in the `image` field. Each file is stored as-is
+/// plus resized medium (800px) and thumbnail (200px) JPEG copies, and appended to the
+/// product's gallery. The first image becomes the product's `image_url`.
+#[utoipa::path(
+    post,
+    path = "/products/{id}/images/upload",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    request_body(content = ImageUploadForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Images uploaded; returns the new image IDs"),
+        (status = 400, description = "Missing file or unsupported image format"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Product not found"),
+        (status = 413, description = "Upload too large"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn upload_product_images(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(product_id): Path<i64>,
+    mut multipart: Multipart,
+) -> HandlerResult<impl IntoResponse> {
+    if !ProductImageModel::product_exists(&state.db, product_id)
+        .await
+        .map_err(internal_server_error)?
+    {
+        return Err((StatusCode::NOT_FOUND, "Produk tidak ditemukan".to_string()));
+    }
+
+    let mut files = Vec::new();
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| (e.status(), e.body_text()))?
+    {
+        if field.name() != Some("image") {
+            continue;
+        }
+        if files.len() == MAX_FILES_PER_UPLOAD {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("Maksimal {} gambar per unggahan", MAX_FILES_PER_UPLOAD),
+            ));
+        }
+        let bytes = field
+            .bytes()
+            .await
+            .map_err(|e| (e.status(), e.body_text()))?;
+        files.push(bytes.to_vec());
+    }
+    if files.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Sertakan minimal satu file pada field 'image'".to_string(),
+        ));
+    }
+
+    // Semua file diolah dulu supaya satu file rusak tidak meninggalkan unggahan setengah jadi
+    let mut processed = Vec::with_capacity(files.len());
+    for bytes in files {
+        let image = tokio::task::spawn_blocking(move || images::process(bytes))
+            .await
+            .map_err(|e| storage_error(e.to_string()))?
+            .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+        processed.push(image);
+    }
+
+    let mut ids = Vec::with_capacity(processed.len());
+    for image in processed {
+        let dir = format!("products/{}", product_id);
+        let stored = store(state.storage.as_ref(), &dir, image)
+            .await
+            .map_err(storage_error)?;
+        let keys: Vec<String> = stored.keys.keys().map(str::to_string).collect();
+
+        match ProductImageModel::insert(&state.db, product_id, stored).await {
+            Ok(id) => ids.push(id),
+            Err(e) => {
+                for key in &keys {
+                    let _ = state.storage.delete(key).await;
+                }
+                return Err(internal_server_error(e));
+            }
+        }
+    }
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_data_with_message(
+            format!("{} gambar berhasil diunggah", ids.len()),
+            ids,
+        )),
+    ))
+}
+
+/// Simpan ketiga ukuran di `{parent}/{token}/`, mis. `products/{id}/{token}/`.
+/// Dipakai juga untuk foto ulasan.
+pub(crate) async fn store(
+    storage: &dyn Storage,
+    parent: &str,
+    image: ProcessedImage,
+) -> Result<NewProductImage, String> {
+    let mut token = [0u8; 8];
+    rand::thread_rng().fill_bytes(&mut token);
+    let dir = format!("{}/{}", parent, hex::encode(token));
+
+    let original_key = format!("{}/original.{}", dir, image.extension);
+    let medium_key = format!("{}/medium.jpg", dir);
+    let thumbnail_key = format!("{}/thumbnail.jpg", dir);
+
+    storage
+        .put(&original_key, image.original, image.content_type)
+        .await?;
+    storage.put(&medium_key, image.medium, "image/jpeg").await?;
+    storage
+        .put(&thumbnail_key, image.thumbnail, "image/jpeg")
+        .await?;
+
+    Ok(NewProductImage {
+        original_url: storage.public_url(&original_key),
+        medium_url: storage.public_url(&medium_key),
+        thumbnail_url: storage.public_url(&thumbnail_key),
+        keys: StoredImageKeys {
+            original_key: Some(original_key),
+            medium_key: Some(medium_key),
+            thumbnail_key: Some(thumbnail_key),
+        },
+        width: image.width as i32,
+        height: image.height as i32,
+    })
+}
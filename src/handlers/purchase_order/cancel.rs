@@ -0,0 +1,25 @@
+use super::{HandlerResult, purchase_order_error};
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::purchase_order::PurchaseOrderModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Hanya PO berstatus DRAFT atau ORDERED yang bisa dibatalkan.
+pub async fn cancel_purchase_order(
+    State(state): State<Arc<AppState>>,
+    AdminAuth(_): AdminAuth,
+    Path(purchase_order_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    PurchaseOrderModel::cancel(&state.db, purchase_order_id)
+        .await
+        .map_err(purchase_order_error)?;
+
+    Ok(Json(ApiResponse::<()>::success(
+        "Purchase order berhasil dibatalkan",
+    )))
+}
@@ -0,0 +1,61 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::inventory::StockError;
+use crate::dtos::purchase_order::NewPurchaseOrderDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::purchase_order::PurchaseOrderModel;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Create a purchase order (Admin only)
+///
+/// Drafts a replenishment order to a supplier. Items of products with variants must name
+/// the variant. Stock is only added once the order is received.
+#[utoipa::path(
+    post,
+    path = "/purchase-orders/create",
+    tag = "purchase-orders",
+    request_body = NewPurchaseOrderDto,
+    responses(
+        (status = 201, description = "Purchase order created as DRAFT"),
+        (status = 400, description = "Invalid purchase order data"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Supplier, warehouse or product not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn create_purchase_order(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Json(order): Json<NewPurchaseOrderDto>,
+) -> HandlerResult<impl IntoResponse> {
+    order
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(internal_server_error)?;
+
+    match PurchaseOrderModel::create(&state.db, order, admin_id).await {
+        Ok(id) => Ok((
+            StatusCode::CREATED,
+            Json(ApiResponse::success_data_with_message(
+                format!("Purchase order berhasil dibuat dengan ID: {}", id),
+                id,
+            )),
+        )),
+        Err(StockError::NotFound(message)) => Err((StatusCode::NOT_FOUND, message)),
+        Err(StockError::Invalid(message)) => Err((StatusCode::BAD_REQUEST, message)),
+        Err(StockError::Database(e)) => Err(internal_server_error(e)),
+    }
+}
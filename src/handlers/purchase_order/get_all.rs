@@ -0,0 +1,45 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::pagination::PaginationParams;
+use crate::dtos::purchase_order::PurchaseOrderListParams;
+use crate::middleware::auth::AdminAuth;
+use crate::models::purchase_order::PurchaseOrderModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Query, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get purchase orders (Admin only)
+///
+/// Lists purchase orders newest first, optionally filtered by status.
+#[utoipa::path(
+    get,
+    path = "/purchase-orders",
+    tag = "purchase-orders",
+    params(PurchaseOrderListParams, PaginationParams),
+    responses(
+        (status = 200, description = "Paginated purchase orders"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_all_purchase_orders(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Query(filter): Query<PurchaseOrderListParams>,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    let orders = PurchaseOrderModel::find_paginated(&state.db, filter.status, params)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar purchase order berhasil diambil",
+        orders,
+    )))
+}
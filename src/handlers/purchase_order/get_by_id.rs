@@ -0,0 +1,51 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::purchase_order::PurchaseOrderDetail;
+use crate::middleware::auth::AdminAuth;
+use crate::models::purchase_order::PurchaseOrderModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get a purchase order (Admin only)
+///
+/// Returns the purchase order with its items and received quantities.
+#[utoipa::path(
+    get,
+    path = "/purchase-orders/{id}",
+    tag = "purchase-orders",
+    params(
+        ("id" = i64, Path, description = "Purchase order ID")
+    ),
+    responses(
+        (status = 200, description = "Purchase order detail", body = PurchaseOrderDetail),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Purchase order not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_purchase_order(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(purchase_order_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    let order = PurchaseOrderModel::find_detail(&state.db, purchase_order_id)
+        .await
+        .map_err(internal_server_error)?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            "Purchase order tidak ditemukan".to_string(),
+        ))?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Purchase order berhasil diambil",
+        order,
+    )))
+}
@@ -0,0 +1,28 @@
+pub mod cancel;
+pub mod create;
+pub mod get_all;
+pub mod get_by_id;
+pub mod order;
+pub mod receive;
+
+use crate::dtos::inventory::StockError;
+use axum::http::StatusCode;
+
+pub(crate) type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+pub(crate) fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
+
+/// Status tidak sesuai alur (mis. menerima PO yang masih DRAFT) dilaporkan sebagai 409.
+pub(crate) fn purchase_order_error(e: StockError) -> (StatusCode, String) {
+    match e {
+        StockError::NotFound(message) => (StatusCode::NOT_FOUND, message),
+        StockError::Invalid(message) => (StatusCode::CONFLICT, message),
+        StockError::Database(e) => internal_server_error(e),
+    }
+}
@@ -0,0 +1,46 @@
+use super::{HandlerResult, purchase_order_error};
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::purchase_order::PurchaseOrderModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Mark a purchase order as ordered (Admin only)
+///
+/// Moves a DRAFT order to ORDERED. From then on its quantities count as stock on order
+/// in the low-stock report.
+#[utoipa::path(
+    post,
+    path = "/purchase-orders/{id}/order",
+    tag = "purchase-orders",
+    params(
+        ("id" = i64, Path, description = "Purchase order ID")
+    ),
+    responses(
+        (status = 200, description = "Purchase order marked as ordered"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Purchase order not found"),
+        (status = 409, description = "Purchase order is not a draft"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn order_purchase_order(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(purchase_order_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    PurchaseOrderModel::mark_ordered(&state.db, purchase_order_id)
+        .await
+        .map_err(purchase_order_error)?;
+
+    Ok(Json(ApiResponse::<()>::success(
+        "Purchase order berhasil dipesan ke supplier",
+    )))
+}
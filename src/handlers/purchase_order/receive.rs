@@ -0,0 +1,51 @@
+use super::{HandlerResult, internal_server_error, purchase_order_error};
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::purchase_order::PurchaseOrderModel;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Receive a purchase order (Admin only)
+///
+/// Adds every outstanding item to the order's destination warehouse and records a
+/// RECEIPT movement for each in the stock ledger.
+#[utoipa::path(
+    post,
+    path = "/purchase-orders/{id}/receive",
+    tag = "purchase-orders",
+    params(
+        ("id" = i64, Path, description = "Purchase order ID")
+    ),
+    responses(
+        (status = 200, description = "Goods received into stock"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Purchase order not found"),
+        (status = 409, description = "Purchase order is not in ORDERED status"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn receive_purchase_order(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Path(purchase_order_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(internal_server_error)?;
+
+    PurchaseOrderModel::receive(&state.db, purchase_order_id, admin_id)
+        .await
+        .map_err(purchase_order_error)?;
+
+    Ok(Json(ApiResponse::<()>::success(
+        "Barang purchase order berhasil diterima",
+    )))
+}
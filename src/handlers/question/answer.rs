@@ -0,0 +1,68 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::question::NewAnswerDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::question::QuestionModel;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Answer a product question (Admin only)
+///
+/// The answer is shown immediately, and the asker receives a `QUESTION_ANSWERED`
+/// notification.
+#[utoipa::path(
+    post,
+    path = "/products/{id}/questions/{question_id}/answers/create",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID"),
+        ("question_id" = i64, Path, description = "Question ID")
+    ),
+    request_body = NewAnswerDto,
+    responses(
+        (status = 201, description = "Answer posted; returns the answer ID"),
+        (status = 400, description = "Empty or too long answer"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Question not found for this product"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn answer_question(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Path((product_id, question_id)): Path<(i64, i64)>,
+    Json(dto): Json<NewAnswerDto>,
+) -> HandlerResult<impl IntoResponse> {
+    dto.validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(internal_server_error)?
+        .ok_or((StatusCode::UNAUTHORIZED, "User tidak ditemukan".to_string()))?;
+
+    let id = QuestionModel::answer(&state.db, product_id, question_id, admin_id, &dto.answer)
+        .await
+        .map_err(internal_server_error)?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            "Pertanyaan tidak ditemukan".to_string(),
+        ))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_data_with_message(
+            "Jawaban berhasil dikirim".to_string(),
+            id,
+        )),
+    ))
+}
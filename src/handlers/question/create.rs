@@ -0,0 +1,73 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::product::RodProduct;
+use crate::dtos::question::NewQuestionDto;
+use crate::middleware::auth::AuthUser;
+use crate::models::question::QuestionModel;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Ask a question about a product
+///
+/// Posted questions are shown immediately in the product's Q&A thread. The asker gets a
+/// notification when an admin answers.
+#[utoipa::path(
+    post,
+    path = "/products/{id}/questions/create",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    request_body = NewQuestionDto,
+    responses(
+        (status = 201, description = "Question posted; returns the question ID"),
+        (status = 400, description = "Question too short or too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Product not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn create_question(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+    Path(product_id): Path<i64>,
+    Json(dto): Json<NewQuestionDto>,
+) -> HandlerResult<impl IntoResponse> {
+    dto.validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    // Produk yang diarsipkan tidak menerima pertanyaan baru
+    match RodProduct::find_by_id(&state.db, product_id).await {
+        Ok(_) => {}
+        Err(sqlx::Error::RowNotFound) => {
+            return Err((StatusCode::NOT_FOUND, "Produk tidak ditemukan".to_string()));
+        }
+        Err(e) => return Err(internal_server_error(e)),
+    }
+
+    let user_id = User::find_id_by_email(&state.db, &email)
+        .await
+        .map_err(internal_server_error)?
+        .ok_or((StatusCode::UNAUTHORIZED, "User tidak ditemukan".to_string()))?;
+
+    let id = QuestionModel::create(&state.db, product_id, user_id, &dto.question)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_data_with_message(
+            "Pertanyaan berhasil dikirim".to_string(),
+            id,
+        )),
+    ))
+}
@@ -0,0 +1,52 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::pagination::PaginationParams;
+use crate::models::product_image::ProductImageModel;
+use crate::models::question::QuestionModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get a product's questions and answers
+///
+/// Public Q&A thread, newest question first, each with its answers (oldest first).
+/// Hidden questions and answers are not included.
+#[utoipa::path(
+    get,
+    path = "/products/{id}/questions",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID"),
+        PaginationParams
+    ),
+    responses(
+        (status = 200, description = "Paginated questions with answers"),
+        (status = 404, description = "Product not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_product_questions(
+    State(state): State<Arc<AppState>>,
+    Path(product_id): Path<i64>,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    if !ProductImageModel::product_exists(&state.db, product_id)
+        .await
+        .map_err(internal_server_error)?
+    {
+        return Err((StatusCode::NOT_FOUND, "Produk tidak ditemukan".to_string()));
+    }
+
+    let questions = QuestionModel::find_visible(&state.db, product_id, params)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Tanya jawab produk berhasil diambil",
+        questions,
+    )))
+}
@@ -0,0 +1,50 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::pagination::PaginationParams;
+use crate::dtos::question::QuestionQueueParams;
+use crate::middleware::auth::AdminAuth;
+use crate::models::question::QuestionModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Query, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get product questions for staff (Admin only)
+///
+/// `filter=unanswered` (default, oldest first) lists visible questions without a visible
+/// answer; `answered`, `hidden` and `all` are newest first. Hidden answers are included
+/// with their `hidden_at`.
+#[utoipa::path(
+    get,
+    path = "/questions",
+    tag = "questions",
+    params(
+        QuestionQueueParams,
+        PaginationParams
+    ),
+    responses(
+        (status = 200, description = "Paginated questions with all answers"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_question_queue(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Query(filter): Query<QuestionQueueParams>,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    let questions = QuestionModel::find_for_queue(&state.db, filter.filter, params)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar pertanyaan berhasil diambil",
+        questions,
+    )))
+}
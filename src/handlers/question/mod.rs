@@ -0,0 +1,17 @@
+pub mod answer;
+pub mod create;
+pub mod get_all;
+pub mod get_queue;
+pub mod moderate;
+
+use axum::http::StatusCode;
+
+pub(crate) type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+pub(crate) fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
@@ -0,0 +1,108 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::question::ModerateQnaDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::question::QuestionModel;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Hide or show a question (Admin only)
+///
+/// A hidden question (with all of its answers) disappears from the public thread.
+#[utoipa::path(
+    post,
+    path = "/questions/{id}/moderate",
+    tag = "questions",
+    params(
+        ("id" = i64, Path, description = "Question ID")
+    ),
+    request_body = ModerateQnaDto,
+    responses(
+        (status = 200, description = "Question visibility updated"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Question not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn moderate_question(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Path(question_id): Path<i64>,
+    Json(dto): Json<ModerateQnaDto>,
+) -> HandlerResult<impl IntoResponse> {
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(internal_server_error)?;
+
+    if !QuestionModel::set_question_hidden(&state.db, question_id, dto.hidden, admin_id)
+        .await
+        .map_err(internal_server_error)?
+    {
+        return Err((
+            StatusCode::NOT_FOUND,
+            "Pertanyaan tidak ditemukan".to_string(),
+        ));
+    }
+
+    Ok(Json(ApiResponse::<()>::success(visibility_message(
+        dto.hidden,
+    ))))
+}
+
+/// Hide or show an answer (Admin only)
+#[utoipa::path(
+    post,
+    path = "/questions/answers/{id}/moderate",
+    tag = "questions",
+    params(
+        ("id" = i64, Path, description = "Answer ID")
+    ),
+    request_body = ModerateQnaDto,
+    responses(
+        (status = 200, description = "Answer visibility updated"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Answer not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn moderate_answer(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Path(answer_id): Path<i64>,
+    Json(dto): Json<ModerateQnaDto>,
+) -> HandlerResult<impl IntoResponse> {
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(internal_server_error)?;
+
+    if !QuestionModel::set_answer_hidden(&state.db, answer_id, dto.hidden, admin_id)
+        .await
+        .map_err(internal_server_error)?
+    {
+        return Err((StatusCode::NOT_FOUND, "Jawaban tidak ditemukan".to_string()));
+    }
+
+    Ok(Json(ApiResponse::<()>::success(visibility_message(
+        dto.hidden,
+    ))))
+}
+
+fn visibility_message(hidden: bool) -> &'static str {
+    if hidden {
+        "Berhasil disembunyikan dari publik"
+    } else {
+        "Berhasil ditampilkan kembali"
+    }
+}
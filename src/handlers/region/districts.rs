@@ -0,0 +1,39 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::region::RegionDto;
+use crate::models::region::RegionModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// List districts
+///
+/// Returns the districts of the given regency, for cascading address dropdowns.
+#[utoipa::path(
+    get,
+    path = "/regions/regencies/{id}/districts",
+    tag = "regions",
+    params(
+        ("id" = String, Path, description = "Regency code")
+    ),
+    responses(
+        (status = 200, description = "List of districts", body = [RegionDto]),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_districts(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> HandlerResult<impl IntoResponse> {
+    let regions = RegionModel::find_districts(&state.db, &id)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar kecamatan berhasil diambil",
+        regions,
+    )))
+}
@@ -0,0 +1,33 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::region::RegionDto;
+use crate::models::region::RegionModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// List provinces
+///
+/// Returns every Indonesian province in the bundled region dataset.
+#[utoipa::path(
+    get,
+    path = "/regions/provinces",
+    tag = "regions",
+    responses(
+        (status = 200, description = "List of provinces", body = [RegionDto]),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_provinces(State(state): State<Arc<AppState>>) -> HandlerResult<impl IntoResponse> {
+    let provinces = RegionModel::find_provinces(&state.db)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar provinsi berhasil diambil",
+        provinces,
+    )))
+}
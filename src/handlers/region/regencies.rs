@@ -0,0 +1,39 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::region::RegionDto;
+use crate::models::region::RegionModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// List regencies
+///
+/// Returns the regencies of the given province, for cascading address dropdowns.
+#[utoipa::path(
+    get,
+    path = "/regions/provinces/{id}/regencies",
+    tag = "regions",
+    params(
+        ("id" = String, Path, description = "Province code")
+    ),
+    responses(
+        (status = 200, description = "List of regencies", body = [RegionDto]),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_regencies(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> HandlerResult<impl IntoResponse> {
+    let regions = RegionModel::find_regencies(&state.db, &id)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar kota/kabupaten berhasil diambil",
+        regions,
+    )))
+}
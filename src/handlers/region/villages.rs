@@ -0,0 +1,39 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::region::RegionDto;
+use crate::models::region::RegionModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// List villages
+///
+/// Returns the villages of the given district, for cascading address dropdowns.
+#[utoipa::path(
+    get,
+    path = "/regions/districts/{id}/villages",
+    tag = "regions",
+    params(
+        ("id" = String, Path, description = "District code")
+    ),
+    responses(
+        (status = 200, description = "List of villages", body = [RegionDto]),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_villages(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> HandlerResult<impl IntoResponse> {
+    let regions = RegionModel::find_villages(&state.db, &id)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar kelurahan/desa berhasil diambil",
+        regions,
+    )))
+}
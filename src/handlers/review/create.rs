@@ -0,0 +1,180 @@
+use super::{HandlerResult, current_user_id, internal_server_error};
+use crate::AppState;
+use crate::dtos::review::{NewReview, NewReviewForm};
+use crate::handlers::product_image::{storage_error, upload::store};
+use crate::middleware::auth::AuthUser;
+use crate::models::product_image::ProductImageModel;
+use crate::models::review::ReviewModel;
+use crate::utils::ApiResponse;
+use crate::utils::images;
+use axum::{
+    extract::{Json, Multipart, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+const MAX_PHOTOS_PER_REVIEW: usize = 5;
+
+/// Review a product
+///
+/// Verified buyers only: the user must have a paid order containing the product. One review
+/// per product per user. The form has `rating` (1-5), optional `title`, `body`, and up to
+/// five optional `photo` files (JPEG/PNG/WebP). New reviews wait for admin moderation before
+/// they are shown.
+#[utoipa::path(
+    post,
+    path = "/products/{id}/reviews/create",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    request_body(content = NewReviewForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Review submitted for moderation; returns the review ID"),
+        (status = 400, description = "Invalid rating, text or photo"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only buyers with a paid order for this product can review it"),
+        (status = 404, description = "Product not found"),
+        (status = 409, description = "The user already reviewed this product"),
+        (status = 413, description = "Upload too large"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn create_review(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+    Path(product_id): Path<i64>,
+    mut multipart: Multipart,
+) -> HandlerResult<impl IntoResponse> {
+    if !ProductImageModel::product_exists(&state.db, product_id)
+        .await
+        .map_err(internal_server_error)?
+    {
+        return Err((StatusCode::NOT_FOUND, "Produk tidak ditemukan".to_string()));
+    }
+
+    let user_id = current_user_id(&state.db, &email).await?;
+    if !ReviewModel::is_verified_buyer(&state.db, user_id, product_id)
+        .await
+        .map_err(internal_server_error)?
+    {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "Hanya pembeli produk ini (order sudah dibayar) yang bisa memberi ulasan".to_string(),
+        ));
+    }
+    if ReviewModel::has_reviewed(&state.db, user_id, product_id)
+        .await
+        .map_err(internal_server_error)?
+    {
+        return Err((
+            StatusCode::CONFLICT,
+            "Anda sudah memberi ulasan untuk produk ini".to_string(),
+        ));
+    }
+
+    let mut review = NewReview::default();
+    let mut files = Vec::new();
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| (e.status(), e.body_text()))?
+    {
+        let name = field.name().unwrap_or_default().to_string();
+        if name == "photo" {
+            if files.len() == MAX_PHOTOS_PER_REVIEW {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    format!("Maksimal {} foto per ulasan", MAX_PHOTOS_PER_REVIEW),
+                ));
+            }
+            let bytes = field
+                .bytes()
+                .await
+                .map_err(|e| (e.status(), e.body_text()))?;
+            files.push(bytes.to_vec());
+            continue;
+        }
+
+        let text = field
+            .text()
+            .await
+            .map_err(|e| (e.status(), e.body_text()))?;
+        match name.as_str() {
+            "rating" => {
+                review.rating = text.trim().parse().map_err(|_| {
+                    (
+                        StatusCode::BAD_REQUEST,
+                        "rating harus berupa angka 1-5".to_string(),
+                    )
+                })?
+            }
+            "title" => {
+                review.title = Some(text.trim().to_string()).filter(|t| !t.is_empty());
+            }
+            "body" => review.body = text,
+            _ => {}
+        }
+    }
+    review
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    // Semua foto diolah dulu supaya satu file rusak tidak meninggalkan unggahan setengah jadi
+    let mut processed = Vec::with_capacity(files.len());
+    for bytes in files {
+        let image = tokio::task::spawn_blocking(move || images::process_stripped(bytes))
+            .await
+            .map_err(|e| storage_error(e.to_string()))?
+            .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+        processed.push(image);
+    }
+
+    let dir = format!("reviews/{}", product_id);
+    let mut photos = Vec::with_capacity(processed.len());
+    let mut keys: Vec<String> = Vec::new();
+    for image in processed {
+        let stored = store(state.storage.as_ref(), &dir, image).await;
+        match stored {
+            Ok(stored) => {
+                keys.extend(stored.keys.keys().map(str::to_string));
+                photos.push(stored);
+            }
+            Err(e) => {
+                for key in &keys {
+                    let _ = state.storage.delete(key).await;
+                }
+                return Err(storage_error(e));
+            }
+        }
+    }
+
+    match ReviewModel::create(&state.db, product_id, user_id, review, photos).await {
+        Ok(id) => Ok((
+            StatusCode::CREATED,
+            Json(ApiResponse::success_data_with_message(
+                "Ulasan terkirim dan akan tampil setelah dimoderasi".to_string(),
+                id,
+            )),
+        )),
+        Err(e) => {
+            for key in &keys {
+                let _ = state.storage.delete(key).await;
+            }
+            // Dua ulasan dikirim bersamaan: unique index (product_id, user_id)
+            if e.as_database_error()
+                .is_some_and(|db_error| db_error.is_unique_violation())
+            {
+                return Err((
+                    StatusCode::CONFLICT,
+                    "Anda sudah memberi ulasan untuk produk ini".to_string(),
+                ));
+            }
+            Err(internal_server_error(e))
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::pagination::{PaginationMeta, PaginationParams};
+use crate::dtos::review::ProductReviewPage;
+use crate::models::product_image::ProductImageModel;
+use crate::models::review::ReviewModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get a product's reviews
+///
+/// Approved reviews from verified buyers, newest first, with the rating summary
+/// (average, count and per-star distribution).
+#[utoipa::path(
+    get,
+    path = "/products/{id}/reviews",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID"),
+        PaginationParams
+    ),
+    responses(
+        (status = 200, description = "Rating summary and paginated reviews", body = ProductReviewPage),
+        (status = 404, description = "Product not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_product_reviews(
+    State(state): State<Arc<AppState>>,
+    Path(product_id): Path<i64>,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    if !ProductImageModel::product_exists(&state.db, product_id)
+        .await
+        .map_err(internal_server_error)?
+    {
+        return Err((StatusCode::NOT_FOUND, "Produk tidak ditemukan".to_string()));
+    }
+
+    let summary = ReviewModel::rating_summary(&state.db, product_id)
+        .await
+        .map_err(internal_server_error)?;
+    let (reviews, total) = ReviewModel::find_approved(&state.db, product_id, &params)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Ulasan produk berhasil diambil",
+        ProductReviewPage {
+            summary,
+            data: reviews,
+            pagination: PaginationMeta::new(params.page, params.per_page, total as u32),
+        },
+    )))
+}
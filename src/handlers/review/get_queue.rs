@@ -0,0 +1,50 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::pagination::PaginationParams;
+use crate::dtos::review::{ReviewQueueParams, ReviewStatus};
+use crate::middleware::auth::AdminAuth;
+use crate::models::review::ReviewModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Query, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get reviews for moderation (Admin only)
+///
+/// Reviews with the given status (default `PENDING`, oldest first), including the
+/// reviewer's email and photos.
+#[utoipa::path(
+    get,
+    path = "/reviews",
+    tag = "reviews",
+    params(
+        ReviewQueueParams,
+        PaginationParams
+    ),
+    responses(
+        (status = 200, description = "Paginated reviews with the requested status"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_review_queue(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Query(filter): Query<ReviewQueueParams>,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    let status = filter.status.unwrap_or(ReviewStatus::Pending);
+    let reviews = ReviewModel::find_for_moderation(&state.db, status, params)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar ulasan berhasil diambil",
+        reviews,
+    )))
+}
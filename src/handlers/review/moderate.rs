@@ -0,0 +1,67 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::review::{ModerateReviewDto, ReviewStatus};
+use crate::middleware::auth::AdminAuth;
+use crate::models::review::ReviewModel;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Approve or reject a review (Admin only)
+///
+/// Only approved reviews are shown and counted in the product's rating; the product's
+/// `rating_average` and `rating_count` are recalculated immediately. A review can be
+/// moderated again later (e.g. hiding a previously approved review).
+#[utoipa::path(
+    post,
+    path = "/reviews/{id}/moderate",
+    tag = "reviews",
+    params(
+        ("id" = i64, Path, description = "Review ID")
+    ),
+    request_body = ModerateReviewDto,
+    responses(
+        (status = 200, description = "Review moderated"),
+        (status = 400, description = "Invalid status or note"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Review not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn moderate_review(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Path(review_id): Path<i64>,
+    Json(dto): Json<ModerateReviewDto>,
+) -> HandlerResult<impl IntoResponse> {
+    dto.validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(internal_server_error)?;
+    let note = dto
+        .note
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty());
+
+    ReviewModel::moderate(&state.db, review_id, dto.status, note, admin_id)
+        .await
+        .map_err(internal_server_error)?
+        .ok_or((StatusCode::NOT_FOUND, "Ulasan tidak ditemukan".to_string()))?;
+
+    let message = if dto.status == ReviewStatus::Approved {
+        "Ulasan disetujui dan ditampilkan"
+    } else {
+        "Ulasan ditolak"
+    };
+    Ok(Json(ApiResponse::<()>::success(message)))
+}
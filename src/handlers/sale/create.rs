@@ -0,0 +1,63 @@
+use super::{HandlerResult, internal_server_error, pricing_error};
+use crate::AppState;
+use crate::dtos::pricing::NewProductSaleDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::sale::SaleModel;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Schedule a sale price (Admin only)
+///
+/// Sets a discounted price for the product (or one variant) between `starts_at` and
+/// `ends_at`. The effective price is applied automatically in listings, checkout and the
+/// chatbot, and reverts when the window ends. Schedules for the same target cannot overlap.
+#[utoipa::path(
+    post,
+    path = "/products/{id}/sales/create",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    request_body = NewProductSaleDto,
+    responses(
+        (status = 201, description = "Sale scheduled"),
+        (status = 400, description = "Invalid window, price not below the regular price, or overlapping schedule"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Product or variant not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn create_product_sale(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Path(product_id): Path<i64>,
+    Json(sale): Json<NewProductSaleDto>,
+) -> HandlerResult<impl IntoResponse> {
+    sale.validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(internal_server_error)?;
+
+    let id = SaleModel::create(&state.db, product_id, sale, admin_id)
+        .await
+        .map_err(|e| pricing_error(e, StatusCode::BAD_REQUEST))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_data_with_message(
+            format!("Sale berhasil dijadwalkan dengan ID: {}", id),
+            id,
+        )),
+    ))
+}
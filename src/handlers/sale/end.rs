@@ -0,0 +1,55 @@
+use super::{HandlerResult, internal_server_error, pricing_error};
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::sale::SaleModel;
+use crate::models::user::User;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// End a sale (Admin only)
+///
+/// Cancels a sale that has not started yet, or ends a running sale immediately so the
+/// regular price applies again.
+#[utoipa::path(
+    post,
+    path = "/products/{id}/sales/{sale_id}/end",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID"),
+        ("sale_id" = i64, Path, description = "Sale ID")
+    ),
+    responses(
+        (status = 200, description = "Sale cancelled or ended"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Sale not found"),
+        (status = 409, description = "Sale has already ended"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn end_product_sale(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Path((product_id, sale_id)): Path<(i64, i64)>,
+) -> HandlerResult<impl IntoResponse> {
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(internal_server_error)?;
+
+    let cancelled = SaleModel::end(&state.db, product_id, sale_id, admin_id)
+        .await
+        .map_err(|e| pricing_error(e, StatusCode::CONFLICT))?;
+
+    Ok(Json(ApiResponse::<()>::success(if cancelled {
+        "Sale dibatalkan"
+    } else {
+        "Sale dihentikan, harga normal berlaku kembali"
+    })))
+}
@@ -0,0 +1,45 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::sale::SaleModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get a product's sales (Admin only)
+///
+/// Lists scheduled, active and past sale prices of the product and its variants,
+/// newest schedule first.
+#[utoipa::path(
+    get,
+    path = "/products/{id}/sales",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Sales of the product with their status"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_product_sales(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(product_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    let sales = SaleModel::find_by_product(&state.db, product_id)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar sale berhasil diambil",
+        sales,
+    )))
+}
@@ -0,0 +1,26 @@
+pub mod create;
+pub mod end;
+pub mod get_all;
+pub mod price_history;
+
+use crate::dtos::pricing::PricingError;
+use axum::http::StatusCode;
+
+pub(crate) type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+pub(crate) fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
+
+/// `invalid_status` dipakai untuk `PricingError::Invalid` (400 saat membuat, 409 saat menghentikan).
+pub(crate) fn pricing_error(e: PricingError, invalid_status: StatusCode) -> (StatusCode, String) {
+    match e {
+        PricingError::NotFound(message) => (StatusCode::NOT_FOUND, message),
+        PricingError::Invalid(message) => (invalid_status, message),
+        PricingError::Database(e) => internal_server_error(e),
+    }
+}
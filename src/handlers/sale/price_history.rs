@@ -0,0 +1,48 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::pagination::PaginationParams;
+use crate::middleware::auth::AdminAuth;
+use crate::models::price_history::PriceHistoryModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, Query, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get a product's price history (Admin only)
+///
+/// Every regular price change of the product and its variants, plus sales being scheduled,
+/// ended early or cancelled, newest first.
+#[utoipa::path(
+    get,
+    path = "/products/{id}/price-history",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID"),
+        PaginationParams
+    ),
+    responses(
+        (status = 200, description = "Paginated price history"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_price_history(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Path(product_id): Path<i64>,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    let history = PriceHistoryModel::find_by_product(&state.db, product_id, params)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Riwayat harga berhasil diambil",
+        history,
+    )))
+}
@@ -0,0 +1,52 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::supplier::NewSupplierDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::supplier::SupplierModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Create a supplier (Admin only)
+///
+/// Registers a supplier that purchase orders can be raised against.
+#[utoipa::path(
+    post,
+    path = "/suppliers/create",
+    tag = "suppliers",
+    request_body = NewSupplierDto,
+    responses(
+        (status = 201, description = "Supplier created successfully"),
+        (status = 400, description = "Invalid supplier data"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn create_supplier(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Json(supplier): Json<NewSupplierDto>,
+) -> HandlerResult<impl IntoResponse> {
+    supplier
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let id = SupplierModel::insert(&state.db, supplier)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_data_with_message(
+            format!("Supplier berhasil dibuat dengan ID: {}", id),
+            id,
+        )),
+    ))
+}
@@ -0,0 +1,39 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::supplier::Supplier;
+use crate::middleware::auth::AdminAuth;
+use crate::models::supplier::SupplierModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get all suppliers (Admin only)
+#[utoipa::path(
+    get,
+    path = "/suppliers",
+    tag = "suppliers",
+    responses(
+        (status = 200, description = "List of suppliers", body = [Supplier]),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_all_suppliers(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+) -> HandlerResult<impl IntoResponse> {
+    let suppliers = SupplierModel::find_all(&state.db)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar supplier berhasil diambil",
+        suppliers,
+    )))
+}
@@ -0,0 +1,37 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::supplier::NewSupplierDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::supplier::SupplierModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Supplier yang dinonaktifkan tidak bisa dipakai untuk purchase order baru.
+pub async fn update_supplier(
+    State(state): State<Arc<AppState>>,
+    AdminAuth(_): AdminAuth,
+    Path(supplier_id): Path<i64>,
+    Json(supplier): Json<NewSupplierDto>,
+) -> HandlerResult<impl IntoResponse> {
+    supplier
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    match SupplierModel::update(&state.db, supplier_id, supplier)
+        .await
+        .map_err(internal_server_error)?
+    {
+        0 => Err((
+            StatusCode::NOT_FOUND,
+            "Supplier tidak ditemukan".to_string(),
+        )),
+        _ => Ok(Json(ApiResponse::<()>::success(
+            "Supplier berhasil diperbarui",
+        ))),
+    }
+}
@@ -0,0 +1,187 @@
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use axum_extra::extract::cookie::CookieJar;
+use chrono::Utc;
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::dtos::order::Order;
+use crate::dtos::user::{DeleteAccountRequest, ExportedOrder, ExportedProfile, UserDataExport};
+use crate::middleware::auth::AuthUser;
+use crate::models::address::AddressModel;
+use crate::models::api_key::ApiKeyModel;
+use crate::models::notification::NotificationModel;
+use crate::models::question::QuestionModel;
+use crate::models::review::ReviewModel;
+use crate::models::user::User;
+use crate::models::wishlist::WishlistModel;
+use crate::utils::ApiResponse;
+use crate::utils::cookie::removal_cookie;
+use crate::utils::csrf::CSRF_COOKIE;
+
+type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+fn internal_server_error(e: sqlx::Error) -> (StatusCode, String) {
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
+
+/// Export personal data
+///
+/// Returns a JSON archive of the authenticated user's profile, address book,
+/// orders, login sessions, API keys, product reviews (with photos), product
+/// questions, notifications and wishlist.
+#[utoipa::path(
+    get,
+    path = "/user/export",
+    tag = "user",
+    responses(
+        (status = 200, description = "Personal data archive", body = UserDataExport),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn export_user_data(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+) -> HandlerResult<impl IntoResponse> {
+    let user = User::find_by_email(&state.db, &email)
+        .await
+        .map_err(internal_server_error)?
+        .ok_or((StatusCode::NOT_FOUND, "User not found".to_string()))?;
+
+    let mut orders = Vec::new();
+    for order in Order::find_by_user_id(&state.db, user.id)
+        .await
+        .map_err(internal_server_error)?
+    {
+        let items = Order::find_export_items(&state.db, order.id)
+            .await
+            .map_err(internal_server_error)?;
+        orders.push(ExportedOrder {
+            id: order.id,
+            total_amount: order.total_amount,
+            shipping_address: order.shipping_address,
+            payment_method: order.payment_method,
+            status: order.status,
+            order_date: order.order_date,
+            items,
+        });
+    }
+
+    let addresses = AddressModel::find_by_user(&state.db, user.id)
+        .await
+        .map_err(internal_server_error)?;
+    let sessions = User::find_sessions(&state.db, user.id)
+        .await
+        .map_err(internal_server_error)?;
+    let api_keys = ApiKeyModel::find_by_user_id(&state.db, user.id)
+        .await
+        .map_err(internal_server_error)?;
+    let reviews = ReviewModel::find_by_user(&state.db, user.id)
+        .await
+        .map_err(internal_server_error)?;
+    let questions = QuestionModel::find_by_user(&state.db, user.id)
+        .await
+        .map_err(internal_server_error)?;
+    let notifications = NotificationModel::find_all_by_user(&state.db, user.id)
+        .await
+        .map_err(internal_server_error)?;
+    let wishlist = WishlistModel::find_all_by_user(&state.db, user.id)
+        .await
+        .map_err(internal_server_error)?;
+
+    let export = UserDataExport {
+        exported_at: Utc::now().naive_utc(),
+        profile: ExportedProfile {
+            id: user.id,
+            name: user.name,
+            email: user.email,
+            address: user.address,
+            role: user.role,
+        },
+        addresses,
+        orders,
+        sessions,
+        api_keys,
+        reviews,
+        questions,
+        notifications,
+        wishlist,
+    };
+
+    Ok(Json(ApiResponse::success_data(
+        "Data pribadi berhasil diekspor",
+        export,
+    )))
+}
+
+/// Delete account
+///
+/// Anonymizes the authenticated user's account. Order history is preserved
+/// for accounting, while all refresh tokens and API keys are revoked. Reviews
+/// stay (shown as "Deleted User") so product ratings don't change, but their
+/// photos are deleted.
+#[utoipa::path(
+    delete,
+    path = "/user/delete",
+    tag = "user",
+    request_body = DeleteAccountRequest,
+    responses(
+        (status = 200, description = "Account deleted"),
+        (status = 401, description = "Unauthorized or wrong password"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn delete_account(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+    jar: CookieJar,
+    Json(payload): Json<DeleteAccountRequest>,
+) -> HandlerResult<impl IntoResponse> {
+    let user = User::find_by_email(&state.db, &email)
+        .await
+        .map_err(internal_server_error)?
+        .ok_or((StatusCode::NOT_FOUND, "User not found".to_string()))?;
+
+    // Akun dengan password wajib konfirmasi ulang; akun Google tidak punya password
+    if !user.password.is_empty() {
+        let password = payload.password.as_deref().unwrap_or("");
+        let valid = bcrypt::verify(password, &user.password)
+            .map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR, "Verifikasi gagal".into()))?;
+        if !valid {
+            return Err((StatusCode::UNAUTHORIZED, "Password salah".into()));
+        }
+    }
+
+    let photo_keys = User::anonymize(&state.db, user.id)
+        .await
+        .map_err(internal_server_error)?;
+    for key in photo_keys.iter().flat_map(|keys| keys.keys()) {
+        if let Err(e) = state.storage.delete(key).await {
+            eprintln!("Storage Error: {}", e);
+        }
+    }
+
+    let jar = jar
+        .add(removal_cookie("jwt", true))
+        .add(removal_cookie("refresh_token", true))
+        .add(removal_cookie(CSRF_COOKIE, false));
+
+    Ok((
+        jar,
+        Json(ApiResponse::<()>::success("Akun berhasil dihapus")),
+    ))
+}
@@ -0,0 +1,64 @@
+use super::{HandlerResult, variant_db_error};
+use crate::AppState;
+use crate::dtos::variant::NewVariantDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::user::User;
+use crate::models::variant::VariantModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Add a variant to a product (Admin only)
+///
+/// Creates a SKU with its option values (e.g. length, power), an optional price
+/// override, stock level and image.
+#[utoipa::path(
+    post,
+    path = "/products/{id}/variants/create",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    request_body = NewVariantDto,
+    responses(
+        (status = 201, description = "Variant created successfully"),
+        (status = 400, description = "Invalid variant data"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 404, description = "Product not found"),
+        (status = 409, description = "SKU already exists"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn create_variant(
+    State(state): State<Arc<AppState>>,
+    admin: AdminAuth,
+    Path(product_id): Path<i64>,
+    Json(variant): Json<NewVariantDto>,
+) -> HandlerResult<impl IntoResponse> {
+    variant
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let admin_id = User::find_id_by_email(&state.db, &admin.0.email)
+        .await
+        .map_err(variant_db_error)?;
+
+    let id = VariantModel::insert(&state.db, product_id, variant, admin_id)
+        .await
+        .map_err(variant_db_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_data_with_message(
+            format!("Varian berhasil ditambahkan dengan ID: {}", id),
+            id,
+        )),
+    ))
+}
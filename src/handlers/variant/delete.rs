@@ -0,0 +1,26 @@
+use super::{HandlerResult, variant_db_error};
+use crate::AppState;
+use crate::middleware::auth::AdminAuth;
+use crate::models::variant::VariantModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Item order lama tetap menyimpan SKU-nya; `variant_id` di order_items menjadi NULL.
+pub async fn delete_variant(
+    State(state): State<Arc<AppState>>,
+    AdminAuth(_): AdminAuth,
+    Path((product_id, variant_id)): Path<(i64, i64)>,
+) -> HandlerResult<impl IntoResponse> {
+    match VariantModel::delete(&state.db, product_id, variant_id)
+        .await
+        .map_err(variant_db_error)?
+    {
+        0 => Err((StatusCode::NOT_FOUND, "Varian tidak ditemukan".to_string())),
+        _ => Ok(Json(ApiResponse::<()>::success("Varian berhasil dihapus"))),
+    }
+}
@@ -0,0 +1,39 @@
+use super::{HandlerResult, variant_db_error};
+use crate::AppState;
+use crate::dtos::variant::ProductVariant;
+use crate::models::variant::VariantModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get product variants
+///
+/// Returns every variant of the product, including inactive ones, with the effective price.
+#[utoipa::path(
+    get,
+    path = "/products/{id}/variants",
+    tag = "products",
+    params(
+        ("id" = i64, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Product variants", body = [ProductVariant]),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_product_variants(
+    State(state): State<Arc<AppState>>,
+    Path(product_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    let variants = VariantModel::find_by_product(&state.db, product_id, false)
+        .await
+        .map_err(variant_db_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Varian produk berhasil diambil",
+        variants,
+    )))
+}
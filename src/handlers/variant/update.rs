@@ -0,0 +1,38 @@
+use super::{HandlerResult, variant_db_error};
+use crate::AppState;
+use crate::dtos::variant::NewVariantDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::user::User;
+use crate::models::variant::VariantModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+pub async fn update_variant(
+    State(state): State<Arc<AppState>>,
+    AdminAuth(admin): AdminAuth,
+    Path((product_id, variant_id)): Path<(i64, i64)>,
+    Json(variant): Json<NewVariantDto>,
+) -> HandlerResult<impl IntoResponse> {
+    variant
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let admin_id = User::find_id_by_email(&state.db, &admin.email)
+        .await
+        .map_err(variant_db_error)?;
+
+    match VariantModel::update(&state.db, product_id, variant_id, variant, admin_id)
+        .await
+        .map_err(variant_db_error)?
+    {
+        0 => Err((StatusCode::NOT_FOUND, "Varian tidak ditemukan".to_string())),
+        _ => Ok(Json(ApiResponse::<()>::success(
+            "Varian berhasil diperbarui",
+        ))),
+    }
+}
@@ -0,0 +1,54 @@
+use super::{HandlerResult, warehouse_db_error};
+use crate::AppState;
+use crate::dtos::warehouse::NewWarehouseDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::warehouse::WarehouseModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Create a warehouse (Admin only)
+///
+/// Adds a fulfilment location. Its province and city are matched against the shipping
+/// address at checkout to pick the nearest warehouse.
+#[utoipa::path(
+    post,
+    path = "/warehouses/create",
+    tag = "warehouses",
+    request_body = NewWarehouseDto,
+    responses(
+        (status = 201, description = "Warehouse created successfully"),
+        (status = 400, description = "Invalid warehouse data"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 409, description = "Warehouse code already exists"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn create_warehouse(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Json(warehouse): Json<NewWarehouseDto>,
+) -> HandlerResult<impl IntoResponse> {
+    warehouse
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    let id = WarehouseModel::insert(&state.db, warehouse)
+        .await
+        .map_err(warehouse_db_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_data_with_message(
+            format!("Gudang berhasil dibuat dengan ID: {}", id),
+            id,
+        )),
+    ))
+}
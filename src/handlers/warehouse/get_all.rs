@@ -0,0 +1,35 @@
+use super::{HandlerResult, warehouse_db_error};
+use crate::AppState;
+use crate::dtos::warehouse::Warehouse;
+use crate::models::warehouse::WarehouseModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get all warehouses
+///
+/// Returns every fulfilment location ordered by priority.
+#[utoipa::path(
+    get,
+    path = "/warehouses",
+    tag = "warehouses",
+    responses(
+        (status = 200, description = "List of warehouses", body = [Warehouse]),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_all_warehouses(
+    State(state): State<Arc<AppState>>,
+) -> HandlerResult<impl IntoResponse> {
+    let warehouses = WarehouseModel::find_all(&state.db)
+        .await
+        .map_err(warehouse_db_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Daftar gudang berhasil diambil",
+        warehouses,
+    )))
+}
@@ -0,0 +1,24 @@
+pub mod create;
+pub mod get_all;
+pub mod update;
+
+use axum::http::StatusCode;
+
+pub(crate) type HandlerResult<T> = Result<T, (StatusCode, String)>;
+
+/// Terjemahkan error database untuk gudang ke status HTTP yang sesuai.
+pub(crate) fn warehouse_db_error(e: sqlx::Error) -> (StatusCode, String) {
+    if let Some(db_error) = e.as_database_error()
+        && db_error.is_unique_violation()
+    {
+        return (
+            StatusCode::CONFLICT,
+            "Kode gudang sudah dipakai".to_string(),
+        );
+    }
+    eprintln!("Database Error: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Terjadi kesalahan internal pada server.".to_string(),
+    )
+}
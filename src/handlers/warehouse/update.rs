@@ -0,0 +1,34 @@
+use super::{HandlerResult, warehouse_db_error};
+use crate::AppState;
+use crate::dtos::warehouse::NewWarehouseDto;
+use crate::middleware::auth::AdminAuth;
+use crate::models::warehouse::WarehouseModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Gudang yang dinonaktifkan tidak dipakai lagi saat checkout, tapi stoknya tetap tersimpan.
+pub async fn update_warehouse(
+    State(state): State<Arc<AppState>>,
+    AdminAuth(_): AdminAuth,
+    Path(warehouse_id): Path<i64>,
+    Json(warehouse): Json<NewWarehouseDto>,
+) -> HandlerResult<impl IntoResponse> {
+    warehouse
+        .validate()
+        .map_err(|msg| (StatusCode::BAD_REQUEST, msg))?;
+
+    match WarehouseModel::update(&state.db, warehouse_id, warehouse)
+        .await
+        .map_err(warehouse_db_error)?
+    {
+        0 => Err((StatusCode::NOT_FOUND, "Gudang tidak ditemukan".to_string())),
+        _ => Ok(Json(ApiResponse::<()>::success(
+            "Gudang berhasil diperbarui",
+        ))),
+    }
+}
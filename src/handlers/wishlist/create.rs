@@ -0,0 +1,56 @@
+use super::{HandlerResult, current_user_id, internal_server_error};
+use crate::AppState;
+use crate::dtos::wishlist::AddWishlistDto;
+use crate::middleware::auth::AuthUser;
+use crate::models::wishlist::WishlistModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Add a product to my wishlist
+///
+/// The user is notified (`PRICE_DROPPED`, `BACK_IN_STOCK`) when the product's effective price
+/// drops below, or its stock returns after, what it was when added or last notified.
+/// Adding a product that is already saved is a no-op.
+#[utoipa::path(
+    post,
+    path = "/user/wishlist/create",
+    tag = "user",
+    request_body = AddWishlistDto,
+    responses(
+        (status = 201, description = "Product added to the wishlist"),
+        (status = 200, description = "Product was already in the wishlist"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Product not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn add_to_wishlist(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+    Json(dto): Json<AddWishlistDto>,
+) -> HandlerResult<impl IntoResponse> {
+    let user_id = current_user_id(&state.db, &email).await?;
+
+    match WishlistModel::add(&state.db, user_id, dto.product_id)
+        .await
+        .map_err(internal_server_error)?
+    {
+        Some(true) => Ok((
+            StatusCode::CREATED,
+            Json(ApiResponse::<()>::success("Produk ditambahkan ke wishlist")),
+        )),
+        Some(false) => Ok((
+            StatusCode::OK,
+            Json(ApiResponse::<()>::success("Produk sudah ada di wishlist")),
+        )),
+        None => Err((StatusCode::NOT_FOUND, "Produk tidak ditemukan".to_string())),
+    }
+}
@@ -0,0 +1,50 @@
+use super::{HandlerResult, current_user_id, internal_server_error};
+use crate::AppState;
+use crate::middleware::auth::AuthUser;
+use crate::models::wishlist::WishlistModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Remove a product from my wishlist
+#[utoipa::path(
+    delete,
+    path = "/user/wishlist/{product_id}/delete",
+    tag = "user",
+    params(
+        ("product_id" = i64, Path, description = "Product ID")
+    ),
+    responses(
+        (status = 200, description = "Product removed from the wishlist"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Product is not in the wishlist"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn remove_from_wishlist(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+    Path(product_id): Path<i64>,
+) -> HandlerResult<impl IntoResponse> {
+    let user_id = current_user_id(&state.db, &email).await?;
+    let removed = WishlistModel::remove(&state.db, user_id, product_id)
+        .await
+        .map_err(internal_server_error)?;
+    if removed == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            "Produk tidak ada di wishlist".to_string(),
+        ));
+    }
+
+    Ok(Json(ApiResponse::<()>::success(
+        "Produk dihapus dari wishlist",
+    )))
+}
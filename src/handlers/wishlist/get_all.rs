@@ -0,0 +1,45 @@
+use super::{HandlerResult, current_user_id, internal_server_error};
+use crate::AppState;
+use crate::dtos::pagination::PaginationParams;
+use crate::middleware::auth::AuthUser;
+use crate::models::wishlist::WishlistModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Query, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get my wishlist
+///
+/// Saved products, most recently added first, with their current effective price and stock
+/// status. Archived products are left out.
+#[utoipa::path(
+    get,
+    path = "/user/wishlist",
+    tag = "user",
+    params(PaginationParams),
+    responses(
+        (status = 200, description = "Paginated wishlist items"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_wishlist(
+    State(state): State<Arc<AppState>>,
+    AuthUser { email, .. }: AuthUser,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    let user_id = current_user_id(&state.db, &email).await?;
+    let items = WishlistModel::find_by_user(&state.db, user_id, params)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Wishlist berhasil diambil",
+        items,
+    )))
+}
@@ -0,0 +1,44 @@
+use super::{HandlerResult, internal_server_error};
+use crate::AppState;
+use crate::dtos::pagination::PaginationParams;
+use crate::middleware::auth::AdminAuth;
+use crate::models::wishlist::WishlistModel;
+use crate::utils::ApiResponse;
+use axum::{
+    extract::{Json, Query, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Get wishlist demand per product (Admin only)
+///
+/// How many users saved each active product, most wishlisted first, with the number added in
+/// the last 30 days and the current price and stock status.
+#[utoipa::path(
+    get,
+    path = "/wishlists",
+    tag = "wishlists",
+    params(PaginationParams),
+    responses(
+        (status = 200, description = "Paginated wishlist counts per product"),
+        (status = 401, description = "Unauthorized - Admin access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    )
+)]
+pub async fn get_wishlist_demand(
+    State(state): State<Arc<AppState>>,
+    _admin: AdminAuth,
+    Query(params): Query<PaginationParams>,
+) -> HandlerResult<impl IntoResponse> {
+    let demand = WishlistModel::find_demand(&state.db, params)
+        .await
+        .map_err(internal_server_error)?;
+
+    Ok(Json(ApiResponse::success_data(
+        "Jumlah wishlist per produk berhasil diambil",
+        demand,
+    )))
+}
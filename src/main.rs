@@ -1,11 +1,13 @@
-use axum::{ Router, routing::{ get, post }, serve };
+use axum::{ Extension, Router, routing::{ get, post }, serve };
 use dotenvy::dotenv;
 use sqlx::{ MySql, Pool };
 use std::{ env, net::SocketAddr, sync::Arc };
 use tokio::net::TcpListener;
 use tower_http::cors::{ AllowHeaders, AllowMethods, AllowOrigin, CorsLayer };
+use tower_http::services::ServeDir;
 use utoipa::OpenApi;
 
+mod cli;
 mod db;
 mod dtos;
 mod handlers;
@@ -17,10 +19,20 @@ mod utils;
 // IMPORT SEMUA RUTE DAN HANDLER WEBHOOK
 use crate::handlers::order::webhook::webhook_payment;
 use routes::{
+    api_key_routes::api_key_routes,
     auth_routes::auth_routes,
     category_routes::category_routes,
+    flash_sale_routes::flash_sale_routes,
+    inventory_routes::stock_report_routes,
     order_routes::order_routes,
     product_routes::product_routes,
+    purchase_order_routes::purchase_order_routes,
+    question_routes::question_moderation_routes,
+    region_routes::region_routes,
+    review_routes::review_moderation_routes,
+    supplier_routes::supplier_routes,
+    warehouse_routes::warehouse_routes,
+    wishlist_routes::wishlist_demand_routes,
     user_routes::user_routes,
     chatbot_routes::chatbot_routes,
 }; // <<< IMPORT HANDLER WEBHOOK DARI SINI >>>
@@ -34,6 +46,8 @@ pub struct AppState {
     pub midtrans_server_key: String,
     pub midtrans_client_key: String,
     pub midtrans_base_url: String,
+    /// Penyimpanan file unggahan (lokal atau S3-compatible)
+    pub storage: Arc<dyn utils::storage::Storage>,
 }
 
 // ========================
@@ -45,21 +59,110 @@ pub struct AppState {
         // Auth endpoints
         handlers::auth::register::register_handler,
         handlers::auth::login::login_handler,
+
+        // API key endpoints
+        handlers::api_key::create::create_api_key,
+        handlers::api_key::get_all::get_all_api_keys,
+        handlers::api_key::revoke::revoke_api_key,
         
         // Product endpoints
         handlers::product::get_all::get_all_products,
+        handlers::product::search::search_products,
+        handlers::product::suggest::suggest_products,
         handlers::product::get_by_id::find_product_by_id,
+        handlers::product::get_by_slug::find_product_by_slug,
         handlers::product::create::create_product,
+        handlers::product::get_archived::get_archived_products,
+        handlers::product::import::import_products,
+        handlers::product::export::export_products,
+        handlers::product::restore::restore_product,
+        handlers::variant::get_all::get_product_variants,
+        handlers::variant::create::create_variant,
+        handlers::inventory::get_stock::get_product_stock,
+        handlers::inventory::adjust::adjust_stock,
+        handlers::inventory::transfer::transfer_stock,
+        handlers::inventory::movements::get_stock_movements,
+        handlers::inventory::reorder::set_reorder_threshold,
+        handlers::inventory::low_stock::get_low_stock,
+        handlers::inventory::low_stock_reports::get_low_stock_reports,
+        handlers::product_image::get_all::get_product_images,
+        handlers::product_image::upload::upload_product_images,
+        handlers::product_image::reorder::reorder_product_images,
+        handlers::sale::get_all::get_product_sales,
+        handlers::sale::create::create_product_sale,
+        handlers::sale::end::end_product_sale,
+        handlers::sale::price_history::get_price_history,
+
+        // Flash sale endpoints
+        handlers::flash_sale::get_active::get_active_flash_sales,
+        handlers::flash_sale::get_all::get_all_flash_sales,
+        handlers::flash_sale::create::create_flash_sale,
+        handlers::flash_sale::end::end_flash_sale,
+
+        // Review endpoints
+        handlers::review::get_all::get_product_reviews,
+        handlers::review::create::create_review,
+        handlers::review::get_queue::get_review_queue,
+        handlers::review::moderate::moderate_review,
+
+        // Q&A endpoints
+        handlers::question::get_all::get_product_questions,
+        handlers::question::create::create_question,
+        handlers::question::answer::answer_question,
+        handlers::question::get_queue::get_question_queue,
+        handlers::question::moderate::moderate_question,
+        handlers::question::moderate::moderate_answer,
         
         // Category endpoints
         handlers::category::get_all::get_all_categories,
         handlers::category::create::create_category,
+        handlers::category::get_archived::get_archived_categories,
+        handlers::category::get_tree::get_category_tree,
+        handlers::category::get_products::get_category_products,
+        handlers::category::restore::restore_category,
+        handlers::attribute::get_all::get_category_attributes,
+        handlers::attribute::create::create_category_attribute,
         
         // Order endpoints
         handlers::order::checkout::checkout,
+        handlers::order::refunds::get_refund_required_orders,
         
         // Chatbot
         handlers::chatbot::recommend::chatbot_recommend,
+
+        // User endpoints
+        handlers::user::account::export_user_data,
+        handlers::user::account::delete_account,
+        handlers::address::create::create_address,
+        handlers::address::get_all::get_all_addresses,
+        handlers::notification::get_all::get_notifications,
+        handlers::notification::mark_read::mark_notification_read,
+        handlers::notification::mark_read::mark_all_notifications_read,
+        handlers::wishlist::get_all::get_wishlist,
+        handlers::wishlist::create::add_to_wishlist,
+        handlers::wishlist::delete::remove_from_wishlist,
+        handlers::wishlist::get_demand::get_wishlist_demand,
+
+        // Region endpoints
+        handlers::region::provinces::get_provinces,
+        handlers::region::regencies::get_regencies,
+        handlers::region::districts::get_districts,
+        handlers::region::villages::get_villages,
+
+        // Warehouse endpoints
+        handlers::warehouse::get_all::get_all_warehouses,
+        handlers::warehouse::create::create_warehouse,
+
+        // Supplier endpoints
+        handlers::supplier::get_all::get_all_suppliers,
+        handlers::supplier::create::create_supplier,
+
+        // Purchase order endpoints
+        handlers::purchase_order::get_all::get_all_purchase_orders,
+        handlers::purchase_order::get_by_id::get_purchase_order,
+        handlers::purchase_order::create::create_purchase_order,
+        handlers::purchase_order::order::order_purchase_order,
+        handlers::purchase_order::receive::receive_purchase_order,
     ),
     components(
         schemas(
@@ -71,22 +174,93 @@ pub struct AppState {
             dtos::auth::login::LoginResponse,
             dtos::auth::login::UserLoginData,
             dtos::auth::update_role::UpdateRoleRequest,
+
+            // API key DTOs
+            dtos::api_key::NewApiKeyDto,
+            dtos::api_key::ApiKeyDto,
+            dtos::api_key::CreatedApiKey,
             
             // Product DTOs
             dtos::product::NewRodProductDto,
+            dtos::product_transfer::TransferFormat,
+            dtos::product_transfer::ProductTransferRow,
+            dtos::product_transfer::ImportAction,
+            dtos::product_transfer::ImportRowResult,
+            dtos::product_transfer::ImportReport,
             dtos::product::RodProductDetail,
+            dtos::product::ProductSlugRedirect,
             dtos::product::RodProduct,
             
             // Category DTOs
             dtos::category::KategoriDto,
             dtos::category::NewKategoriDto,
+            dtos::category::CategoryTreeNode,
+            dtos::attribute::AttributeType,
+            dtos::attribute::AttributeDefinition,
+            dtos::attribute::NewAttributeDto,
+            dtos::variant::ProductVariant,
+            dtos::variant::NewVariantDto,
+            dtos::inventory::MovementType,
+            dtos::inventory::StockMovement,
+            dtos::inventory::VariantStock,
+            dtos::inventory::ProductStock,
+            dtos::inventory::StockAdjustmentDto,
+            dtos::inventory::StockTransferDto,
+            dtos::inventory::WarehouseStockLevel,
+            dtos::inventory::StockShortage,
+            dtos::inventory::ReorderSettingsDto,
+            dtos::inventory::LowStockItem,
+            dtos::inventory::LowStockReport,
+            dtos::product_image::ProductImage,
+            dtos::product_image::ImageUploadForm,
+            dtos::product_image::ImageOrderDto,
+            dtos::pricing::SaleStatus,
+            dtos::pricing::ProductSale,
+            dtos::pricing::NewProductSaleDto,
+            dtos::pricing::PriceChangeType,
+            dtos::pricing::PriceHistoryEntry,
+            dtos::flash_sale::FlashSale,
+            dtos::flash_sale::NewFlashSaleDto,
+            dtos::review::ReviewStatus,
+            dtos::review::ReviewPhoto,
+            dtos::review::ProductReview,
+            dtos::review::ReviewForModeration,
+            dtos::review::ExportedReview,
+            dtos::review::RatingBucket,
+            dtos::review::RatingSummary,
+            dtos::review::ProductReviewPage,
+            dtos::review::NewReviewForm,
+            dtos::review::ModerateReviewDto,
+            dtos::question::ProductQuestion,
+            dtos::question::ProductAnswer,
+            dtos::question::NewQuestionDto,
+            dtos::question::NewAnswerDto,
+            dtos::question::ModerateQnaDto,
+            dtos::question::QuestionQueueFilter,
+            dtos::notification::NotificationType,
+            dtos::notification::Notification,
+            dtos::notification::NotificationPage,
+            dtos::wishlist::WishlistItem,
+            dtos::wishlist::AddWishlistDto,
+            dtos::wishlist::WishlistDemand,
             
             // Order DTOs
             dtos::order::OrderItem,
             dtos::order::NewOrderDto,
+            dtos::order::RefundRequiredOrder,
             
             // User DTOs
             dtos::user::UpdateProfile,
+            dtos::user::DeleteAccountRequest,
+            dtos::user::UserDataExport,
+            dtos::user::ExportedProfile,
+            dtos::user::ExportedOrder,
+            dtos::user::ExportedOrderItem,
+            dtos::user::ExportedSession,
+
+            // Address DTOs
+            dtos::address::AddressDto,
+            dtos::address::NewAddressDto,
             
             // Chatbot DTOs
             dtos::chatbot::ChatRequest,
@@ -95,18 +269,53 @@ pub struct AppState {
             // Pagination
             dtos::pagination::PaginationParams,
             dtos::pagination::PaginationMeta,
+            dtos::product::ProductSort,
+            dtos::product::ProductSearchHit,
+            dtos::product::ProductSearchResponse,
+            dtos::product::RodSpecsDisplay,
+            dtos::product::SpecDisplay,
+            dtos::search::Suggestion,
+            dtos::search::SuggestResponse,
             
+            // Region DTOs
+            dtos::region::RegionDto,
+
+            // Warehouse DTOs
+            dtos::warehouse::Warehouse,
+            dtos::warehouse::NewWarehouseDto,
+            dtos::warehouse::WarehouseAvailability,
+
+            // Supplier & Purchase Order DTOs
+            dtos::supplier::Supplier,
+            dtos::supplier::NewSupplierDto,
+            dtos::purchase_order::PurchaseOrderStatus,
+            dtos::purchase_order::PurchaseOrder,
+            dtos::purchase_order::PurchaseOrderItem,
+            dtos::purchase_order::PurchaseOrderDetail,
+            dtos::purchase_order::NewPurchaseOrderItem,
+            dtos::purchase_order::NewPurchaseOrderDto,
+
             // Generic Response
             utils::api_response::ApiResponse<String>,
+            utils::money::Money,
         )
     ),
     tags(
         (name = "auth", description = "Authentication and authorization endpoints"),
+        (name = "api-keys", description = "Scoped API keys for service-to-service access"),
         (name = "products", description = "Fishing rod product management"),
         (name = "categories", description = "Product category management"),
+        (name = "flash-sales", description = "Limited-quota flash sales"),
+        (name = "reviews", description = "Product review moderation"),
+        (name = "questions", description = "Product Q&A moderation"),
+        (name = "wishlists", description = "Wishlist demand per product"),
         (name = "orders", description = "Order and payment management"),
         (name = "chatbot", description = "AI-powered product recommendations"),
-        (name = "user", description = "User profile management")
+        (name = "user", description = "User profile management"),
+        (name = "regions", description = "Indonesian administrative regions for address input"),
+        (name = "warehouses", description = "Fulfilment locations and per-warehouse stock"),
+        (name = "suppliers", description = "Suppliers for stock replenishment"),
+        (name = "purchase-orders", description = "Replenishment purchase orders and goods receipt")
     ),
     modifiers(&SecurityAddon),
     info(
@@ -114,6 +323,7 @@ pub struct AppState {
         version = "1.0.0",
         description = "REST API untuk toko joran pancing dengan fitur:\n\
         - Autentikasi JWT dan Google OAuth\n\
+        - API key dengan scope untuk integrasi antar service\n\
         - Manajemen produk dan kategori\n\
         - Sistem order dengan Midtrans payment gateway\n\
         - AI chatbot untuk rekomendasi produk (Groq LLM)\n\
@@ -145,6 +355,17 @@ impl utoipa::Modify for SecurityAddon {
                         .build(),
                 ),
             );
+            components.add_security_scheme(
+                "api_key",
+                utoipa::openapi::security::SecurityScheme::ApiKey(
+                    utoipa::openapi::security::ApiKey::Header(
+                        utoipa::openapi::security::ApiKeyValue::with_description(
+                            "X-API-Key",
+                            "Scoped API key issued by an admin (bk_...)",
+                        ),
+                    ),
+                ),
+            );
         }
     }
 }
@@ -159,6 +380,83 @@ async fn main() {
     // --- 1. Koneksi Database ---
     let db_pool = db::init_db().await;
 
+    // Perintah CLI (mis. `import-products`) dijalankan lalu keluar tanpa menyalakan server
+    let args: Vec<String> = env::args().skip(1).collect();
+    if !args.is_empty() {
+        std::process::exit(cli::run(&db_pool, &args).await);
+    }
+
+    // Lengkapi dataset wilayah per level; gagal seed tidak menghentikan server
+    match models::region::RegionModel::seed(&db_pool).await {
+        Ok(0) => {}
+        Ok(n) => println!("✅ Dataset wilayah dimuat: {} baris", n),
+        Err(e) => eprintln!("⚠️  Gagal memuat dataset wilayah: {}", e),
+    }
+
+    // Isi spesifikasi numerik untuk produk yang dibuat sebelum kolomnya ada
+    match dtos::product::RodProduct::backfill_specs(&db_pool).await {
+        Ok(0) => {}
+        Ok(n) => println!("✅ Spesifikasi numerik diisi untuk {} produk", n),
+        Err(e) => eprintln!("⚠️  Gagal mengisi spesifikasi numerik produk: {}", e),
+    }
+
+    // Index autocomplete produk dibangun sekali di awal, lalu diperbarui saat data berubah
+    utils::suggest::refresh_or_log(&db_pool).await;
+
+    // Lepas stok yang ditahan order PENDING yang tidak dibayar sampai batas waktunya
+    let expiry_pool = db_pool.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+        loop {
+            interval.tick().await;
+            match dtos::order::Order::release_expired(&expiry_pool).await {
+                Ok(0) => {}
+                Ok(n) => println!("⏱️  {} order kedaluwarsa, reservasi stok dilepas", n),
+                Err(e) => eprintln!("⚠️  Gagal melepas reservasi order kedaluwarsa: {}", e),
+            }
+        }
+    });
+
+    // Notifikasi wishlist: harga efektif turun atau stok kembali tersedia
+    let wishlist_pool = db_pool.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+        loop {
+            interval.tick().await;
+            match models::wishlist::WishlistModel::notify_changes(&wishlist_pool).await {
+                Ok(0) => {}
+                Ok(n) => println!("🔔 {} notifikasi wishlist dikirim", n),
+                Err(e) => eprintln!("⚠️  Gagal memproses notifikasi wishlist: {}", e),
+            }
+        }
+    });
+
+    // Laporan stok menipis harian, dibuat sekali per hari setelah jam LOW_STOCK_REPORT_HOUR
+    let report_pool = db_pool.clone();
+    let report_hour: u32 = env::var("LOW_STOCK_REPORT_HOUR")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|h| *h < 24)
+        .unwrap_or(7);
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+        loop {
+            interval.tick().await;
+            let now = chrono::Local::now();
+            if chrono::Timelike::hour(&now) < report_hour {
+                continue;
+            }
+            match models::inventory::InventoryModel::generate_low_stock_report(
+                &report_pool,
+                now.date_naive(),
+            ).await {
+                Ok(None | Some(0)) => {}
+                Ok(Some(n)) => println!("📉 Laporan stok menipis: {} SKU perlu dipesan ulang", n),
+                Err(e) => eprintln!("⚠️  Gagal membuat laporan stok menipis: {}", e),
+            }
+        }
+    });
+
     // 🧩 2. Ambil variabel Midtrans dari .env
     let midtrans_server_key = env
         ::var("MIDTRANS_SERVER_KEY")
@@ -170,25 +468,46 @@ async fn main() {
         ::var("MIDTRANS_BASE_URL")
         .unwrap_or_else(|_| "https://api.sandbox.midtrans.com".to_string());
 
+    let storage = utils::storage::from_env().expect("Konfigurasi storage tidak valid");
+
     let shared_state = Arc::new(AppState {
         db: db_pool,
         midtrans_server_key,
         midtrans_client_key,
         midtrans_base_url,
+        storage,
     });
 
     let app = Router::new()
         .route("/", get(root_handler))
         .route("/api-docs/openapi.json", get(openapi_json))
         .nest("/auth", auth_routes())
+        .nest("/api-keys", api_key_routes())
         .nest("/user", user_routes())
         .nest("/categories", category_routes())
         .nest("/products", product_routes())
         .nest("/orders", order_routes())
+        .nest("/regions", region_routes())
+        .nest("/warehouses", warehouse_routes())
+        .nest("/inventory", stock_report_routes())
+        .nest("/suppliers", supplier_routes())
+        .nest("/purchase-orders", purchase_order_routes())
+        .nest("/flash-sales", flash_sale_routes())
+        .nest("/reviews", review_moderation_routes())
+        .nest("/questions", question_moderation_routes())
+        .nest("/wishlists", wishlist_demand_routes())
         .route("/webhook/payment", post(webhook_payment))
         .nest("/chatbot", chatbot_routes())
+        // state juga ditaruh di extension agar middleware auth bisa cek API key ke DB
+        .layer(Extension(shared_state.clone()))
         .layer(cors_layer()) // tambahkan CORS layer
         .with_state(shared_state);
+
+    // File unggahan disajikan langsung oleh aplikasi jika memakai storage lokal
+    let app = match utils::storage::local_upload_dir() {
+        Some(dir) => app.nest_service(utils::storage::LOCAL_URL_PREFIX, ServeDir::new(dir)),
+        None => app,
+    };
     
     // Note: OpenAPI JSON available at /api-docs/openapi.json
     // Use Swagger Editor (https://editor.swagger.io) or Postman to view the documentation
@@ -215,7 +534,7 @@ async fn main() {
 // ========================
 
 async fn root_handler() -> &'static str {
-    "Server connected to MySQL successfully! Available endpoints: /auth, /user, /categories, /products, /orders. API Docs: /api-docs/openapi.json"
+    "Server connected to MySQL successfully! Available endpoints: /auth, /user, /categories, /products, /orders, /warehouses, /inventory, /suppliers, /purchase-orders. API Docs: /api-docs/openapi.json"
 }
 
 async fn openapi_json() -> axum::Json<utoipa::openapi::OpenApi> {
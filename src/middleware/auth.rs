@@ -1,7 +1,13 @@
+use crate::AppState;
+use crate::models::api_key::ApiKeyModel;
+use crate::utils::api_key::{hash_api_key, parse_prefix, required_scope, scope_allows};
+use crate::utils::csrf::{requires_csrf, verify_csrf};
 use crate::utils::jwt::verify_jwt;
-use axum::extract::FromRequestParts;
-use axum::http::{StatusCode, request::Parts};
+use axum::extract::{FromRequestParts, OriginalUri};
+use axum::http::{Extensions, HeaderMap, Method, StatusCode, Uri, request::Parts};
 use axum_extra::extract::cookie::CookieJar; // <--- ini penting
+use chrono::Utc;
+use std::sync::Arc;
 
 #[derive(Clone, Debug)]
 pub struct AuthUser {
@@ -9,105 +15,201 @@ pub struct AuthUser {
     pub role: String,
 }
 
-// --- Convenience middleware functions for route layers ---
-use axum::body::Body;
-use axum::http::Request;
-use axum::http::StatusCode as HttpStatusCode;
-use axum::middleware::Next;
-use axum::response::{IntoResponse, Response};
+/// Kredensial yang ditemukan pada request.
+enum Credential {
+    /// JWT dari cookie; request yang mengubah data wajib lolos cek CSRF.
+    CookieJwt(String),
+    /// JWT dari header `Authorization`.
+    BearerJwt(String),
+    ApiKey(String),
+}
 
-/// Middleware that ensures a request has a valid JWT (any role).
-/// Returns the inner response when token is valid, otherwise returns 401.
-pub async fn auth_user_middleware(req: Request<Body>, next: Next) -> Response {
-    // reuse cookie/header parsing logic similar to the extractors above
-    let headers = req.headers();
+/// Ambil kredensial dari request dengan urutan prioritas:
+/// 1. cookie `jwt`/`token` (client browser yang sudah login)
+/// 2. header `Authorization` (dengan atau tanpa prefix `Bearer `); nilai berawalan `bk_` = API key
+/// 3. header `X-API-Key`
+fn extract_credential(headers: &HeaderMap) -> Option<Credential> {
     let jar = CookieJar::from_headers(headers);
 
-    let cookie_token = jar
-        .get("jwt")
-        .or_else(|| jar.get("token"))
-        .map(|c| c.value().to_string());
+    if let Some(cookie) = jar.get("jwt").or_else(|| jar.get("token"))
+        && !cookie.value().is_empty()
+    {
+        return Some(Credential::CookieJwt(cookie.value().to_string()));
+    }
 
+    // Toleransi: cek `Authorization`/`authorization`, terima nilai dengan atau tanpa
+    // prefix `Bearer ` dan trim whitespace.
     let header_token = headers
         .get("authorization")
         .or_else(|| headers.get("Authorization"))
         .and_then(|v| v.to_str().ok())
         .map(|v| v.trim())
-        .map(|v| {
-            if let Some(s) = v.strip_prefix("Bearer ") {
-                s.to_string()
-            } else {
-                v.to_string()
+        .map(|v| v.strip_prefix("Bearer ").unwrap_or(v).trim().to_string())
+        .filter(|v| !v.is_empty());
+
+    if let Some(token) = header_token {
+        if parse_prefix(&token).is_some() {
+            return Some(Credential::ApiKey(token));
+        }
+        return Some(Credential::BearerJwt(token));
+    }
+
+    headers
+        .get("x-api-key")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .map(Credential::ApiKey)
+}
+
+/// Verifikasi API key: cocokkan hash, cek masa berlaku dan scope untuk request ini,
+/// lalu catat waktu pemakaian terakhir.
+async fn authenticate_api_key(
+    extensions: &Extensions,
+    key: &str,
+    method: &Method,
+    path: &str,
+) -> Result<AuthUser, (StatusCode, String)> {
+    let state = extensions.get::<Arc<AppState>>().ok_or((
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "State aplikasi tidak tersedia".to_string(),
+    ))?;
+
+    let invalid = || (StatusCode::UNAUTHORIZED, "API key tidak valid".to_string());
+
+    let prefix = parse_prefix(key).ok_or_else(invalid)?;
+    let record = ApiKeyModel::find_active_by_prefix(&state.db, prefix)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or_else(invalid)?;
+
+    if record.key_hash != hash_api_key(key) {
+        return Err(invalid());
+    }
+
+    if let Some(expires_at) = record.expires_at
+        && Utc::now().naive_utc() > expires_at
+    {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            "API key sudah kedaluwarsa".to_string(),
+        ));
+    }
+
+    let scopes: Vec<String> = record
+        .scopes
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    let allowed = required_scope(method, path)
+        .map(|required| scope_allows(&scopes, &required))
+        .unwrap_or(false);
+
+    if !allowed {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "Akses ditolak: scope API key tidak mencukupi.".to_string(),
+        ));
+    }
+
+    if let Err(e) = ApiKeyModel::touch_last_used(&state.db, record.id).await {
+        eprintln!(
+            "Failed to update last_used_at for api key {}: {:?}",
+            record.id, e
+        );
+    }
+
+    Ok(AuthUser {
+        email: record.email,
+        role: record.role,
+    })
+}
+
+/// Path lengkap request. Di dalam router yang di-`nest`, `uri` sudah terpotong prefix-nya,
+/// jadi pakai `OriginalUri` bila tersedia.
+fn request_path<'a>(extensions: &'a Extensions, uri: &'a Uri) -> &'a str {
+    extensions
+        .get::<OriginalUri>()
+        .map(|o| o.0.path())
+        .unwrap_or_else(|| uri.path())
+}
+
+/// Autentikasi request dari cookie, Bearer JWT, atau API key.
+/// Request non-GET yang memakai cookie juga harus membawa header `X-CSRF-Token`.
+async fn authenticate(
+    headers: &HeaderMap,
+    extensions: &Extensions,
+    method: &Method,
+    uri: &Uri,
+) -> Result<AuthUser, (StatusCode, String)> {
+    // Middleware route layer sudah mengautentikasi request ini
+    if let Some(user) = extensions.get::<AuthUser>() {
+        return Ok(user.clone());
+    }
+
+    match extract_credential(headers) {
+        Some(Credential::CookieJwt(token)) => {
+            let claims = verify_jwt(&token)?;
+            if requires_csrf(method) {
+                verify_csrf(headers)?;
             }
-        });
-
-    let token = cookie_token.or(header_token);
-
-    match token {
-        Some(t) if !t.is_empty() => match verify_jwt(&t) {
-            Ok(_) => next.run(req).await,
-            Err(_) => (
-                HttpStatusCode::UNAUTHORIZED,
-                "Token tidak valid".to_string(),
-            )
-                .into_response(),
-        },
-        _ => (
-            HttpStatusCode::UNAUTHORIZED,
+            Ok(AuthUser {
+                email: claims.sub,
+                role: claims.role,
+            })
+        }
+        Some(Credential::BearerJwt(token)) => {
+            let claims = verify_jwt(&token)?;
+            Ok(AuthUser {
+                email: claims.sub,
+                role: claims.role,
+            })
+        }
+        Some(Credential::ApiKey(key)) => {
+            authenticate_api_key(extensions, &key, method, request_path(extensions, uri)).await
+        }
+        None => Err((
+            StatusCode::UNAUTHORIZED,
             "Token tidak ditemukan".to_string(),
-        )
-            .into_response(),
+        )),
     }
 }
 
-/// Middleware that ensures the request contains a JWT with role == "admin".
-pub async fn admin_auth_middleware(req: Request<Body>, next: Next) -> Response {
-    let headers = req.headers();
-    let jar = CookieJar::from_headers(headers);
+// --- Convenience middleware functions for route layers ---
+use axum::body::Body;
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
 
-    let cookie_token = jar
-        .get("jwt")
-        .or_else(|| jar.get("token"))
-        .map(|c| c.value().to_string());
+/// Middleware that ensures a request has a valid JWT or API key (any role).
+/// Returns the inner response when the credential is valid, otherwise returns 401/403.
+pub async fn auth_user_middleware(mut req: Request<Body>, next: Next) -> Response {
+    match authenticate(req.headers(), req.extensions(), req.method(), req.uri()).await {
+        Ok(user) => {
+            req.extensions_mut().insert(user);
+            next.run(req).await
+        }
+        Err(e) => e.into_response(),
+    }
+}
 
-    let header_token = headers
-        .get("authorization")
-        .or_else(|| headers.get("Authorization"))
-        .and_then(|v| v.to_str().ok())
-        .map(|v| v.trim())
-        .map(|v| {
-            if let Some(s) = v.strip_prefix("Bearer ") {
-                s.to_string()
-            } else {
-                v.to_string()
-            }
-        });
-
-    let token = cookie_token.or(header_token);
-
-    match token {
-        Some(t) if !t.is_empty() => match verify_jwt(&t) {
-            Ok(claims) => {
-                if claims.role != "admin" {
-                    return (
-                        HttpStatusCode::FORBIDDEN,
-                        "Akses ditolak: Hanya administrator yang diizinkan.".to_string(),
-                    )
-                        .into_response();
-                }
-                next.run(req).await
+/// Middleware that ensures the request is authenticated with role == "admin".
+pub async fn admin_auth_middleware(mut req: Request<Body>, next: Next) -> Response {
+    match authenticate(req.headers(), req.extensions(), req.method(), req.uri()).await {
+        Ok(user) => {
+            if user.role != "admin" {
+                return (
+                    StatusCode::FORBIDDEN,
+                    "Akses ditolak: Hanya administrator yang diizinkan.".to_string(),
+                )
+                    .into_response();
             }
-            Err(_) => (
-                HttpStatusCode::UNAUTHORIZED,
-                "Token tidak valid".to_string(),
-            )
-                .into_response(),
-        },
-        _ => (
-            HttpStatusCode::UNAUTHORIZED,
-            "Token tidak ditemukan".to_string(),
-        )
-            .into_response(),
+            req.extensions_mut().insert(user);
+            next.run(req).await
+        }
+        Err(e) => e.into_response(),
     }
 }
 
@@ -123,8 +225,6 @@ where
     type Rejection = (StatusCode, String);
 
     async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-        // pakai async block biasa
-        // --- Ambil cookie dari header ---
         // Prioritaskan cookie agar client yang sudah login (cookie httpOnly)
         // tidak perlu menambahkan header Authorization pada request POST/PUT/DELETE.
         // Debug: print Cookie header to help identify if client sent it
@@ -137,52 +237,7 @@ where
             eprintln!("[auth] No Cookie header present on request");
         }
 
-        let jar = CookieJar::from_headers(&parts.headers);
-
-        // Coba ambil token dari cookie dulu (nama cookie: `jwt` atau `token`)
-        let cookie_token = jar
-            .get("jwt")
-            .or_else(|| jar.get("token"))
-            .map(|c| c.value().to_string());
-
-        // Ambil token dari header Authorization jika cookie tidak tersedia
-        // Toleransi: cek `Authorization`/`authorization`, terima nilai dengan atau tanpa
-        // prefix `Bearer ` dan trim whitespace.
-        let header_token = parts
-            .headers
-            .get("authorization")
-            .or_else(|| parts.headers.get("Authorization"))
-            .and_then(|v| v.to_str().ok())
-            .map(|v| v.trim())
-            .map(|v| {
-                if let Some(s) = v.strip_prefix("Bearer ") {
-                    s.to_string()
-                } else {
-                    // allow bare token values as well
-                    v.to_string()
-                }
-            });
-
-        // Pilih token yang ketemu: prefer cookie, lalu header
-        let token = cookie_token.or(header_token);
-
-        let token = match token {
-            Some(t) if !t.is_empty() => t,
-            _ => {
-                return Err((
-                    StatusCode::UNAUTHORIZED,
-                    "Token tidak ditemukan".to_string(),
-                ));
-            }
-        };
-
-        // --- Verifikasi token ---
-        let claims = verify_jwt(&token)?;
-
-        Ok(AuthUser {
-            email: claims.sub,
-            role: claims.role,
-        })
+        authenticate(&parts.headers, &parts.extensions, &parts.method, &parts.uri).await
     }
 }
 
@@ -196,51 +251,6 @@ where
     type Rejection = (StatusCode, String);
 
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
-        // Prioritas utama: coba ambil JWT dari cookie 'jwt' jika tersedia
-        // Debug: log Cookie header for AdminAuth path as well
-        if let Some(h) = parts.headers.get("cookie") {
-            match h.to_str() {
-                Ok(v) => eprintln!("[admin_auth] Incoming Cookie header: {}", v),
-                Err(_) => eprintln!("[admin_auth] Incoming Cookie header: <non-utf8>"),
-            }
-        } else {
-            eprintln!("[admin_auth] No Cookie header present on request");
-        }
-
-        let jar = CookieJar::from_headers(&parts.headers);
-
-        if let Some(cookie) = jar.get("jwt").or_else(|| jar.get("token")) {
-            let token = cookie.value().to_string();
-
-            if token.is_empty() {
-                return Err((
-                    StatusCode::UNAUTHORIZED,
-                    "Token tidak ditemukan".to_string(),
-                ));
-            }
-
-            // Verifikasi token menggunakan util pusat
-            let claims = match verify_jwt(&token) {
-                Ok(c) => c,
-                Err(e) => return Err(e),
-            };
-
-            // Pastikan role admin
-            if claims.role != "admin" {
-                return Err((
-                    StatusCode::FORBIDDEN,
-                    "Akses ditolak: Hanya administrator yang diizinkan.".to_string(),
-                ));
-            }
-
-            let auth_user = AuthUser {
-                email: claims.sub,
-                role: claims.role,
-            };
-            return Ok(AdminAuth(auth_user));
-        }
-
-        // 2. Jika cookie tidak ada, fallback ke mekanisme AuthUser (cek header Authorization + cookie)
         let auth_user = AuthUser::from_request_parts(parts, state).await?;
 
         if auth_user.role != "admin" {
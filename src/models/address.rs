@@ -0,0 +1,198 @@
+// src/models/address.rs
+
+use crate::dtos::address::{AddressDto, NewAddressDto};
+use sqlx::{Executor, MySql, Pool};
+
+pub struct AddressModel;
+
+const ADDRESS_COLUMNS: &str = "id, user_id, label, recipient_name, phone, province, city, \
+    district, postal_code, street, is_default, created_at, updated_at";
+
+impl AddressModel {
+    // --- 1. CREATE ---
+    // Alamat pertama milik user otomatis menjadi default.
+    pub async fn insert(
+        pool: &Pool<MySql>,
+        user_id: i64,
+        new_address: NewAddressDto,
+    ) -> Result<i64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        let existing: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM addresses WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        let is_default = new_address.is_default || existing == 0;
+
+        if is_default {
+            Self::clear_default(&mut *tx, user_id).await?;
+        }
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO addresses (user_id, label, recipient_name, phone, province, city,
+                                   district, postal_code, street, is_default)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(new_address.label)
+        .bind(new_address.recipient_name.trim())
+        .bind(new_address.phone.trim())
+        .bind(new_address.province.trim())
+        .bind(new_address.city.trim())
+        .bind(new_address.district.trim())
+        .bind(new_address.postal_code.trim())
+        .bind(new_address.street.trim())
+        .bind(is_default)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(result.last_insert_id() as i64)
+    }
+
+    // --- 2. FIND ALL MILIK USER ---
+    pub async fn find_by_user(
+        pool: &Pool<MySql>,
+        user_id: i64,
+    ) -> Result<Vec<AddressDto>, sqlx::Error> {
+        sqlx::query_as::<_, AddressDto>(&format!(
+            "SELECT {} FROM addresses WHERE user_id = ? ORDER BY is_default DESC, id DESC",
+            ADDRESS_COLUMNS
+        ))
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    // --- 3. FIND BY ID (hanya jika milik user tersebut) ---
+    pub async fn find_by_id_for_user(
+        executor: impl Executor<'_, Database = MySql>,
+        id: i64,
+        user_id: i64,
+    ) -> Result<Option<AddressDto>, sqlx::Error> {
+        sqlx::query_as::<_, AddressDto>(&format!(
+            "SELECT {} FROM addresses WHERE id = ? AND user_id = ?",
+            ADDRESS_COLUMNS
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(executor)
+        .await
+    }
+
+    // --- 4. UPDATE ---
+    pub async fn update(
+        pool: &Pool<MySql>,
+        id: i64,
+        user_id: i64,
+        updated: NewAddressDto,
+    ) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        if updated.is_default {
+            Self::clear_default(&mut *tx, user_id).await?;
+        }
+
+        let result = sqlx::query(
+            r#"
+            UPDATE addresses SET
+                label = ?, recipient_name = ?, phone = ?, province = ?, city = ?,
+                district = ?, postal_code = ?, street = ?,
+                is_default = (is_default OR ?)
+            WHERE id = ? AND user_id = ?
+            "#,
+        )
+        .bind(updated.label)
+        .bind(updated.recipient_name.trim())
+        .bind(updated.phone.trim())
+        .bind(updated.province.trim())
+        .bind(updated.city.trim())
+        .bind(updated.district.trim())
+        .bind(updated.postal_code.trim())
+        .bind(updated.street.trim())
+        .bind(updated.is_default)
+        .bind(id)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        // Jika alamat tidak ditemukan, jangan sampai default alamat lain ikut terhapus
+        if result.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Ok(0);
+        }
+
+        tx.commit().await?;
+        Ok(result.rows_affected())
+    }
+
+    // --- 5. SET DEFAULT ---
+    pub async fn set_default(
+        pool: &Pool<MySql>,
+        id: i64,
+        user_id: i64,
+    ) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        if Self::find_by_id_for_user(&mut *tx, id, user_id)
+            .await?
+            .is_none()
+        {
+            tx.rollback().await?;
+            return Ok(0);
+        }
+
+        Self::clear_default(&mut *tx, user_id).await?;
+        let result = sqlx::query("UPDATE addresses SET is_default = TRUE WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(result.rows_affected().max(1))
+    }
+
+    // --- 6. DELETE ---
+    // Jika yang dihapus adalah alamat default, alamat terbaru lainnya dijadikan default.
+    pub async fn delete(pool: &Pool<MySql>, id: i64, user_id: i64) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        let address = match Self::find_by_id_for_user(&mut *tx, id, user_id).await? {
+            Some(a) => a,
+            None => {
+                tx.rollback().await?;
+                return Ok(0);
+            }
+        };
+
+        let result = sqlx::query("DELETE FROM addresses WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        if address.is_default {
+            sqlx::query(
+                "UPDATE addresses SET is_default = TRUE WHERE user_id = ? ORDER BY id DESC LIMIT 1",
+            )
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn clear_default(
+        executor: impl Executor<'_, Database = MySql>,
+        user_id: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE addresses SET is_default = FALSE WHERE user_id = ?")
+            .bind(user_id)
+            .execute(executor)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+// src/models/api_key.rs
+
+use crate::dtos::api_key::{ApiKeyDto, ApiKeyOwner};
+use chrono::NaiveDateTime;
+use sqlx::{MySql, Pool};
+
+pub struct ApiKeyModel;
+
+impl ApiKeyModel {
+    // --- 1. CREATE ---
+    #[allow(clippy::too_many_arguments)]
+    pub async fn insert(
+        pool: &Pool<MySql>,
+        user_id: i64,
+        created_by: i64,
+        name: &str,
+        prefix: &str,
+        key_hash: &str,
+        scopes: &str,
+        expires_at: Option<NaiveDateTime>,
+    ) -> Result<i64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO api_keys (user_id, created_by, name, prefix, key_hash, scopes, expires_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(created_by)
+        .bind(name)
+        .bind(prefix)
+        .bind(key_hash)
+        .bind(scopes)
+        .bind(expires_at)
+        .execute(pool)
+        .await?;
+
+        Ok(result.last_insert_id() as i64)
+    }
+
+    // --- 2. FIND ALL ---
+    pub async fn find_all(pool: &Pool<MySql>) -> Result<Vec<ApiKeyDto>, sqlx::Error> {
+        sqlx::query_as::<_, ApiKeyDto>(
+            r#"
+            SELECT k.id, k.name, k.prefix, u.email AS user_email, k.scopes,
+                   k.expires_at, k.last_used_at, k.revoked, k.created_at
+            FROM api_keys k
+            JOIN users u ON k.user_id = u.id
+            ORDER BY k.id DESC
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    // --- 3. LOOKUP UNTUK AUTENTIKASI (key belum dicabut) ---
+    pub async fn find_active_by_prefix(
+        pool: &Pool<MySql>,
+        prefix: &str,
+    ) -> Result<Option<ApiKeyOwner>, sqlx::Error> {
+        sqlx::query_as::<_, ApiKeyOwner>(
+            r#"
+            SELECT k.id, k.key_hash, k.scopes, k.expires_at, u.email, u.role
+            FROM api_keys k
+            JOIN users u ON k.user_id = u.id
+            WHERE k.prefix = ? AND k.revoked = FALSE
+            "#,
+        )
+        .bind(prefix)
+        .fetch_optional(pool)
+        .await
+    }
+
+    // --- 4. CATAT PEMAKAIAN TERAKHIR ---
+    pub async fn touch_last_used(pool: &Pool<MySql>, id: i64) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE api_keys SET last_used_at = NOW() WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    // --- 5. REVOKE ---
+    pub async fn revoke(pool: &Pool<MySql>, id: i64) -> Result<u64, sqlx::Error> {
+        let result =
+            sqlx::query("UPDATE api_keys SET revoked = TRUE WHERE id = ? AND revoked = FALSE")
+                .bind(id)
+                .execute(pool)
+                .await?;
+        Ok(result.rows_affected())
+    }
+
+    // --- 6. KEY MILIK USER TERTENTU ---
+    pub async fn find_by_user_id(
+        pool: &Pool<MySql>,
+        user_id: i64,
+    ) -> Result<Vec<ApiKeyDto>, sqlx::Error> {
+        sqlx::query_as::<_, ApiKeyDto>(
+            r#"
+            SELECT k.id, k.name, k.prefix, u.email AS user_email, k.scopes,
+                   k.expires_at, k.last_used_at, k.revoked, k.created_at
+            FROM api_keys k
+            JOIN users u ON k.user_id = u.id
+            WHERE k.user_id = ?
+            ORDER BY k.id DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,98 @@
+// src/models/attribute.rs
+
+use crate::dtos::attribute::{AttributeDefinition, NewAttributeDto};
+use sqlx::types::Json;
+use sqlx::{MySql, Pool};
+
+pub struct AttributeModel;
+
+const ATTRIBUTE_COLUMNS: &str = "id, category_id, code, label, data_type, unit, allowed_values, \
+    required, min_value, max_value, sort_order";
+
+impl AttributeModel {
+    // --- 1. CREATE ---
+    pub async fn insert(
+        pool: &Pool<MySql>,
+        category_id: i32,
+        attribute: NewAttributeDto,
+    ) -> Result<i64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO category_attributes (category_id, code, label, data_type, unit,
+                                             allowed_values, required, min_value, max_value, sort_order)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(category_id)
+        .bind(attribute.code)
+        .bind(attribute.label.trim())
+        .bind(attribute.data_type.as_str())
+        .bind(attribute.unit)
+        .bind(attribute.allowed_values.map(Json))
+        .bind(attribute.required)
+        .bind(attribute.min_value)
+        .bind(attribute.max_value)
+        .bind(attribute.sort_order)
+        .execute(pool)
+        .await?;
+
+        Ok(result.last_insert_id() as i64)
+    }
+
+    // --- 2. SKEMA SATU KATEGORI ---
+    pub async fn find_by_category(
+        pool: &Pool<MySql>,
+        category_id: i32,
+    ) -> Result<Vec<AttributeDefinition>, sqlx::Error> {
+        sqlx::query_as::<_, AttributeDefinition>(&format!(
+            "SELECT {} FROM category_attributes WHERE category_id = ? ORDER BY sort_order ASC, id ASC",
+            ATTRIBUTE_COLUMNS
+        ))
+        .bind(category_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    // --- 3. UPDATE ---
+    // `code` tidak ikut diubah karena sudah dipakai sebagai key di products.attributes.
+    pub async fn update(
+        pool: &Pool<MySql>,
+        category_id: i32,
+        id: i64,
+        attribute: NewAttributeDto,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE category_attributes SET
+                label = ?, data_type = ?, unit = ?, allowed_values = ?, required = ?,
+                min_value = ?, max_value = ?, sort_order = ?
+            WHERE id = ? AND category_id = ?
+            "#,
+        )
+        .bind(attribute.label.trim())
+        .bind(attribute.data_type.as_str())
+        .bind(attribute.unit)
+        .bind(attribute.allowed_values.map(Json))
+        .bind(attribute.required)
+        .bind(attribute.min_value)
+        .bind(attribute.max_value)
+        .bind(attribute.sort_order)
+        .bind(id)
+        .bind(category_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    // --- 4. DELETE ---
+    pub async fn delete(pool: &Pool<MySql>, category_id: i32, id: i64) -> Result<u64, sqlx::Error> {
+        let result =
+            sqlx::query("DELETE FROM category_attributes WHERE id = ? AND category_id = ?")
+                .bind(id)
+                .bind(category_id)
+                .execute(pool)
+                .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -1,6 +1,10 @@
 // src/models/category.rs
 
-use crate::dtos::category::{KategoriDto, NewKategoriDto};
+use crate::dtos::category::{
+    CategoryCountRow, CategoryTreeNode, KategoriDto, NewKategoriDto, build_category_tree,
+};
+use crate::utils::slug::slugify;
+use crate::utils::suggest;
 use sqlx::{MySql, Pool};
 // use sqlx::FromRow; // tidak dipakai di file ini (jika diperlukan, import di DTO yang sesuai)
 
@@ -13,21 +17,71 @@ pub struct KategoriModel;
 
 impl KategoriModel {
     // --- 1. CREATE (Insert) ---
+    // Slug kosong dibuat dari nama; slug manual yang bentrok ditolak oleh unique index.
     pub async fn insert(
         pool: &Pool<MySql>,
         new_kategori: NewKategoriDto,
     ) -> Result<u64, sqlx::Error> {
-        let result = sqlx::query!("INSERT INTO kategori (name) VALUES (?)", new_kategori.name)
-            .execute(pool)
-            .await?;
+        let slug = match new_kategori.slug {
+            Some(slug) => slug,
+            None => Self::unique_slug(pool, &new_kategori.name).await?,
+        };
+        let result = sqlx::query(
+            r#"
+            INSERT INTO kategori (name, slug, parent_id, description, sort_order)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(new_kategori.name.trim())
+        .bind(slug)
+        .bind(new_kategori.parent_id)
+        .bind(new_kategori.description)
+        .bind(new_kategori.sort_order)
+        .execute(pool)
+        .await?;
+        suggest::refresh_or_log(pool).await;
         Ok(result.last_insert_id())
     }
 
+    /// Slug dari nama yang belum dipakai kategori lain (diberi akhiran `-2`, `-3`, ...).
+    async fn unique_slug(pool: &Pool<MySql>, name: &str) -> Result<String, sqlx::Error> {
+        let base = match slugify(name) {
+            slug if slug.is_empty() => "kategori".to_string(),
+            slug => slug,
+        };
+        let mut candidate = base.clone();
+        let mut suffix = 2;
+        loop {
+            let taken: bool =
+                sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM kategori WHERE slug = ?)")
+                    .bind(&candidate)
+                    .fetch_one(pool)
+                    .await?;
+            if !taken {
+                return Ok(candidate);
+            }
+            candidate = format!("{}-{}", base, suffix);
+            suffix += 1;
+        }
+    }
+
     // --- 2. FIND ALL (READ ALL) ---
     pub async fn find_all(pool: &Pool<MySql>) -> Result<Vec<KategoriDto>, sqlx::Error> {
-        sqlx::query_as::<_, KategoriDto>("SELECT id, name FROM kategori ORDER BY id ASC")
-            .fetch_all(pool)
-            .await
+        sqlx::query_as::<_, KategoriDto>(
+            "SELECT id, name, slug, parent_id, description, sort_order FROM kategori \
+             WHERE archived_at IS NULL ORDER BY sort_order ASC, id ASC",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_archived(pool: &Pool<MySql>) -> Result<Vec<KategoriDto>, sqlx::Error> {
+        sqlx::query_as::<_, KategoriDto>(
+            "SELECT id, name, slug, parent_id, description, sort_order FROM kategori \
+             WHERE archived_at IS NOT NULL ORDER BY id ASC",
+        )
+        .fetch_all(pool)
+        .await
     }
 
     // --- 3. FIND BY ID (READ DETAIL) ---
@@ -35,37 +89,175 @@ impl KategoriModel {
         pool: &Pool<MySql>,
         id: i32,
     ) -> Result<Option<KategoriDto>, sqlx::Error> {
-        sqlx::query_as::<_, KategoriDto>("SELECT id, name FROM kategori WHERE id = ?")
-            .bind(id)
-            .fetch_optional(pool)
-            .await
+        sqlx::query_as::<_, KategoriDto>(
+            "SELECT id, name, slug, parent_id, description, sort_order FROM kategori \
+             WHERE id = ? AND archived_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(pool)
+        .await
     }
 
     // --- 4. UPDATE ---
+    // Slug kosong berarti slug lama dipertahankan agar URL yang sudah dibagikan tetap berlaku.
     pub async fn update(
         pool: &Pool<MySql>,
         id: i32,
-        updated_name: &str,
+        updated: NewKategoriDto,
     ) -> Result<u64, sqlx::Error> {
-        let result = sqlx::query!(
-            "UPDATE kategori SET name = ? WHERE id = ?",
-            updated_name,
-            id
+        let result = sqlx::query(
+            r#"
+            UPDATE kategori
+            SET name = ?, slug = COALESCE(?, slug), parent_id = ?, description = ?, sort_order = ?
+            WHERE id = ? AND archived_at IS NULL
+            "#,
         )
+        .bind(updated.name.trim())
+        .bind(updated.slug)
+        .bind(updated.parent_id)
+        .bind(updated.description)
+        .bind(updated.sort_order)
+        .bind(id)
         .execute(pool)
         .await?;
+        suggest::refresh_or_log(pool).await;
         Ok(result.rows_affected())
     }
 
-    // --- 5. DELETE ---
-    pub async fn delete(pool: &Pool<MySql>, id: i32) -> Result<u64, sqlx::Error> {
-        let result = sqlx::query!("DELETE FROM kategori WHERE id = ?", id)
-            .execute(pool)
-            .await?;
+    // --- 5. DELETE (ARSIPKAN) ---
+    // Produk tetap merujuk ke kategori, jadi barisnya tidak dihapus permanen.
+    // Jika `reassign_to` diisi, semua produknya (termasuk yang diarsipkan) dipindah dulu
+    // ke kategori tersebut dalam transaksi yang sama.
+    pub async fn delete(
+        pool: &Pool<MySql>,
+        id: i32,
+        reassign_to: Option<i32>,
+    ) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let result = sqlx::query(
+            "UPDATE kategori SET archived_at = NOW() WHERE id = ? AND archived_at IS NULL",
+        )
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+        if let Some(target_id) = reassign_to
+            && result.rows_affected() > 0
+        {
+            sqlx::query("UPDATE products SET category_id = ? WHERE category_id = ?")
+                .bind(target_id)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        suggest::refresh_or_log(pool).await;
         Ok(result.rows_affected())
     }
+
+    // Subkategori aktif harus dipindah/diarsipkan dulu sebelum induknya diarsipkan.
+    pub async fn count_active_children(pool: &Pool<MySql>, id: i32) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM kategori WHERE parent_id = ? AND archived_at IS NULL",
+        )
+        .bind(id)
+        .fetch_one(pool)
+        .await
+    }
+
+    // Kategori yang masih dipakai produk aktif tidak boleh diarsipkan.
+    pub async fn count_active_products(pool: &Pool<MySql>, id: i32) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM products WHERE category_id = ? AND archived_at IS NULL",
+        )
+        .bind(id)
+        .fetch_one(pool)
+        .await
+    }
+
+    // --- 6. RESTORE ---
+    // `None` jika kategori tidak ada di arsip, `Some(false)` jika induknya masih diarsipkan.
+    pub async fn restore(pool: &Pool<MySql>, id: i32) -> Result<Option<bool>, sqlx::Error> {
+        let parent_active: Option<bool> = sqlx::query_scalar(
+            r#"
+            SELECT parent.id IS NULL OR parent.archived_at IS NULL
+            FROM kategori k
+            LEFT JOIN kategori parent ON k.parent_id = parent.id
+            WHERE k.id = ? AND k.archived_at IS NOT NULL
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+        match parent_active {
+            Some(true) => {}
+            other => return Ok(other),
+        }
+
+        let result = sqlx::query(
+            "UPDATE kategori SET archived_at = NULL WHERE id = ? AND archived_at IS NOT NULL",
+        )
+        .bind(id)
+        .execute(pool)
+        .await?;
+        suggest::refresh_or_log(pool).await;
+        Ok((result.rows_affected() > 0).then_some(true))
+    }
+
+    // --- 7. CARI BERDASARKAN SLUG ---
+    pub async fn find_by_slug(
+        pool: &Pool<MySql>,
+        slug: &str,
+    ) -> Result<Option<KategoriDto>, sqlx::Error> {
+        sqlx::query_as::<_, KategoriDto>(
+            "SELECT id, name, slug, parent_id, description, sort_order FROM kategori \
+             WHERE slug = ? AND archived_at IS NULL",
+        )
+        .bind(slug)
+        .fetch_optional(pool)
+        .await
+    }
+
+    // --- 8. POHON KATEGORI DENGAN JUMLAH PRODUK ---
+    pub async fn find_tree(pool: &Pool<MySql>) -> Result<Vec<CategoryTreeNode>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, CategoryCountRow>(
+            r#"
+            SELECT c.id, c.parent_id, c.name, c.slug, c.description, c.sort_order,
+                   COUNT(p.id) AS product_count
+            FROM kategori c
+            LEFT JOIN products p ON p.category_id = c.id AND p.archived_at IS NULL
+            WHERE c.archived_at IS NULL
+            GROUP BY c.id, c.parent_id, c.name, c.slug, c.description, c.sort_order
+            ORDER BY c.sort_order ASC, c.id ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(build_category_tree(rows))
+    }
+
+    // --- 9. CEK SIKLUS ---
+    // `true` jika `candidate` adalah `id` sendiri atau salah satu turunannya, sehingga tidak
+    // boleh dijadikan induk `id`.
+    pub async fn is_in_subtree(
+        pool: &Pool<MySql>,
+        id: i32,
+        candidate: i32,
+    ) -> Result<bool, sqlx::Error> {
+        let sql = format!("SELECT ? IN ({})", CATEGORY_SUBTREE_SQL);
+        sqlx::query_scalar(&sql)
+            .bind(candidate)
+            .bind(id)
+            .fetch_one(pool)
+            .await
+    }
 }
 
+/// Id kategori `?` beserta seluruh turunannya (rekursif).
+pub(crate) const CATEGORY_SUBTREE_SQL: &str = "WITH RECURSIVE subtree AS ( \
+    SELECT id FROM kategori WHERE id = ? \
+    UNION ALL SELECT k.id FROM kategori k JOIN subtree s ON k.parent_id = s.id \
+    ) SELECT id FROM subtree";
+
 // Convenience module-level wrappers used by handlers (names expected by handlers)
 // Note: convenience wrappers removed because handlers call KategoriModel::* directly.
 // If other modules need wrappers with these names, re-add them or expose via pub API.
@@ -0,0 +1,379 @@
+// src/models/flash_sale.rs
+//
+// Flash sale: harga khusus satu SKU dengan kuota total dan batas per pelanggan. Kuota dijaga
+// di checkout: baris flash_sales dikunci (FOR UPDATE) di transaksi order, jadi checkout
+// bersamaan untuk flash sale yang sama berjalan bergantian dan `sold` tidak bisa melewati kuota.
+
+use crate::dtos::flash_sale::{FlashSale, NewFlashSaleDto};
+use crate::dtos::order::CheckoutError;
+use crate::dtos::pricing::PricingError;
+use crate::utils::money::Money;
+use chrono::NaiveDateTime;
+use sqlx::{MySql, MySqlConnection, Pool};
+
+pub struct FlashSaleModel;
+
+const FLASH_SALE_SELECT: &str = r#"
+    SELECT f.id, f.name, f.product_id, p.name AS product_name, f.variant_id, v.sku,
+           f.flash_price, COALESCE(v.price, p.price) AS regular_price, f.starts_at, f.ends_at,
+           f.quota, f.quota - f.sold AS remaining, f.per_customer_limit,
+           CASE WHEN f.starts_at > NOW() THEN 'SCHEDULED'
+                WHEN f.ends_at > NOW() THEN 'ACTIVE'
+                ELSE 'ENDED' END AS status
+    FROM flash_sales f
+    JOIN products p ON f.product_id = p.id
+    LEFT JOIN product_variants v ON f.variant_id = v.id
+"#;
+
+/// Baris flash sale yang dikunci saat checkout.
+#[derive(sqlx::FromRow)]
+struct LockedFlashSale {
+    product_id: i64,
+    variant_id: Option<i64>,
+    flash_price: Money,
+    remaining: i64,
+    per_customer_limit: i32,
+    active: bool,
+}
+
+/// Baris flash sale yang dikunci saat dihentikan.
+#[derive(sqlx::FromRow)]
+struct LockedWindow {
+    starts_at: NaiveDateTime,
+    ends_at: NaiveDateTime,
+    now: NaiveDateTime,
+}
+
+/// Unit flash sale milik satu order, untuk dikembalikan ke kuota.
+#[derive(sqlx::FromRow)]
+struct Claim {
+    flash_sale_id: i64,
+    user_id: i64,
+    quantity: i64,
+}
+
+impl FlashSaleModel {
+    // --- 1. FLASH SALE YANG SEDANG BERLANGSUNG (PUBLIK) ---
+    pub async fn find_active(pool: &Pool<MySql>) -> Result<Vec<FlashSale>, sqlx::Error> {
+        let sql = format!(
+            "{} WHERE f.starts_at <= NOW() AND f.ends_at > NOW() AND p.archived_at IS NULL \
+             ORDER BY f.ends_at ASC, f.id ASC",
+            FLASH_SALE_SELECT
+        );
+        sqlx::query_as::<_, FlashSale>(&sql).fetch_all(pool).await
+    }
+
+    // --- 2. SEMUA FLASH SALE (ADMIN) ---
+    pub async fn find_all(pool: &Pool<MySql>) -> Result<Vec<FlashSale>, sqlx::Error> {
+        let sql = format!("{} ORDER BY f.starts_at DESC, f.id DESC", FLASH_SALE_SELECT);
+        sqlx::query_as::<_, FlashSale>(&sql).fetch_all(pool).await
+    }
+
+    // --- 3. BUAT FLASH SALE ---
+    pub async fn create(
+        pool: &Pool<MySql>,
+        flash_sale: NewFlashSaleDto,
+        created_by: Option<i64>,
+    ) -> Result<i64, PricingError> {
+        let mut tx = pool.begin().await?;
+
+        let product_price: Money =
+            sqlx::query_scalar("SELECT price FROM products WHERE id = ? AND archived_at IS NULL")
+                .bind(flash_sale.product_id)
+                .fetch_optional(&mut *tx)
+                .await?
+                .ok_or_else(|| PricingError::NotFound("Produk tidak ditemukan".to_string()))?;
+
+        let regular_price = match flash_sale.variant_id {
+            Some(variant_id) => {
+                let variant_price: Option<Money> = sqlx::query_scalar(
+                    r#"
+                    SELECT price FROM product_variants
+                    WHERE id = ? AND product_id = ? AND is_active = TRUE
+                    "#,
+                )
+                .bind(variant_id)
+                .bind(flash_sale.product_id)
+                .fetch_optional(&mut *tx)
+                .await?
+                .ok_or_else(|| PricingError::NotFound("Varian tidak ditemukan".to_string()))?;
+                variant_price.unwrap_or(product_price)
+            }
+            None => {
+                // Produk bervarian hanya bisa dibeli per varian, jadi flash sale-nya juga per varian
+                let has_variants: bool = sqlx::query_scalar(
+                    r#"
+                    SELECT EXISTS(SELECT 1 FROM product_variants
+                                  WHERE product_id = ? AND is_active = TRUE)
+                    "#,
+                )
+                .bind(flash_sale.product_id)
+                .fetch_one(&mut *tx)
+                .await?;
+                if has_variants {
+                    return Err(PricingError::Invalid(
+                        "Produk memiliki varian, pilih variant_id".to_string(),
+                    ));
+                }
+                product_price
+            }
+        };
+        if flash_sale.flash_price >= regular_price {
+            return Err(PricingError::Invalid(format!(
+                "Harga flash sale harus lebih rendah dari harga normal (Rp {})",
+                regular_price
+            )));
+        }
+
+        let ends_in_future: bool = sqlx::query_scalar("SELECT ? > NOW()")
+            .bind(flash_sale.ends_at)
+            .fetch_one(&mut *tx)
+            .await?;
+        if !ends_in_future {
+            return Err(PricingError::Invalid("ends_at sudah lewat".to_string()));
+        }
+
+        let id = sqlx::query(
+            r#"
+            INSERT INTO flash_sales (name, product_id, variant_id, flash_price, starts_at, ends_at,
+                                     quota, per_customer_limit, created_by)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(flash_sale.name.trim())
+        .bind(flash_sale.product_id)
+        .bind(flash_sale.variant_id)
+        .bind(flash_sale.flash_price)
+        .bind(flash_sale.starts_at)
+        .bind(flash_sale.ends_at)
+        .bind(flash_sale.quota)
+        .bind(flash_sale.per_customer_limit)
+        .bind(created_by)
+        .execute(&mut *tx)
+        .await?
+        .last_insert_id() as i64;
+
+        tx.commit().await?;
+        Ok(id)
+    }
+
+    // --- 4. HENTIKAN FLASH SALE ---
+    // Yang belum dimulai dihapus (`true`); yang sedang berjalan diakhiri sekarang (`false`).
+    // Unit yang sudah dipesan tetap memakai harga flash sale.
+    pub async fn end(pool: &Pool<MySql>, id: i64) -> Result<bool, PricingError> {
+        let mut tx = pool.begin().await?;
+
+        let window = sqlx::query_as::<_, LockedWindow>(
+            "SELECT starts_at, ends_at, NOW() AS now FROM flash_sales WHERE id = ? FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| PricingError::NotFound("Flash sale tidak ditemukan".to_string()))?;
+
+        if window.ends_at <= window.now {
+            return Err(PricingError::Invalid(
+                "Flash sale sudah berakhir".to_string(),
+            ));
+        }
+
+        let cancelled = window.starts_at > window.now;
+        if cancelled {
+            sqlx::query("DELETE FROM flash_sales WHERE id = ?")
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        } else {
+            sqlx::query("UPDATE flash_sales SET ends_at = ? WHERE id = ?")
+                .bind(window.now)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+        Ok(cancelled)
+    }
+
+    // --- 5. AMBIL KUOTA SAAT CHECKOUT ---
+    // Dipanggil di transaksi checkout, urut per SKU sebelum baris stok dikunci. Mengembalikan
+    // harga flash sale per unit. Kuota dan jatah pelanggan langsung bertambah; dikembalikan
+    // oleh `release_order` jika order gagal/kedaluwarsa.
+    pub async fn claim(
+        conn: &mut MySqlConnection,
+        flash_sale_id: i64,
+        user_id: i64,
+        product_id: i64,
+        variant_id: Option<i64>,
+        quantity: i32,
+    ) -> Result<Money, CheckoutError> {
+        let sale = sqlx::query_as::<_, LockedFlashSale>(
+            r#"
+            SELECT product_id, variant_id, flash_price, quota - sold AS remaining,
+                   per_customer_limit, starts_at <= NOW() AND ends_at > NOW() AS active
+            FROM flash_sales
+            WHERE id = ?
+            FOR UPDATE
+            "#,
+        )
+        .bind(flash_sale_id)
+        .fetch_optional(&mut *conn)
+        .await?
+        .filter(|s| s.product_id == product_id && s.variant_id == variant_id)
+        .ok_or_else(|| {
+            CheckoutError::InvalidItem(format!(
+                "Flash sale {} tidak berlaku untuk produk {}.",
+                flash_sale_id, product_id
+            ))
+        })?;
+
+        if !sale.active {
+            return Err(CheckoutError::FlashSaleUnavailable(format!(
+                "Flash sale {} sedang tidak berlangsung.",
+                flash_sale_id
+            )));
+        }
+
+        // Dibaca dengan FOR UPDATE agar melihat pembelian yang baru di-commit checkout lain
+        let bought: i32 = sqlx::query_scalar(
+            r#"
+            SELECT quantity FROM flash_sale_customers
+            WHERE flash_sale_id = ? AND user_id = ?
+            FOR UPDATE
+            "#,
+        )
+        .bind(flash_sale_id)
+        .bind(user_id)
+        .fetch_optional(&mut *conn)
+        .await?
+        .unwrap_or(0);
+
+        if i64::from(bought) + i64::from(quantity) > i64::from(sale.per_customer_limit) {
+            return Err(CheckoutError::FlashSaleUnavailable(format!(
+                "Batas flash sale {} adalah {} unit per pelanggan (sudah dibeli {}).",
+                flash_sale_id, sale.per_customer_limit, bought
+            )));
+        }
+        if sale.remaining < i64::from(quantity) {
+            return Err(CheckoutError::FlashSaleUnavailable(format!(
+                "Kuota flash sale {} tersisa {} unit.",
+                flash_sale_id,
+                sale.remaining.max(0)
+            )));
+        }
+
+        sqlx::query("UPDATE flash_sales SET sold = sold + ? WHERE id = ?")
+            .bind(quantity)
+            .bind(flash_sale_id)
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query(
+            r#"
+            INSERT INTO flash_sale_customers (flash_sale_id, user_id, quantity)
+            VALUES (?, ?, ?)
+            ON DUPLICATE KEY UPDATE quantity = quantity + VALUES(quantity)
+            "#,
+        )
+        .bind(flash_sale_id)
+        .bind(user_id)
+        .bind(quantity)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(sale.flash_price)
+    }
+
+    // --- 6. KEMBALIKAN KUOTA ORDER YANG GAGAL ---
+    // Urutan SKU sama dengan checkout agar kunci diambil dengan urutan yang sama.
+    pub async fn release_order(
+        conn: &mut MySqlConnection,
+        order_id: i64,
+    ) -> Result<(), sqlx::Error> {
+        let claims = sqlx::query_as::<_, Claim>(
+            r#"
+            SELECT oi.flash_sale_id, o.user_id, CAST(SUM(oi.quantity) AS SIGNED) AS quantity
+            FROM order_items oi
+            JOIN orders o ON o.id = oi.order_id
+            WHERE oi.order_id = ? AND oi.flash_sale_id IS NOT NULL
+            GROUP BY oi.flash_sale_id, o.user_id, oi.product_id, oi.variant_id
+            ORDER BY oi.product_id ASC, oi.variant_id ASC
+            "#,
+        )
+        .bind(order_id)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        for claim in claims {
+            sqlx::query("UPDATE flash_sales SET sold = GREATEST(sold - ?, 0) WHERE id = ?")
+                .bind(claim.quantity)
+                .bind(claim.flash_sale_id)
+                .execute(&mut *conn)
+                .await?;
+            sqlx::query(
+                r#"
+                UPDATE flash_sale_customers SET quantity = GREATEST(quantity - ?, 0)
+                WHERE flash_sale_id = ? AND user_id = ?
+                "#,
+            )
+            .bind(claim.quantity)
+            .bind(claim.flash_sale_id)
+            .bind(claim.user_id)
+            .execute(&mut *conn)
+            .await?;
+        }
+        Ok(())
+    }
+
+    // --- 7. AMBIL LAGI KUOTA ORDER YANG DIBAYAR TERLAMBAT ---
+    // Kebalikan `release_order` untuk pembayaran yang masuk setelah order kedaluwarsa. Hanya
+    // kuota total yang dicek: jatah pelanggan sudah lolos saat checkout. `false` jika kuota
+    // tidak lagi cukup; perubahan yang sudah dibuat harus di-rollback oleh pemanggil.
+    pub async fn reclaim_order(
+        conn: &mut MySqlConnection,
+        order_id: i64,
+    ) -> Result<bool, sqlx::Error> {
+        let claims = sqlx::query_as::<_, Claim>(
+            r#"
+            SELECT oi.flash_sale_id, o.user_id, CAST(SUM(oi.quantity) AS SIGNED) AS quantity
+            FROM order_items oi
+            JOIN orders o ON o.id = oi.order_id
+            WHERE oi.order_id = ? AND oi.flash_sale_id IS NOT NULL
+            GROUP BY oi.flash_sale_id, o.user_id, oi.product_id, oi.variant_id
+            ORDER BY oi.product_id ASC, oi.variant_id ASC
+            "#,
+        )
+        .bind(order_id)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        for claim in claims {
+            let remaining: Option<i64> =
+                sqlx::query_scalar("SELECT quota - sold FROM flash_sales WHERE id = ? FOR UPDATE")
+                    .bind(claim.flash_sale_id)
+                    .fetch_optional(&mut *conn)
+                    .await?;
+            if remaining.is_none_or(|r| r < claim.quantity) {
+                return Ok(false);
+            }
+
+            sqlx::query("UPDATE flash_sales SET sold = sold + ? WHERE id = ?")
+                .bind(claim.quantity)
+                .bind(claim.flash_sale_id)
+                .execute(&mut *conn)
+                .await?;
+            sqlx::query(
+                r#"
+                INSERT INTO flash_sale_customers (flash_sale_id, user_id, quantity)
+                VALUES (?, ?, ?)
+                ON DUPLICATE KEY UPDATE quantity = quantity + VALUES(quantity)
+                "#,
+            )
+            .bind(claim.flash_sale_id)
+            .bind(claim.user_id)
+            .bind(claim.quantity)
+            .execute(&mut *conn)
+            .await?;
+        }
+        Ok(true)
+    }
+}
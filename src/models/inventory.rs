@@ -0,0 +1,692 @@
+// src/models/inventory.rs
+
+use crate::dtos::inventory::{
+    LowStockItem, LowStockReport, MovementType, ProductStock, ReorderSettingsDto,
+    StockAdjustmentDto, StockCounts, StockError, StockMovement, StockTransferDto, VariantStock,
+    WarehouseCounts, WarehouseStockLevel,
+};
+use crate::dtos::pagination::{PaginatedResponse, PaginationMeta, PaginationParams};
+use crate::models::variant::VariantModel;
+use crate::models::warehouse::WarehouseModel;
+use chrono::NaiveDate;
+use sqlx::types::Json;
+use sqlx::{FromRow, MySql, MySqlConnection, Pool};
+
+pub struct InventoryModel;
+
+/// Jumlah per SKU di purchase order berstatus ORDERED yang belum diterima.
+const ON_ORDER_SQL: &str = "SELECT poi.product_id, poi.variant_id, \
+    SUM(poi.quantity - poi.received_quantity) AS on_order \
+    FROM purchase_order_items poi \
+    JOIN purchase_orders po ON po.id = poi.purchase_order_id \
+    WHERE po.status = 'ORDERED' \
+    GROUP BY poi.product_id, poi.variant_id";
+
+/// Reservasi satu item order di satu gudang, seperti tercatat di buku besar.
+#[derive(Debug, FromRow)]
+struct Reservation {
+    product_id: i64,
+    variant_id: Option<i64>,
+    warehouse_id: Option<i64>,
+    quantity: i32,
+}
+
+/// Satu baris baru untuk tabel stock_movements.
+pub struct NewMovement<'a> {
+    pub product_id: i64,
+    pub variant_id: Option<i64>,
+    pub warehouse_id: i64,
+    pub order_id: Option<i64>,
+    pub purchase_order_id: Option<i64>,
+    pub movement_type: MovementType,
+    pub quantity: i32,
+    pub stock_after: i32,
+    pub note: Option<&'a str>,
+    pub created_by: Option<i64>,
+}
+
+impl InventoryModel {
+    // --- 1. KUNCI BARIS STOK (SELECT ... FOR UPDATE) ---
+    // Total stok produk/varian; dipakai untuk memastikan SKU-nya ada.
+    pub async fn lock(
+        conn: &mut MySqlConnection,
+        product_id: i64,
+        variant_id: Option<i64>,
+    ) -> Result<Option<StockCounts>, sqlx::Error> {
+        match variant_id {
+            Some(variant_id) => {
+                sqlx::query_as::<_, StockCounts>(
+                    "SELECT stock, reserved_stock FROM product_variants \
+                     WHERE id = ? AND product_id = ? FOR UPDATE",
+                )
+                .bind(variant_id)
+                .bind(product_id)
+                .fetch_optional(conn)
+                .await
+            }
+            None => {
+                sqlx::query_as::<_, StockCounts>(
+                    "SELECT stock, reserved_stock FROM products WHERE id = ? FOR UPDATE",
+                )
+                .bind(product_id)
+                .fetch_optional(conn)
+                .await
+            }
+        }
+    }
+
+    // Stok SKU di semua gudang yang punya barisnya.
+    pub async fn lock_warehouses(
+        conn: &mut MySqlConnection,
+        product_id: i64,
+        variant_id: Option<i64>,
+    ) -> Result<Vec<WarehouseCounts>, sqlx::Error> {
+        sqlx::query_as::<_, WarehouseCounts>(
+            r#"
+            SELECT warehouse_id, stock, reserved_stock
+            FROM warehouse_stock
+            WHERE product_id = ? AND variant_key = ?
+            ORDER BY warehouse_id ASC
+            FOR UPDATE
+            "#,
+        )
+        .bind(product_id)
+        .bind(variant_id.unwrap_or(0))
+        .fetch_all(conn)
+        .await
+    }
+
+    // Stok SKU di satu gudang; baris kosong dibuat dulu jika belum ada.
+    pub async fn lock_warehouse(
+        conn: &mut MySqlConnection,
+        warehouse_id: i64,
+        product_id: i64,
+        variant_id: Option<i64>,
+    ) -> Result<WarehouseCounts, sqlx::Error> {
+        sqlx::query(
+            "INSERT IGNORE INTO warehouse_stock (warehouse_id, product_id, variant_id) VALUES (?, ?, ?)",
+        )
+        .bind(warehouse_id)
+        .bind(product_id)
+        .bind(variant_id)
+        .execute(&mut *conn)
+        .await?;
+
+        sqlx::query_as::<_, WarehouseCounts>(
+            r#"
+            SELECT warehouse_id, stock, reserved_stock
+            FROM warehouse_stock
+            WHERE warehouse_id = ? AND product_id = ? AND variant_key = ?
+            FOR UPDATE
+            "#,
+        )
+        .bind(warehouse_id)
+        .bind(product_id)
+        .bind(variant_id.unwrap_or(0))
+        .fetch_one(conn)
+        .await
+    }
+
+    // --- 2. UBAH STOK FISIK / RESERVASI ---
+    // Baris gudang dan total di products/product_variants diubah bersamaan.
+    // Dipanggil setelah baris gudangnya dikunci di transaksi yang sama.
+    pub async fn apply(
+        conn: &mut MySqlConnection,
+        product_id: i64,
+        variant_id: Option<i64>,
+        warehouse_id: i64,
+        stock_delta: i32,
+        reserved_delta: i32,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE warehouse_stock
+            SET stock = stock + ?, reserved_stock = GREATEST(reserved_stock + ?, 0)
+            WHERE warehouse_id = ? AND product_id = ? AND variant_key = ?
+            "#,
+        )
+        .bind(stock_delta)
+        .bind(reserved_delta)
+        .bind(warehouse_id)
+        .bind(product_id)
+        .bind(variant_id.unwrap_or(0))
+        .execute(&mut *conn)
+        .await?;
+
+        let sql = match variant_id {
+            Some(_) => {
+                "UPDATE product_variants SET stock = stock + ?, \
+                 reserved_stock = GREATEST(reserved_stock + ?, 0) WHERE id = ?"
+            }
+            None => {
+                "UPDATE products SET stock = stock + ?, \
+                 reserved_stock = GREATEST(reserved_stock + ?, 0) WHERE id = ?"
+            }
+        };
+        sqlx::query(sql)
+            .bind(stock_delta)
+            .bind(reserved_delta)
+            .bind(variant_id.unwrap_or(product_id))
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+
+    // --- 3. CATAT KE BUKU BESAR ---
+    pub async fn record(
+        conn: &mut MySqlConnection,
+        movement: NewMovement<'_>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO stock_movements (product_id, variant_id, warehouse_id, order_id,
+                                         purchase_order_id, movement_type, quantity, stock_after,
+                                         note, created_by)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(movement.product_id)
+        .bind(movement.variant_id)
+        .bind(movement.warehouse_id)
+        .bind(movement.order_id)
+        .bind(movement.purchase_order_id)
+        .bind(movement.movement_type.as_str())
+        .bind(movement.quantity)
+        .bind(movement.stock_after)
+        .bind(movement.note)
+        .bind(movement.created_by)
+        .execute(conn)
+        .await?;
+        Ok(())
+    }
+
+    // --- 4. SELESAIKAN RESERVASI ORDER ---
+    // `sold = true`: stok fisik dikurangi (PAID); `false`: reservasi dilepas (FAILED/kedaluwarsa).
+    // Hanya item yang punya catatan RESERVE yang diproses, jadi order lama aman.
+    pub async fn settle_order(
+        conn: &mut MySqlConnection,
+        order_id: i64,
+        sold: bool,
+    ) -> Result<(), sqlx::Error> {
+        let reservations = sqlx::query_as::<_, Reservation>(
+            r#"
+            SELECT product_id, variant_id, warehouse_id, quantity
+            FROM stock_movements
+            WHERE order_id = ? AND movement_type = 'RESERVE'
+            ORDER BY product_id ASC, variant_id ASC, warehouse_id ASC
+            "#,
+        )
+        .bind(order_id)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        for reservation in reservations {
+            let Reservation {
+                product_id,
+                variant_id,
+                warehouse_id: Some(warehouse_id),
+                quantity,
+            } = reservation
+            else {
+                continue;
+            };
+
+            // Varian yang sudah dihapus tidak punya stok lagi untuk diselesaikan
+            if Self::lock(conn, product_id, variant_id).await?.is_none() {
+                continue;
+            }
+            let counts = Self::lock_warehouse(conn, warehouse_id, product_id, variant_id).await?;
+
+            let (movement_type, stock_delta, logged_quantity) = if sold {
+                (MovementType::Sale, -quantity, -quantity)
+            } else {
+                (MovementType::Release, 0, quantity)
+            };
+            Self::apply(
+                conn,
+                product_id,
+                variant_id,
+                warehouse_id,
+                stock_delta,
+                -quantity,
+            )
+            .await?;
+            Self::record(
+                conn,
+                NewMovement {
+                    product_id,
+                    variant_id,
+                    warehouse_id,
+                    order_id: Some(order_id),
+                    purchase_order_id: None,
+                    movement_type,
+                    quantity: logged_quantity,
+                    stock_after: counts.stock + stock_delta,
+                    note: None,
+                    created_by: None,
+                },
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    // Pembayaran yang masuk setelah reservasinya dilepas: jual langsung dari stok bebas di
+    // gudang yang sama. `false` jika ada SKU yang stoknya tidak lagi cukup; perubahan yang
+    // sudah dibuat harus di-rollback oleh pemanggil.
+    pub async fn sell_released(
+        conn: &mut MySqlConnection,
+        order_id: i64,
+    ) -> Result<bool, sqlx::Error> {
+        let reservations = sqlx::query_as::<_, Reservation>(
+            r#"
+            SELECT product_id, variant_id, warehouse_id, quantity
+            FROM stock_movements
+            WHERE order_id = ? AND movement_type = 'RESERVE'
+            ORDER BY product_id ASC, variant_id ASC, warehouse_id ASC
+            "#,
+        )
+        .bind(order_id)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        for reservation in reservations {
+            let Reservation {
+                product_id,
+                variant_id,
+                warehouse_id: Some(warehouse_id),
+                quantity,
+            } = reservation
+            else {
+                continue;
+            };
+
+            if Self::lock(conn, product_id, variant_id).await?.is_none() {
+                return Ok(false);
+            }
+            let counts = Self::lock_warehouse(conn, warehouse_id, product_id, variant_id).await?;
+            if counts.stock - counts.reserved_stock < quantity {
+                return Ok(false);
+            }
+
+            Self::apply(conn, product_id, variant_id, warehouse_id, -quantity, 0).await?;
+            Self::record(
+                conn,
+                NewMovement {
+                    product_id,
+                    variant_id,
+                    warehouse_id,
+                    order_id: Some(order_id),
+                    purchase_order_id: None,
+                    movement_type: MovementType::Sale,
+                    quantity: -quantity,
+                    stock_after: counts.stock - quantity,
+                    note: Some("pembayaran setelah reservasi kedaluwarsa"),
+                    created_by: None,
+                },
+            )
+            .await?;
+        }
+        Ok(true)
+    }
+
+    // Pastikan SKU yang diubah admin valid: produk bervarian wajib menyebut variant_id.
+    async fn check_sku(
+        conn: &mut MySqlConnection,
+        product_id: i64,
+        variant_id: Option<i64>,
+    ) -> Result<(), StockError> {
+        if variant_id.is_none() && VariantModel::has_active_variants(&mut *conn, product_id).await?
+        {
+            return Err(StockError::Invalid(
+                "Produk memiliki varian, sertakan variant_id".to_string(),
+            ));
+        }
+        if Self::lock(conn, product_id, variant_id).await?.is_none() {
+            return Err(StockError::NotFound(
+                "Produk atau varian tidak ditemukan".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn check_warehouse(
+        conn: &mut MySqlConnection,
+        warehouse_id: i64,
+    ) -> Result<(), StockError> {
+        match WarehouseModel::find_by_id(conn, warehouse_id).await? {
+            Some(_) => Ok(()),
+            None => Err(StockError::NotFound(format!(
+                "Gudang {} tidak ditemukan",
+                warehouse_id
+            ))),
+        }
+    }
+
+    // --- 5. PENYESUAIAN STOK OLEH ADMIN ---
+    pub async fn adjust(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        adjustment: &StockAdjustmentDto,
+        created_by: Option<i64>,
+    ) -> Result<WarehouseCounts, StockError> {
+        let mut tx = pool.begin().await?;
+        let variant_id = adjustment.variant_id;
+
+        let warehouse_id = match adjustment.warehouse_id {
+            Some(id) => {
+                Self::check_warehouse(&mut tx, id).await?;
+                id
+            }
+            None => {
+                WarehouseModel::find_primary(&mut *tx)
+                    .await?
+                    .ok_or_else(|| StockError::NotFound("Belum ada gudang aktif".to_string()))?
+                    .id
+            }
+        };
+        Self::check_sku(&mut tx, product_id, variant_id).await?;
+
+        let counts = Self::lock_warehouse(&mut tx, warehouse_id, product_id, variant_id).await?;
+        let new_stock = counts.stock + adjustment.quantity;
+        if new_stock < counts.reserved_stock {
+            return Err(StockError::Invalid(format!(
+                "Stok gudang tidak boleh kurang dari jumlah yang sedang dipesan ({})",
+                counts.reserved_stock
+            )));
+        }
+
+        Self::apply(
+            &mut tx,
+            product_id,
+            variant_id,
+            warehouse_id,
+            adjustment.quantity,
+            0,
+        )
+        .await?;
+        Self::record(
+            &mut tx,
+            NewMovement {
+                product_id,
+                variant_id,
+                warehouse_id,
+                order_id: None,
+                purchase_order_id: None,
+                movement_type: MovementType::Adjustment,
+                quantity: adjustment.quantity,
+                stock_after: new_stock,
+                note: Some(adjustment.note.trim()),
+                created_by,
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(WarehouseCounts {
+            stock: new_stock,
+            ..counts
+        })
+    }
+
+    // --- 6. TRANSFER ANTAR GUDANG ---
+    // Hanya stok yang tidak sedang dipesan yang bisa dipindahkan.
+    pub async fn transfer(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        transfer: &StockTransferDto,
+        created_by: Option<i64>,
+    ) -> Result<(), StockError> {
+        let mut tx = pool.begin().await?;
+        let variant_id = transfer.variant_id;
+        let (from, to) = (transfer.from_warehouse_id, transfer.to_warehouse_id);
+
+        Self::check_warehouse(&mut tx, from).await?;
+        Self::check_warehouse(&mut tx, to).await?;
+        Self::check_sku(&mut tx, product_id, variant_id).await?;
+
+        // Kunci dengan urutan id gudang yang tetap agar dua transfer berlawanan tidak deadlock
+        let (first, second) = (from.min(to), from.max(to));
+        let first_counts = Self::lock_warehouse(&mut tx, first, product_id, variant_id).await?;
+        let second_counts = Self::lock_warehouse(&mut tx, second, product_id, variant_id).await?;
+        let (from_counts, to_counts) = if first == from {
+            (first_counts, second_counts)
+        } else {
+            (second_counts, first_counts)
+        };
+
+        if from_counts.available() < transfer.quantity {
+            return Err(StockError::Invalid(format!(
+                "Stok tersedia di gudang asal hanya {}",
+                from_counts.available().max(0)
+            )));
+        }
+
+        let note = Some(transfer.note.trim());
+        for (warehouse_id, delta, stock_after) in [
+            (
+                from,
+                -transfer.quantity,
+                from_counts.stock - transfer.quantity,
+            ),
+            (to, transfer.quantity, to_counts.stock + transfer.quantity),
+        ] {
+            Self::apply(&mut tx, product_id, variant_id, warehouse_id, delta, 0).await?;
+            Self::record(
+                &mut tx,
+                NewMovement {
+                    product_id,
+                    variant_id,
+                    warehouse_id,
+                    order_id: None,
+                    purchase_order_id: None,
+                    movement_type: MovementType::Transfer,
+                    quantity: delta,
+                    stock_after,
+                    note,
+                    created_by,
+                },
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    // --- 7. RINGKASAN STOK PRODUK ---
+    pub async fn find_product_stock(
+        pool: &Pool<MySql>,
+        product_id: i64,
+    ) -> Result<Option<ProductStock>, sqlx::Error> {
+        let Some(counts) = sqlx::query_as::<_, StockCounts>(
+            "SELECT stock, reserved_stock FROM products WHERE id = ?",
+        )
+        .bind(product_id)
+        .fetch_optional(pool)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let variants = sqlx::query_as::<_, VariantStock>(
+            r#"
+            SELECT id AS variant_id, sku, stock, reserved_stock,
+                   stock - reserved_stock AS available_stock
+            FROM product_variants
+            WHERE product_id = ?
+            ORDER BY id ASC
+            "#,
+        )
+        .bind(product_id)
+        .fetch_all(pool)
+        .await?;
+
+        let warehouses = sqlx::query_as::<_, WarehouseStockLevel>(
+            r#"
+            SELECT ws.warehouse_id, w.code AS warehouse_code, ws.variant_id, ws.stock,
+                   ws.reserved_stock, ws.stock - ws.reserved_stock AS available_stock
+            FROM warehouse_stock ws
+            JOIN warehouses w ON w.id = ws.warehouse_id
+            WHERE ws.product_id = ?
+            ORDER BY w.priority ASC, w.id ASC, ws.variant_key ASC
+            "#,
+        )
+        .bind(product_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(Some(ProductStock {
+            product_id,
+            stock: counts.stock,
+            reserved_stock: counts.reserved_stock,
+            available_stock: counts.available(),
+            variants,
+            warehouses,
+        }))
+    }
+
+    // --- 8. RIWAYAT PERGERAKAN STOK ---
+    pub async fn find_movements(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        params: PaginationParams,
+    ) -> Result<PaginatedResponse<StockMovement>, sqlx::Error> {
+        let total: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM stock_movements WHERE product_id = ?")
+                .bind(product_id)
+                .fetch_one(pool)
+                .await?;
+
+        let movements = sqlx::query_as::<_, StockMovement>(
+            r#"
+            SELECT id, product_id, variant_id, warehouse_id, order_id, purchase_order_id,
+                   movement_type, quantity, stock_after, note, created_by, created_at
+            FROM stock_movements
+            WHERE product_id = ?
+            ORDER BY id DESC
+            LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(product_id)
+        .bind(params.per_page as i64)
+        .bind(params.offset() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(PaginatedResponse {
+            data: movements,
+            pagination: PaginationMeta::new(params.page, params.per_page, total as u32),
+        })
+    }
+
+    // --- 9. TITIK PEMESANAN ULANG ---
+    pub async fn set_reorder(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        settings: &ReorderSettingsDto,
+    ) -> Result<u64, sqlx::Error> {
+        let result =
+            sqlx::query("UPDATE products SET reorder_point = ?, reorder_quantity = ? WHERE id = ?")
+                .bind(settings.reorder_point)
+                .bind(settings.reorder_quantity)
+                .bind(product_id)
+                .execute(pool)
+                .await?;
+        Ok(result.rows_affected())
+    }
+
+    // --- 10. STOK MENIPIS ---
+    // Posisi stok = tersedia (semua gudang) + yang sudah dipesan ke supplier tapi belum diterima.
+    pub async fn find_low_stock(pool: &Pool<MySql>) -> Result<Vec<LowStockItem>, sqlx::Error> {
+        let items = sqlx::query_as::<_, LowStockItem>(&format!(
+            r#"
+            SELECT p.id AS product_id, p.name AS product_name,
+                   CAST(NULL AS SIGNED) AS variant_id, CAST(NULL AS CHAR) AS sku,
+                   p.stock - p.reserved_stock AS available_stock,
+                   CAST(COALESCE(o.on_order, 0) AS SIGNED) AS on_order,
+                   p.reorder_point, p.reorder_quantity
+            FROM products p
+            LEFT JOIN ({on_order}) o ON o.product_id = p.id AND o.variant_id IS NULL
+            WHERE p.reorder_point IS NOT NULL AND p.archived_at IS NULL
+              AND NOT EXISTS (
+                  SELECT 1 FROM product_variants v WHERE v.product_id = p.id AND v.is_active = TRUE
+              )
+              AND p.stock - p.reserved_stock + COALESCE(o.on_order, 0) <= p.reorder_point
+            UNION ALL
+            SELECT p.id, p.name, v.id, v.sku,
+                   v.stock - v.reserved_stock,
+                   CAST(COALESCE(o.on_order, 0) AS SIGNED),
+                   p.reorder_point, p.reorder_quantity
+            FROM product_variants v
+            JOIN products p ON p.id = v.product_id
+            LEFT JOIN ({on_order}) o ON o.product_id = p.id AND o.variant_id = v.id
+            WHERE p.reorder_point IS NOT NULL AND p.archived_at IS NULL AND v.is_active = TRUE
+              AND v.stock - v.reserved_stock + COALESCE(o.on_order, 0) <= p.reorder_point
+            ORDER BY product_id ASC, variant_id ASC
+            "#,
+            on_order = ON_ORDER_SQL
+        ))
+        .fetch_all(pool)
+        .await?;
+
+        Ok(items
+            .into_iter()
+            .map(LowStockItem::with_suggestion)
+            .collect())
+    }
+
+    // Simpan laporan untuk satu tanggal; `None` jika laporan tanggal itu sudah ada.
+    pub async fn generate_low_stock_report(
+        pool: &Pool<MySql>,
+        report_date: NaiveDate,
+    ) -> Result<Option<usize>, sqlx::Error> {
+        let exists: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM low_stock_reports WHERE report_date = ?")
+                .bind(report_date)
+                .fetch_one(pool)
+                .await?;
+        if exists > 0 {
+            return Ok(None);
+        }
+
+        let items = Self::find_low_stock(pool).await?;
+        let count = items.len();
+        sqlx::query(
+            "INSERT IGNORE INTO low_stock_reports (report_date, item_count, items) VALUES (?, ?, ?)",
+        )
+        .bind(report_date)
+        .bind(count as i32)
+        .bind(Json(items))
+        .execute(pool)
+        .await?;
+        Ok(Some(count))
+    }
+
+    pub async fn find_low_stock_reports(
+        pool: &Pool<MySql>,
+        params: PaginationParams,
+    ) -> Result<PaginatedResponse<LowStockReport>, sqlx::Error> {
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM low_stock_reports")
+            .fetch_one(pool)
+            .await?;
+
+        let reports = sqlx::query_as::<_, LowStockReport>(
+            r#"
+            SELECT id, report_date, item_count, items, generated_at
+            FROM low_stock_reports
+            ORDER BY report_date DESC
+            LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(params.per_page as i64)
+        .bind(params.offset() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(PaginatedResponse {
+            data: reports,
+            pagination: PaginationMeta::new(params.page, params.per_page, total as u32),
+        })
+    }
+}
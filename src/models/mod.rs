@@ -1,4 +1,22 @@
+pub mod address;
+pub mod api_key;
+pub mod attribute;
 pub mod category;
+pub mod flash_sale;
+pub mod inventory;
+pub mod notification;
 pub mod order;
+pub mod price_history;
 pub mod product;
+pub mod product_image;
+pub mod purchase_order;
+pub mod question;
+pub mod region;
+pub mod review;
+pub mod sale;
+pub mod search;
+pub mod supplier;
 pub mod user;
+pub mod variant;
+pub mod warehouse;
+pub mod wishlist;
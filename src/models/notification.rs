@@ -0,0 +1,131 @@
+// src/models/notification.rs
+//
+// Notifikasi in-app. Dibuat oleh fitur lain (di transaksi yang sama dengan kejadiannya) dan
+// dibaca user lewat /user/notifications.
+
+use crate::dtos::notification::{NewNotification, Notification};
+use crate::dtos::pagination::{PaginationMeta, PaginationParams};
+use sqlx::types::Json;
+use sqlx::{Executor, MySql, Pool};
+
+pub struct NotificationModel;
+
+impl NotificationModel {
+    // --- 1. CREATE ---
+    pub async fn create(
+        executor: impl Executor<'_, Database = MySql>,
+        notification: NewNotification,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO notifications (user_id, type, title, message, data)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(notification.user_id)
+        .bind(notification.kind.as_str())
+        .bind(notification.title)
+        .bind(notification.message)
+        .bind(notification.data.map(Json))
+        .execute(executor)
+        .await?;
+        Ok(result.last_insert_id())
+    }
+
+    // --- 2. DAFTAR NOTIFIKASI USER ---
+    // Mengembalikan notifikasi (terbaru dulu), pagination, dan jumlah yang belum dibaca.
+    pub async fn find_by_user(
+        pool: &Pool<MySql>,
+        user_id: i64,
+        unread_only: bool,
+        params: &PaginationParams,
+    ) -> Result<(Vec<Notification>, PaginationMeta, i64), sqlx::Error> {
+        let (total, unread): (i64, i64) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*), CAST(COALESCE(SUM(read_at IS NULL), 0) AS SIGNED)
+            FROM notifications
+            WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(pool)
+        .await?;
+
+        let filter = if unread_only {
+            " AND read_at IS NULL"
+        } else {
+            ""
+        };
+        let notifications = sqlx::query_as::<_, Notification>(&format!(
+            r#"
+            SELECT id, type, title, message, data, read_at, created_at
+            FROM notifications
+            WHERE user_id = ?{}
+            ORDER BY created_at DESC, id DESC
+            LIMIT ? OFFSET ?
+            "#,
+            filter
+        ))
+        .bind(user_id)
+        .bind(params.per_page as i64)
+        .bind(params.offset() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        let listed = if unread_only { unread } else { total };
+        Ok((
+            notifications,
+            PaginationMeta::new(params.page, params.per_page, listed as u32),
+            unread,
+        ))
+    }
+
+    // --- 3. TANDAI SUDAH DIBACA ---
+    // `false` jika notifikasi tidak ada atau milik user lain.
+    pub async fn mark_read(pool: &Pool<MySql>, user_id: i64, id: i64) -> Result<bool, sqlx::Error> {
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM notifications WHERE id = ? AND user_id = ?)",
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_one(pool)
+        .await?;
+        if !exists {
+            return Ok(false);
+        }
+
+        sqlx::query("UPDATE notifications SET read_at = NOW() WHERE id = ? AND read_at IS NULL")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(true)
+    }
+
+    pub async fn mark_all_read(pool: &Pool<MySql>, user_id: i64) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE notifications SET read_at = NOW() WHERE user_id = ? AND read_at IS NULL",
+        )
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    // --- 4. SEMUA NOTIFIKASI USER (EKSPOR DATA PRIBADI) ---
+    pub async fn find_all_by_user(
+        pool: &Pool<MySql>,
+        user_id: i64,
+    ) -> Result<Vec<Notification>, sqlx::Error> {
+        sqlx::query_as::<_, Notification>(
+            r#"
+            SELECT id, type, title, message, data, read_at, created_at
+            FROM notifications
+            WHERE user_id = ?
+            ORDER BY created_at DESC, id DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+}
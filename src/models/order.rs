@@ -1,85 +1,408 @@
-use crate::dtos::order::{NewOrderDto, Order};
+use crate::dtos::inventory::{MovementType, StockShortage, WarehouseCounts};
+use crate::dtos::order::{
+    CheckoutError, NewOrderDto, Order, PaymentUpdate, RefundRequiredOrder, ShippingDetails,
+};
 use crate::dtos::product::RodProduct;
+use crate::dtos::user::ExportedOrderItem;
+use crate::models::flash_sale::FlashSaleModel;
+use crate::models::inventory::{InventoryModel, NewMovement};
+use crate::models::variant::VariantModel;
+use crate::models::warehouse::WarehouseModel;
+use crate::utils::fulfilment::{LineDemand, plan_fulfilment, rank_warehouses};
+use crate::utils::money::Money;
 use sqlx::{MySql, Pool};
 
+/// Item order yang sudah divalidasi, sebelum stoknya dialokasikan ke gudang.
+struct CheckoutItem {
+    product_id: i64,
+    variant_id: Option<i64>,
+    sku: Option<String>,
+    quantity: i32,
+    unit_price: Money,
+    flash_sale_id: Option<i64>,
+}
+
+/// Satu baris order_items (item di satu gudang) yang akan disimpan setelah stok ditahan.
+struct OrderLine {
+    product_id: i64,
+    variant_id: Option<i64>,
+    warehouse_id: i64,
+    sku: Option<String>,
+    quantity: i32,
+    price_at_order: Money,
+    flash_sale_id: Option<i64>,
+    /// Stok fisik gudang saat dikunci, untuk kolom stock_after di buku besar
+    stock: i32,
+}
+
+/// Lama stok ditahan untuk order yang belum dibayar (`ORDER_RESERVATION_MINUTES`, default 60).
+/// Halaman pembayaran Midtrans diberi batas waktu yang sama.
+pub(crate) fn reservation_minutes() -> i64 {
+    std::env::var("ORDER_RESERVATION_MINUTES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|m| *m > 0)
+        .unwrap_or(60)
+}
+
 // Catatan: Asumsi RodProduct::find_by_id sudah ada dan menerima &mut Transaction
 
 impl Order {
     // --- 1. PROSES CHECKOUT (CREATE ORDER) ---
+    // Stok setiap item dikunci (SELECT ... FOR UPDATE) lalu ditahan di transaksi yang sama,
+    // sehingga dua checkout bersamaan tidak bisa menjual stok yang sama. Gudang pengirim
+    // dipilih dari yang terdekat ke alamat tujuan; order dipecah jika satu gudang tidak cukup.
+    // Mengembalikan id order dan total tagihannya (untuk gross_amount Midtrans).
     pub async fn create_order(
         pool: &Pool<MySql>,
         user_id: i64,
         new_order: NewOrderDto,
-    ) -> Result<i64, sqlx::Error> {
+        shipping: ShippingDetails,
+    ) -> Result<(i64, Money), CheckoutError> {
         let mut tx = pool.begin().await?;
-        let mut total_amount = 0.0;
-        let mut items_to_insert: Vec<(i64, i32, f64)> = Vec::new();
+        let mut items: Vec<CheckoutItem> = Vec::new();
 
         for item_dto in new_order.items.into_iter() {
-            let product = RodProduct::find_by_id(&mut *tx, item_dto.product_id)
-                .await
-                .map_err(|_| sqlx::Error::RowNotFound)?;
+            if item_dto.quantity <= 0 {
+                return Err(CheckoutError::InvalidItem(format!(
+                    "Kuantitas produk {} harus lebih dari 0.",
+                    item_dto.product_id
+                )));
+            }
+
+            let product = match RodProduct::find_by_id(&mut *tx, item_dto.product_id).await {
+                Ok(product) => product,
+                Err(sqlx::Error::RowNotFound) => {
+                    return Err(CheckoutError::InvalidItem(
+                        "Gagal checkout. Salah satu produk tidak valid.".to_string(),
+                    ));
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            // Produk bervarian: harga & stok diambil dari varian yang dipilih
+            let (unit_price, sku) = match item_dto.variant_id {
+                Some(variant_id) => {
+                    let variant = VariantModel::find_for_product(&mut *tx, product.id, variant_id)
+                        .await?
+                        .filter(|v| v.is_active)
+                        .ok_or_else(|| {
+                            CheckoutError::InvalidItem(format!(
+                                "Varian {} tidak tersedia untuk produk {}.",
+                                variant_id, product.id
+                            ))
+                        })?;
+                    (variant.final_price, Some(variant.sku))
+                }
+                None => {
+                    if VariantModel::has_active_variants(&mut *tx, product.id).await? {
+                        return Err(CheckoutError::InvalidItem(format!(
+                            "Produk {} memiliki varian, pilih variant_id.",
+                            product.id
+                        )));
+                    }
+                    (product.final_price, None)
+                }
+            };
+
+            // SKU yang sama di beberapa baris digabung agar stoknya dihitung sekali
+            if let Some(existing) = items
+                .iter_mut()
+                .find(|i| i.product_id == product.id && i.variant_id == item_dto.variant_id)
+            {
+                if existing.flash_sale_id != item_dto.flash_sale_id {
+                    return Err(CheckoutError::InvalidItem(format!(
+                        "Produk {} muncul di beberapa baris dengan flash sale berbeda.",
+                        product.id
+                    )));
+                }
+                existing.quantity = existing
+                    .quantity
+                    .checked_add(item_dto.quantity)
+                    .ok_or_else(|| {
+                        CheckoutError::InvalidItem(format!(
+                            "Kuantitas produk {} terlalu besar.",
+                            product.id
+                        ))
+                    })?;
+                continue;
+            }
+            items.push(CheckoutItem {
+                product_id: product.id,
+                variant_id: item_dto.variant_id,
+                sku,
+                quantity: item_dto.quantity,
+                unit_price,
+                flash_sale_id: item_dto.flash_sale_id,
+            });
+        }
+
+        // Baris stok dikunci dengan urutan tetap agar checkout bersamaan tidak saling deadlock
+        items.sort_by_key(|i| (i.product_id, i.variant_id));
+
+        // Kuota flash sale diambil lebih dulu (urutan SKU yang sama) dan ikut di-rollback
+        // jika checkout gagal
+        for item in items.iter_mut() {
+            if let Some(flash_sale_id) = item.flash_sale_id {
+                item.unit_price = FlashSaleModel::claim(
+                    &mut tx,
+                    flash_sale_id,
+                    user_id,
+                    item.product_id,
+                    item.variant_id,
+                    item.quantity,
+                )
+                .await?;
+            }
+        }
+
+        let warehouses = WarehouseModel::find_all(&mut *tx).await?;
+        let ranked = rank_warehouses(
+            &warehouses,
+            shipping.province.as_deref(),
+            shipping.city.as_deref(),
+        );
+
+        let mut demands: Vec<LineDemand> = Vec::with_capacity(items.len());
+        let mut locked_rows: Vec<Vec<WarehouseCounts>> = Vec::with_capacity(items.len());
+        let mut shortages: Vec<StockShortage> = Vec::new();
 
-            let item_price = product.price * (item_dto.quantity as f64);
-            total_amount += item_price;
-            items_to_insert.push((item_dto.product_id, item_dto.quantity, product.price));
+        for item in &items {
+            InventoryModel::lock(&mut tx, item.product_id, item.variant_id).await?;
+            let rows =
+                InventoryModel::lock_warehouses(&mut tx, item.product_id, item.variant_id).await?;
+            let demand = LineDemand {
+                quantity: item.quantity,
+                available: rows
+                    .iter()
+                    .map(|r| (r.warehouse_id, r.available()))
+                    .collect(),
+            };
+
+            let available = demand.total_available(&ranked);
+            if available < item.quantity {
+                shortages.push(StockShortage {
+                    product_id: item.product_id,
+                    variant_id: item.variant_id,
+                    sku: item.sku.clone(),
+                    requested: item.quantity,
+                    available,
+                });
+            }
+            demands.push(demand);
+            locked_rows.push(rows);
+        }
+
+        // Semua item yang kurang stok dilaporkan sekaligus; transaksi di-rollback saat di-drop
+        if !shortages.is_empty() {
+            return Err(CheckoutError::OutOfStock(shortages));
+        }
+
+        let plan = plan_fulfilment(&ranked, &demands);
+        let mut total_amount = Money::ZERO;
+        let mut items_to_insert: Vec<OrderLine> = Vec::new();
+
+        for ((item, allocations), rows) in items.iter().zip(plan).zip(&locked_rows) {
+            total_amount = item
+                .unit_price
+                .times(item.quantity)
+                .and_then(|subtotal| total_amount.checked_add(subtotal))
+                .ok_or_else(|| {
+                    CheckoutError::InvalidItem(format!(
+                        "Total order untuk produk {} terlalu besar.",
+                        item.product_id
+                    ))
+                })?;
+
+            for (warehouse_id, quantity) in allocations {
+                InventoryModel::apply(
+                    &mut tx,
+                    item.product_id,
+                    item.variant_id,
+                    warehouse_id,
+                    0,
+                    quantity,
+                )
+                .await?;
+                items_to_insert.push(OrderLine {
+                    product_id: item.product_id,
+                    variant_id: item.variant_id,
+                    warehouse_id,
+                    sku: item.sku.clone(),
+                    quantity,
+                    price_at_order: item.unit_price,
+                    flash_sale_id: item.flash_sale_id,
+                    stock: rows
+                        .iter()
+                        .find(|r| r.warehouse_id == warehouse_id)
+                        .map_or(0, |r| r.stock),
+                });
+            }
         }
 
         // 2. Insert ke tabel orders
         let order_result = sqlx::query(
             r#"
-            INSERT INTO orders (user_id, total_amount, shipping_address, payment_method, status)
-            VALUES (?, ?, ?, ?, 'PENDING')
+            INSERT INTO orders (user_id, total_amount, shipping_address, address_id,
+                                shipping_address_snapshot, payment_method, status, reserved_until)
+            VALUES (?, ?, ?, ?, ?, ?, 'PENDING', DATE_ADD(NOW(), INTERVAL ? MINUTE))
             "#,
         )
         .bind(user_id)
         .bind(total_amount)
-        .bind(new_order.shipping_address)
+        .bind(shipping.text)
+        .bind(shipping.address_id)
+        .bind(shipping.snapshot)
         .bind(new_order.payment_method)
+        .bind(reservation_minutes())
         .execute(&mut *tx)
         .await?;
 
         let order_id = order_result.last_insert_id() as i64;
 
-        // 3. Insert ke tabel order_items
-        for (product_id, quantity, price_at_order) in items_to_insert {
+        // 3. Insert ke tabel order_items + catat reservasi di buku besar stok
+        for line in items_to_insert {
             sqlx::query(
                 r#"
-                INSERT INTO order_items (order_id, product_id, quantity, price_at_order)
-                VALUES (?, ?, ?, ?)
+                INSERT INTO order_items (order_id, product_id, variant_id, warehouse_id, sku,
+                                         quantity, price_at_order, flash_sale_id)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
                 "#,
             )
             .bind(order_id)
-            .bind(product_id)
-            .bind(quantity)
-            .bind(price_at_order)
+            .bind(line.product_id)
+            .bind(line.variant_id)
+            .bind(line.warehouse_id)
+            .bind(&line.sku)
+            .bind(line.quantity)
+            .bind(line.price_at_order)
+            .bind(line.flash_sale_id)
             .execute(&mut *tx)
             .await?;
+
+            InventoryModel::record(
+                &mut tx,
+                NewMovement {
+                    product_id: line.product_id,
+                    variant_id: line.variant_id,
+                    warehouse_id: line.warehouse_id,
+                    order_id: Some(order_id),
+                    purchase_order_id: None,
+                    movement_type: MovementType::Reserve,
+                    quantity: line.quantity,
+                    stock_after: line.stock,
+                    note: None,
+                    created_by: None,
+                },
+            )
+            .await?;
         }
         tx.commit().await?;
-        Ok(order_id)
+        Ok((order_id, total_amount))
     }
 
     // --- 2. LOGIKA PEMBAYARAN (UPDATE STATUS ORDER) ---
+    // PAID mengurangi stok fisik sebesar reservasi; FAILED melepas reservasinya
+    // beserta kuota flash sale yang dipakai order.
+    // Pembayaran sukses untuk order yang sudah FAILED (mis. dibayar setelah kedaluwarsa) tetap
+    // diproses: stok dan kuota diambil lagi jika masih ada, selain itu order ditandai refund.
     pub async fn process_payment(
         pool: &Pool<MySql>,
         order_id: i64,
         is_success: bool,
-    ) -> Result<u64, sqlx::Error> {
-        let new_status = if is_success { "PAID" } else { "FAILED" };
+    ) -> Result<PaymentUpdate, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        let locked = sqlx::query_as::<_, (String, bool)>(
+            "SELECT status, refund_required_at IS NOT NULL FROM orders WHERE id = ? FOR UPDATE",
+        )
+        .bind(order_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some((status, refund_flagged)) = locked else {
+            return Ok(PaymentUpdate::Unchanged);
+        };
 
-        let result = sqlx::query(
+        let outcome = match (status.as_str(), is_success) {
+            ("PENDING", _) => {
+                let new_status = if is_success { "PAID" } else { "FAILED" };
+                sqlx::query("UPDATE orders SET status = ? WHERE id = ?")
+                    .bind(new_status)
+                    .bind(order_id)
+                    .execute(&mut *tx)
+                    .await?;
+                if !is_success {
+                    FlashSaleModel::release_order(&mut tx, order_id).await?;
+                }
+                InventoryModel::settle_order(&mut tx, order_id, is_success).await?;
+                PaymentUpdate::Updated
+            }
+            ("FAILED", true) if !refund_flagged => {
+                // Savepoint: jika salah satu SKU/kuota habis, pengambilan yang sudah terjadi dibatalkan
+                let mut retry = sqlx::Connection::begin(&mut *tx).await?;
+                let reclaimed = FlashSaleModel::reclaim_order(&mut retry, order_id).await?
+                    && InventoryModel::sell_released(&mut retry, order_id).await?;
+                if reclaimed {
+                    retry.commit().await?;
+                    sqlx::query("UPDATE orders SET status = 'PAID' WHERE id = ?")
+                        .bind(order_id)
+                        .execute(&mut *tx)
+                        .await?;
+                    PaymentUpdate::LatePaid
+                } else {
+                    retry.rollback().await?;
+                    sqlx::query("UPDATE orders SET refund_required_at = NOW() WHERE id = ?")
+                        .bind(order_id)
+                        .execute(&mut *tx)
+                        .await?;
+                    PaymentUpdate::RefundRequired
+                }
+            }
+            _ => PaymentUpdate::Unchanged,
+        };
+
+        tx.commit().await?;
+        Ok(outcome)
+    }
+
+    // Batalkan order PENDING yang reservasinya sudah lewat batas waktu.
+    pub async fn release_expired(pool: &Pool<MySql>) -> Result<u64, sqlx::Error> {
+        let expired: Vec<i64> = sqlx::query_scalar(
             r#"
-            UPDATE orders SET status = ?
-            WHERE id = ? AND status = 'PENDING'
+            SELECT id FROM orders
+            WHERE status = 'PENDING' AND reserved_until < NOW()
+            ORDER BY reserved_until ASC
+            LIMIT 100
             "#,
         )
-        .bind(new_status)
-        .bind(order_id)
-        .execute(pool)
+        .fetch_all(pool)
         .await?;
 
-        Ok(result.rows_affected())
+        let mut released = 0;
+        for order_id in expired {
+            if Self::process_payment(pool, order_id, false).await? == PaymentUpdate::Updated {
+                released += 1;
+            }
+        }
+        Ok(released)
+    }
+
+    // Order yang ditandai perlu refund, terbaru dulu.
+    pub async fn find_refund_required(
+        pool: &Pool<MySql>,
+    ) -> Result<Vec<RefundRequiredOrder>, sqlx::Error> {
+        sqlx::query_as::<_, RefundRequiredOrder>(
+            r#"
+            SELECT o.id AS order_id, o.user_id, u.email, o.total_amount, o.order_date,
+                   o.refund_required_at
+            FROM orders o
+            JOIN users u ON u.id = o.user_id
+            WHERE o.refund_required_at IS NOT NULL
+            ORDER BY o.refund_required_at DESC, o.id DESC
+            "#,
+        )
+        .fetch_all(pool)
+        .await
     }
 
     // --- 3. FUNGSI BARU CEK STATUS LOKAL ---
@@ -93,7 +416,7 @@ impl Order {
             SELECT
                 id,
                 user_id,
-                total_amount,
+                total_amount as "total_amount: Money",
                 shipping_address,
                 payment_method,
                 status,
@@ -109,4 +432,42 @@ impl Order {
         // Mengembalikan hanya status
         Ok(order_record.status)
     }
+
+    // --- 4. RIWAYAT ORDER MILIK USER ---
+    pub async fn find_by_user_id(
+        pool: &Pool<MySql>,
+        user_id: i64,
+    ) -> Result<Vec<Order>, sqlx::Error> {
+        sqlx::query_as::<_, Order>(
+            r#"
+            SELECT id, user_id, total_amount, shipping_address, payment_method, status, order_date
+            FROM orders
+            WHERE user_id = ?
+            ORDER BY id DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    // --- 5. ITEM ORDER (untuk ekspor data) ---
+    pub async fn find_export_items(
+        pool: &Pool<MySql>,
+        order_id: i64,
+    ) -> Result<Vec<ExportedOrderItem>, sqlx::Error> {
+        sqlx::query_as::<_, ExportedOrderItem>(
+            r#"
+            SELECT oi.product_id, p.name AS product_name, oi.sku, w.code AS warehouse_code,
+                   oi.quantity, oi.price_at_order
+            FROM order_items oi
+            LEFT JOIN products p ON oi.product_id = p.id
+            LEFT JOIN warehouses w ON oi.warehouse_id = w.id
+            WHERE oi.order_id = ?
+            "#,
+        )
+        .bind(order_id)
+        .fetch_all(pool)
+        .await
+    }
 }
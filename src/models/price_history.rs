@@ -0,0 +1,117 @@
+// src/models/price_history.rs
+
+use crate::dtos::pagination::{PaginatedResponse, PaginationMeta, PaginationParams};
+use crate::dtos::pricing::{PriceChangeType, PriceHistoryEntry};
+use crate::utils::money::Money;
+use chrono::NaiveDateTime;
+use sqlx::{MySql, MySqlConnection, Pool};
+
+pub struct PriceHistoryModel;
+
+/// Satu baris baru untuk tabel price_history.
+pub struct NewPriceChange {
+    pub product_id: i64,
+    pub variant_id: Option<i64>,
+    pub change_type: PriceChangeType,
+    pub old_price: Option<Money>,
+    pub new_price: Option<Money>,
+    /// Sale terkait beserta jadwalnya (hanya untuk perubahan sale)
+    pub sale: Option<(i64, NaiveDateTime, NaiveDateTime)>,
+    pub changed_by: Option<i64>,
+}
+
+impl PriceHistoryModel {
+    // --- 1. CATAT PERUBAHAN ---
+    // Dipanggil di transaksi yang sama dengan perubahan harganya.
+    pub async fn record(
+        conn: &mut MySqlConnection,
+        change: NewPriceChange,
+    ) -> Result<(), sqlx::Error> {
+        let (sale_id, starts_at, ends_at) = match change.sale {
+            Some((id, starts_at, ends_at)) => (Some(id), Some(starts_at), Some(ends_at)),
+            None => (None, None, None),
+        };
+        sqlx::query(
+            r#"
+            INSERT INTO price_history (product_id, variant_id, change_type, old_price, new_price,
+                                       sale_id, sale_starts_at, sale_ends_at, changed_by)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(change.product_id)
+        .bind(change.variant_id)
+        .bind(change.change_type.as_str())
+        .bind(change.old_price)
+        .bind(change.new_price)
+        .bind(sale_id)
+        .bind(starts_at)
+        .bind(ends_at)
+        .bind(change.changed_by)
+        .execute(conn)
+        .await?;
+        Ok(())
+    }
+
+    /// Catat perubahan harga normal jika nilainya memang berubah.
+    pub async fn record_price(
+        conn: &mut MySqlConnection,
+        product_id: i64,
+        variant_id: Option<i64>,
+        old_price: Option<Money>,
+        new_price: Option<Money>,
+        changed_by: Option<i64>,
+    ) -> Result<(), sqlx::Error> {
+        if old_price == new_price {
+            return Ok(());
+        }
+        Self::record(
+            conn,
+            NewPriceChange {
+                product_id,
+                variant_id,
+                change_type: PriceChangeType::Price,
+                old_price,
+                new_price,
+                sale: None,
+                changed_by,
+            },
+        )
+        .await
+    }
+
+    // --- 2. RIWAYAT SATU PRODUK (TERBARU DULU) ---
+    pub async fn find_by_product(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        params: PaginationParams,
+    ) -> Result<PaginatedResponse<PriceHistoryEntry>, sqlx::Error> {
+        let total: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM price_history WHERE product_id = ?")
+                .bind(product_id)
+                .fetch_one(pool)
+                .await?;
+
+        let entries = sqlx::query_as::<_, PriceHistoryEntry>(
+            r#"
+            SELECT h.id, h.product_id, h.variant_id, v.sku, h.change_type, h.old_price,
+                   h.new_price, h.sale_id, h.sale_starts_at, h.sale_ends_at, h.changed_by,
+                   h.changed_at
+            FROM price_history h
+            LEFT JOIN product_variants v ON h.variant_id = v.id
+            WHERE h.product_id = ?
+            ORDER BY h.id DESC
+            LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(product_id)
+        .bind(params.per_page as i64)
+        .bind(params.offset() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(PaginatedResponse {
+            data: entries,
+            pagination: PaginationMeta::new(params.page, params.per_page, total as u32),
+        })
+    }
+}
@@ -1,23 +1,71 @@
 // src/models/product.rs
 
-use crate::dtos::product::{NewRodProductDto, RodProduct, RodProductDetail};
-use sqlx::{Executor, MySql, MySqlPool, Pool}; 
+use crate::dtos::product::{
+    NewRodProductDto, ProductFilterParams, ProductSearchRow, ProductSort, ProductSpecText,
+    RodProduct, RodProductDetail,
+};
+use crate::dtos::product_transfer::ProductTransferRow;
+use sqlx::{Executor, MySql, MySqlConnection, MySqlPool, Pool, QueryBuilder};
 use crate::dtos::pagination::{PaginatedResponse, PaginationMeta, PaginationParams};
+use crate::utils::search::tokenize;
+use crate::dtos::attribute::AttributeFilter;
+use crate::utils::money::Money;
+use crate::utils::rod_specs::RodSpecs;
+use crate::utils::slug::slugify;
+use sqlx::types::Json;
+use crate::utils::suggest;
+use crate::models::category::CATEGORY_SUBTREE_SQL;
+use crate::models::price_history::PriceHistoryModel;
+use crate::models::product_image::ProductImageModel;
+use crate::models::sale::{ACTIVE_SALE_JOIN, FINAL_PRICE_SQL};
+use crate::models::variant::VariantModel;
+use crate::models::warehouse::WarehouseModel;
 
 impl RodProduct {
     // --- 1. INSERT (CREATE) ---
+    // Harga awal menjadi baris pertama riwayat harga produk.
     pub async fn insert(
         pool: &Pool<MySql>,
         new_product: NewRodProductDto,
+        specs: RodSpecs,
+        changed_by: Option<i64>,
     ) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let id = Self::insert_in(&mut tx, new_product, specs, changed_by).await?;
+        tx.commit().await?;
+
+        suggest::refresh_or_log(pool).await;
+        Ok(id)
+    }
+
+    /// Insert di transaksi pemanggil (tanpa refresh index autocomplete), untuk impor massal.
+    pub async fn insert_in(
+        conn: &mut MySqlConnection,
+        new_product: NewRodProductDto,
+        specs: RodSpecs,
+        changed_by: Option<i64>,
+    ) -> Result<u64, sqlx::Error> {
+        let price = new_product.price;
+        let has_sku = new_product
+            .sku
+            .as_deref()
+            .is_some_and(|sku| !sku.trim().is_empty());
+        let slug = match new_product.slug {
+            Some(slug) => slug,
+            None => Self::unique_slug(&mut *conn, &new_product.name, 0).await?,
+        };
         let result = sqlx::query(
                 r#"
-                INSERT INTO products (name, description, category_id, rod_length, line_weight, cast_weight, 
-                                      action, material, power, reel_size, price, image_url)
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                INSERT INTO products (sku, name, slug, description, category_id, rod_length, line_weight, cast_weight, 
+                                      action, material, power, reel_size, price, image_url,
+                                      rod_length_cm, line_min_lb, line_max_lb, cast_min_g, cast_max_g,
+                                      attributes, meta_title, meta_description)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 "#
             )
+            .bind(new_product.sku)
             .bind(new_product.name)
+            .bind(&slug)
             .bind(new_product.description)
             .bind(new_product.category_id)
             .bind(new_product.rod_length)
@@ -29,28 +77,54 @@ impl RodProduct {
             .bind(new_product.reel_size)
             .bind(new_product.price)
             .bind(new_product.image_url)
-            .execute(pool).await?;
+            .bind(specs.rod_length_cm)
+            .bind(specs.line_min_lb)
+            .bind(specs.line_max_lb)
+            .bind(specs.cast_min_g)
+            .bind(specs.cast_max_g)
+            .bind(new_product.attributes.map(Json))
+            .bind(new_product.meta_title)
+            .bind(new_product.meta_description)
+            .execute(&mut *conn).await?;
 
-        Ok(result.last_insert_id())
+        let id = result.last_insert_id();
+        if !has_sku {
+            // SKU bawaan agar produk tetap bisa diekspor lalu diimpor ulang
+            sqlx::query("UPDATE IGNORE products SET sku = CONCAT('P', id) WHERE id = ?")
+                .bind(id)
+                .execute(&mut *conn)
+                .await?;
+        }
+        Self::claim_slug(conn, &slug).await?;
+        PriceHistoryModel::record_price(conn, id as i64, None, None, Some(price), changed_by)
+            .await?;
+        Ok(id)
     }
 
     // --- 2. FIND ALL DETAILS (READ ALL) ---
     pub async fn find_all_details(
         pool: &Pool<MySql>,
     ) -> Result<Vec<RodProductDetail>, sqlx::Error> {
-        sqlx::query_as::<_, RodProductDetail>(
+        sqlx::query_as::<_, RodProductDetail>(&format!(
                 r#"
                 SELECT 
-                    p.id, p.name, p.description, 
+                    p.id, p.name, p.slug, p.description, 
                     c.name as category_name, 
                     p.rod_length, p.line_weight, p.cast_weight, p.action, p.material, p.power, p.reel_size, 
-                    p.price, p.image_url
+                    p.price, {} AS final_price, sale.ends_at AS sale_ends_at, p.image_url,
+                    p.rod_length_cm, p.line_min_lb, p.line_max_lb, p.cast_min_g, p.cast_max_g,
+                    p.attributes, p.meta_title, p.meta_description, p.rating_average, p.rating_count,
+                    p.stock, p.stock - p.reserved_stock AS available_stock
                 FROM products p
                 JOIN kategori c ON p.category_id = c.id
+                {}
+                WHERE p.archived_at IS NULL
                 ORDER BY p.id DESC
-                "#
-            )
+                "#,
+                FINAL_PRICE_SQL, ACTIVE_SALE_JOIN
+            ))
             .fetch_all(pool).await
+            .map(|products| products.into_iter().map(RodProductDetail::with_spec_display).collect())
     }
 
     // --- 3. FIND DETAIL BY ID (READ DETAIL) ---
@@ -58,55 +132,116 @@ impl RodProduct {
         pool: &Pool<MySql>,
         id: i64,
     ) -> Result<Option<RodProductDetail>, sqlx::Error> {
-        sqlx::query_as::<_, RodProductDetail>(
+        let product = sqlx::query_as::<_, RodProductDetail>(&format!(
                 r#"
                 SELECT
-                    p.id, p.name, p.description, 
+                    p.id, p.name, p.slug, p.description, 
                     c.name as category_name, 
                     p.rod_length, p.line_weight, p.cast_weight, p.action, p.material, p.power, p.reel_size, 
-                    p.price, p.image_url
+                    p.price, {} AS final_price, sale.ends_at AS sale_ends_at, p.image_url,
+                    p.rod_length_cm, p.line_min_lb, p.line_max_lb, p.cast_min_g, p.cast_max_g,
+                    p.attributes, p.meta_title, p.meta_description, p.rating_average, p.rating_count,
+                    p.stock, p.stock - p.reserved_stock AS available_stock
                 FROM products p
                 JOIN kategori c ON p.category_id = c.id
-                WHERE p.id = ?
-                "#
-            )
+                {}
+                WHERE p.id = ? AND p.archived_at IS NULL
+                "#,
+                FINAL_PRICE_SQL, ACTIVE_SALE_JOIN
+            ))
             .bind(id)
-            .fetch_optional(pool).await
+            .fetch_optional(pool).await?;
+
+        let Some(product) = product else {
+            return Ok(None);
+        };
+        let variants = VariantModel::find_by_product(pool, id, true).await?;
+        let mut product = product.with_spec_display().with_variants(variants);
+        product.availability = WarehouseModel::find_availability(pool, id).await?;
+        product.images = ProductImageModel::find_by_product(pool, id).await?;
+        Ok(Some(product))
     }
 
     // 🌟 FUNGSI BARU: FIND BY ID (DIPERLUKAN OLEH MODEL ORDER) 🌟
+    // Produk yang diarsipkan tidak bisa dibeli lagi.
     pub async fn find_by_id(
         executor: impl Executor<'_, Database = MySql>,
         id: i64,
     ) -> Result<RodProduct, sqlx::Error> {
-        sqlx::query_as::<_, RodProduct>(
+        sqlx::query_as::<_, RodProduct>(&format!(
             r#"
-            SELECT id, name, description, category_id, rod_length, line_weight, cast_weight, 
-                   action, material, power, reel_size, price, image_url
-            FROM products 
-            WHERE id = ?
+            SELECT p.id, p.name, p.slug, p.description, p.category_id, p.rod_length, p.line_weight,
+                   p.cast_weight, p.action, p.material, p.power, p.reel_size, p.price,
+                   {} AS final_price, sale.ends_at AS sale_ends_at, p.image_url, p.attributes,
+                   p.rating_average, p.rating_count
+            FROM products p
+            {}
+            WHERE p.id = ? AND p.archived_at IS NULL
             "#,
-        )
+            FINAL_PRICE_SQL, ACTIVE_SALE_JOIN
+        ))
         .bind(id)
         .fetch_one(executor)
         .await
     }
 
     // --- 4. UPDATE ---
+    // Perubahan harga normal dicatat di riwayat harga dalam transaksi yang sama.
     pub async fn update(
         pool: &Pool<MySql>,
         id: i64,
         updated_product: NewRodProductDto,
+        specs: RodSpecs,
+        changed_by: Option<i64>,
     ) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let affected = Self::update_in(&mut tx, id, updated_product, specs, changed_by).await?;
+        tx.commit().await?;
+
+        suggest::refresh_or_log(pool).await;
+        Ok(affected)
+    }
+
+    /// Update di transaksi pemanggil (tanpa refresh index autocomplete), untuk impor massal.
+    pub async fn update_in(
+        conn: &mut MySqlConnection,
+        id: i64,
+        updated_product: NewRodProductDto,
+        specs: RodSpecs,
+        changed_by: Option<i64>,
+    ) -> Result<u64, sqlx::Error> {
+        let current: Option<(Money, String, String)> =
+            sqlx::query_as("SELECT price, name, slug FROM products WHERE id = ? FOR UPDATE")
+                .bind(id)
+                .fetch_optional(&mut *conn)
+                .await?;
+        let Some((old_price, old_name, old_slug)) = current else {
+            return Ok(0);
+        };
+        let new_price = updated_product.price;
+
+        // Slug baru jika diisi, atau dibuat ulang dari nama jika produk diganti nama
+        let slug = match updated_product.slug {
+            Some(slug) => slug,
+            None if updated_product.name != old_name => {
+                Self::unique_slug(&mut *conn, &updated_product.name, id).await?
+            }
+            None => old_slug.clone(),
+        };
+
         let result = sqlx::query(
                 r#"
                 UPDATE products SET 
-                    name = ?, description = ?, category_id = ?, rod_length = ?, line_weight = ?, cast_weight = ?, 
-                    action = ?, material = ?, power = ?, reel_size = ?, price = ?, image_url = ?
+                    sku = COALESCE(?, sku), name = ?, slug = ?, description = ?, category_id = ?, rod_length = ?, line_weight = ?, cast_weight = ?, 
+                    action = ?, material = ?, power = ?, reel_size = ?, price = ?, image_url = ?,
+                    rod_length_cm = ?, line_min_lb = ?, line_max_lb = ?, cast_min_g = ?, cast_max_g = ?,
+                    attributes = ?, meta_title = ?, meta_description = ?
                 WHERE id = ?
                 "#
             )
+            .bind(updated_product.sku)
             .bind(updated_product.name)
+            .bind(&slug)
             .bind(updated_product.description)
             .bind(updated_product.category_id)
             .bind(updated_product.rod_length)
@@ -118,53 +253,555 @@ impl RodProduct {
             .bind(updated_product.reel_size)
             .bind(updated_product.price)
             .bind(updated_product.image_url)
+            .bind(specs.rod_length_cm)
+            .bind(specs.line_min_lb)
+            .bind(specs.line_max_lb)
+            .bind(specs.cast_min_g)
+            .bind(specs.cast_max_g)
+            .bind(updated_product.attributes.map(Json))
+            .bind(updated_product.meta_title)
+            .bind(updated_product.meta_description)
+            .bind(id)
+            .execute(&mut *conn).await?;
+
+        if slug != old_slug {
+            sqlx::query(
+                r#"
+                INSERT INTO product_slug_redirects (slug, product_id) VALUES (?, ?)
+                ON DUPLICATE KEY UPDATE product_id = VALUES(product_id), created_at = NOW()
+                "#
+            )
+            .bind(&old_slug)
             .bind(id)
-            .execute(pool).await?;
+            .execute(&mut *conn)
+            .await?;
+            Self::claim_slug(conn, &slug).await?;
+        }
 
+        PriceHistoryModel::record_price(conn, id, None, Some(old_price), Some(new_price), changed_by)
+            .await?;
         Ok(result.rows_affected())
     }
 
     // --- 5. DELETE ---
+    // Diarsipkan (soft delete) agar riwayat order yang merujuk produk ini tetap utuh.
     pub async fn delete(pool: &Pool<MySql>, id: i64) -> Result<u64, sqlx::Error> {
-        let result = sqlx::query("DELETE FROM products WHERE id = ?")
+        let result =
+            sqlx::query("UPDATE products SET archived_at = NOW() WHERE id = ? AND archived_at IS NULL")
+                .bind(id)
+                .execute(pool)
+                .await?;
+        suggest::refresh_or_log(pool).await;
+        Ok(result.rows_affected())
+    }
+
+    // --- 5b. RESTORE ---
+    // `None` jika produk tidak ada di arsip, `Some(false)` jika kategorinya masih diarsipkan.
+    pub async fn restore(pool: &Pool<MySql>, id: i64) -> Result<Option<bool>, sqlx::Error> {
+        let category_active: Option<bool> = sqlx::query_scalar(
+            r#"
+            SELECT c.archived_at IS NULL
+            FROM products p
+            JOIN kategori c ON p.category_id = c.id
+            WHERE p.id = ? AND p.archived_at IS NOT NULL
+            "#
+        )
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+        match category_active {
+            Some(true) => {}
+            other => return Ok(other),
+        }
+
+        sqlx::query("UPDATE products SET archived_at = NULL WHERE id = ?")
             .bind(id)
             .execute(pool)
             .await?;
-        Ok(result.rows_affected())
+        suggest::refresh_or_log(pool).await;
+        Ok(Some(true))
     }
 
-    // --- 6. GET ALL PAGINATED ---
+    // --- 6. GET ALL PAGINATED (DENGAN FILTER & SORT) ---
     pub async fn get_all_paginated(
         pool: &MySqlPool,
         params: PaginationParams,
+        filter: &ProductFilterParams,
+        attribute_filters: &[AttributeFilter],
+        archived: bool,
     ) -> Result<PaginatedResponse<RodProduct>, sqlx::Error> {
         let offset = params.offset();
         let limit = params.per_page;
+        let sort = filter.sort.unwrap_or_default();
 
-        // Get total count
-        let total: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM products")
-            .fetch_one(pool)
-            .await?;
+        // Get total count (filter yang sama dengan query data)
+        let mut count_query = QueryBuilder::<MySql>::new("SELECT COUNT(*) FROM products p");
+        count_query.push(ACTIVE_SALE_JOIN);
+        push_product_filters(&mut count_query, filter, attribute_filters, archived);
+        let total: i64 = count_query.build_query_scalar().fetch_one(pool).await?;
 
         // Get paginated products
-        let products = sqlx::query_as::<_, RodProduct>(
+        let mut query = QueryBuilder::<MySql>::new(format!(
             r#"
-            SELECT id, name, description, category_id, rod_length, line_weight, cast_weight, 
-                   action, material, power, reel_size, price, image_url
-            FROM products
-            ORDER BY id DESC
+            SELECT p.id, p.name, p.slug, p.description, p.category_id, p.rod_length, p.line_weight, p.cast_weight,
+                   p.action, p.material, p.power, p.reel_size, p.price,
+                   {} AS final_price, sale.ends_at AS sale_ends_at, p.image_url, p.attributes,
+                   p.rating_average, p.rating_count
+            FROM products p
+            "#,
+            FINAL_PRICE_SQL
+        ));
+        query.push(ACTIVE_SALE_JOIN);
+        if matches!(sort, ProductSort::Popularity) {
+            query.push(
+                r#"
+                LEFT JOIN (
+                    SELECT oi.product_id, SUM(oi.quantity) AS sold
+                    FROM order_items oi
+                    JOIN orders o ON o.id = oi.order_id
+                    WHERE o.status = 'PAID'
+                    GROUP BY oi.product_id
+                ) s ON s.product_id = p.id
+                "#
+            );
+        }
+        push_product_filters(&mut query, filter, attribute_filters, archived);
+
+        // Kolom ORDER BY hanya dari whitelist enum, bukan dari input mentah
+        query.push(match sort {
+            ProductSort::Newest => " ORDER BY p.id DESC",
+            ProductSort::PriceAsc => " ORDER BY final_price ASC, p.id DESC",
+            ProductSort::PriceDesc => " ORDER BY final_price DESC, p.id DESC",
+            ProductSort::Name => " ORDER BY p.name ASC, p.id ASC",
+            ProductSort::Popularity => " ORDER BY COALESCE(s.sold, 0) DESC, p.id DESC",
+            ProductSort::Rating => {
+                " ORDER BY p.rating_average IS NULL, p.rating_average DESC, p.rating_count DESC, p.id DESC"
+            }
+        });
+        query.push(" LIMIT ").push_bind(limit as i64);
+        query.push(" OFFSET ").push_bind(offset as i64);
+
+        let products = query
+            .build_query_as::<RodProduct>()
+            .fetch_all(pool)
+            .await?;
+
+        Ok(PaginatedResponse {
+            data: products,
+            pagination: PaginationMeta::new(params.page, params.per_page, total as u32),
+        })
+    }
+
+    // --- 7. FULL-TEXT SEARCH ---
+    // Skor = kecocokan di nama (bobot 2) + semua kolom teks/spesifikasi + nama kategori.
+    pub async fn search_fulltext(
+        pool: &MySqlPool,
+        q: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<(Vec<ProductSearchRow>, i64), sqlx::Error> {
+        let total: i64 = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM products p JOIN kategori c ON p.category_id = c.id WHERE {}",
+            FULLTEXT_WHERE_SQL
+        ))
+        .bind(q)
+        .bind(q)
+        .fetch_one(pool)
+        .await?;
+
+        let rows = sqlx::query_as::<_, ProductSearchRow>(&format!(
+            r#"
+            SELECT p.id, p.name, p.slug, p.description, c.name AS category_name, p.price,
+                   {} AS final_price, p.image_url,
+                   (2 * MATCH(p.name) AGAINST (? IN NATURAL LANGUAGE MODE)
+                    + MATCH(p.name, p.description, p.action, p.material, p.power, p.rod_length)
+                          AGAINST (? IN NATURAL LANGUAGE MODE)
+                    + MATCH(c.name) AGAINST (? IN NATURAL LANGUAGE MODE)) AS score
+            FROM products p
+            JOIN kategori c ON p.category_id = c.id
+            {}
+            WHERE {}
+            ORDER BY score DESC, p.id DESC
             LIMIT ? OFFSET ?
-            "#
-        )
+            "#,
+            FINAL_PRICE_SQL, ACTIVE_SALE_JOIN, FULLTEXT_WHERE_SQL
+        ))
+        .bind(q)
+        .bind(q)
+        .bind(q)
+        .bind(q)
+        .bind(q)
         .bind(limit as i64)
         .bind(offset as i64)
         .fetch_all(pool)
         .await?;
 
-        Ok(PaginatedResponse {
-            data: products,
-            pagination: PaginationMeta::new(params.page, params.per_page, total.0 as u32),
-        })
+        Ok((rows, total))
+    }
+
+    // --- 8. FALLBACK SEARCH (LIKE) ---
+    // Untuk kata yang lebih pendek dari ft_min_token_size atau kena stopword FULLTEXT.
+    // Setiap kata harus muncul di nama, deskripsi, atau kategori.
+    pub async fn search_like(
+        pool: &MySqlPool,
+        terms: &[String],
+        limit: u32,
+        offset: u32,
+    ) -> Result<(Vec<ProductSearchRow>, i64), sqlx::Error> {
+        let patterns: Vec<String> = terms
+            .iter()
+            .map(|t| format!("%{}%", escape_like(t)))
+            .collect();
+
+        let push_where = |query: &mut QueryBuilder<'_, MySql>| {
+            query.push(" WHERE p.archived_at IS NULL");
+            for pattern in &patterns {
+                query.push(" AND (p.name LIKE ").push_bind(pattern.clone());
+                query.push(" OR p.description LIKE ").push_bind(pattern.clone());
+                query.push(" OR c.name LIKE ").push_bind(pattern.clone());
+                query.push(")");
+            }
+        };
+
+        let mut count_query = QueryBuilder::<MySql>::new(
+            "SELECT COUNT(*) FROM products p JOIN kategori c ON p.category_id = c.id",
+        );
+        push_where(&mut count_query);
+        let total: i64 = count_query.build_query_scalar().fetch_one(pool).await?;
+
+        let mut query = QueryBuilder::<MySql>::new(format!(
+            "SELECT p.id, p.name, p.slug, p.description, c.name AS category_name, p.price, \
+             {} AS final_price, p.image_url, CAST((0",
+            FINAL_PRICE_SQL
+        ));
+        for pattern in &patterns {
+            query.push(" + 2 * (p.name LIKE ").push_bind(pattern.clone());
+            query.push(") + (p.description LIKE ").push_bind(pattern.clone());
+            query.push(")");
+        }
+        query.push(") AS DOUBLE) AS score FROM products p JOIN kategori c ON p.category_id = c.id");
+        query.push(ACTIVE_SALE_JOIN);
+        push_where(&mut query);
+        query.push(" ORDER BY score DESC, p.id DESC LIMIT ").push_bind(limit as i64);
+        query.push(" OFFSET ").push_bind(offset as i64);
+
+        let rows = query
+            .build_query_as::<ProductSearchRow>()
+            .fetch_all(pool)
+            .await?;
+
+        Ok((rows, total))
+    }
+
+    // --- 9. KOSAKATA KATALOG (UNTUK KOREKSI TYPO) ---
+    pub async fn search_vocabulary(pool: &MySqlPool) -> Result<Vec<String>, sqlx::Error> {
+        let texts: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT CONCAT_WS(' ', p.name, c.name, p.action, p.material, p.power)
+            FROM products p
+            JOIN kategori c ON p.category_id = c.id
+            WHERE p.archived_at IS NULL
+            "#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut vocabulary: Vec<String> = texts
+            .iter()
+            .flat_map(|text| tokenize(text))
+            .filter(|word| word.chars().count() >= 3)
+            .collect();
+        vocabulary.sort();
+        vocabulary.dedup();
+        Ok(vocabulary)
+    }
+
+    // --- 10. BACKFILL SPESIFIKASI NUMERIK ---
+    // Mengisi kolom numerik produk lama dari teksnya. Teks yang tidak bisa di-parse
+    // dilewati (dan di-log) supaya admin bisa memperbaikinya lewat endpoint update.
+    pub async fn backfill_specs(pool: &MySqlPool) -> Result<u64, sqlx::Error> {
+        let products = sqlx::query_as::<_, ProductSpecText>(
+            r#"
+            SELECT id, rod_length, line_weight, cast_weight
+            FROM products
+            WHERE (rod_length IS NOT NULL AND rod_length_cm IS NULL)
+               OR (line_weight IS NOT NULL AND line_min_lb IS NULL)
+               OR (cast_weight IS NOT NULL AND cast_min_g IS NULL)
+            "#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut updated = 0;
+        for product in products {
+            let (specs, errors) = RodSpecs::parse_lenient(
+                product.rod_length.as_deref(),
+                product.line_weight.as_deref(),
+                product.cast_weight.as_deref(),
+            );
+            for error in errors {
+                eprintln!("Produk {}: {}", product.id, error);
+            }
+            if specs == RodSpecs::default() {
+                continue;
+            }
+
+            updated += sqlx::query(
+                r#"
+                UPDATE products SET
+                    rod_length_cm = COALESCE(rod_length_cm, ?),
+                    line_min_lb = COALESCE(line_min_lb, ?), line_max_lb = COALESCE(line_max_lb, ?),
+                    cast_min_g = COALESCE(cast_min_g, ?), cast_max_g = COALESCE(cast_max_g, ?)
+                WHERE id = ?
+                "#
+            )
+            .bind(specs.rod_length_cm)
+            .bind(specs.line_min_lb)
+            .bind(specs.line_max_lb)
+            .bind(specs.cast_min_g)
+            .bind(specs.cast_max_g)
+            .bind(product.id)
+            .execute(pool)
+            .await?
+            .rows_affected();
+        }
+
+        Ok(updated)
+    }
+
+    // --- 11. EKSPOR KATALOG ---
+    // Produk tanpa SKU sudah diberi SKU bawaan `P<id>` (migrasi dan saat dibuat), jadi hasil
+    // ekspor bisa langsung diimpor ulang.
+    pub async fn find_for_export(
+        pool: &Pool<MySql>,
+    ) -> Result<Vec<ProductTransferRow>, sqlx::Error> {
+        sqlx::query_as::<_, ProductTransferRow>(
+            r#"
+            SELECT COALESCE(sku, '') AS sku, name, description, category_id, rod_length,
+                   line_weight, cast_weight, action, material, power, reel_size, price,
+                   image_url, attributes, slug, meta_title, meta_description
+            FROM products
+            WHERE archived_at IS NULL
+            ORDER BY id ASC
+            "#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    // --- 12. CARI PRODUK BERDASARKAN SKU (UNTUK UPSERT IMPOR) ---
+    // Mengembalikan id dan apakah produknya diarsipkan.
+    pub async fn find_by_sku(
+        pool: &Pool<MySql>,
+        sku: &str,
+    ) -> Result<Option<(i64, bool)>, sqlx::Error> {
+        sqlx::query_as("SELECT id, archived_at IS NOT NULL FROM products WHERE sku = ?")
+            .bind(sku)
+            .fetch_optional(pool)
+            .await
+    }
+
+    // --- 13. TERAPKAN IMPOR ---
+    // Semua baris disimpan dalam satu transaksi: impor berhasil seluruhnya atau tidak sama
+    // sekali. `None` = produk baru. Mengembalikan id produk per baris.
+    pub async fn import(
+        pool: &Pool<MySql>,
+        rows: Vec<(Option<i64>, NewRodProductDto, RodSpecs)>,
+        changed_by: Option<i64>,
+    ) -> Result<Vec<i64>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let mut ids = Vec::with_capacity(rows.len());
+        for (existing_id, product, specs) in rows {
+            let id = match existing_id {
+                Some(id) => {
+                    Self::update_in(&mut tx, id, product, specs, changed_by).await?;
+                    id
+                }
+                None => Self::insert_in(&mut tx, product, specs, changed_by).await? as i64,
+            };
+            ids.push(id);
+        }
+        tx.commit().await?;
+
+        suggest::refresh_or_log(pool).await;
+        Ok(ids)
+    }
+
+    // --- 14. CARI PRODUK BERDASARKAN SLUG ---
+    // Slug yang berlaku dicek dulu, lalu riwayat slug lama. Mengembalikan id produk aktif dan
+    // slug yang berlaku; jika berbeda dengan `slug`, klien perlu diarahkan (redirect).
+    pub async fn find_by_slug(
+        pool: &Pool<MySql>,
+        slug: &str,
+    ) -> Result<Option<(i64, String)>, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+            SELECT id, slug FROM products WHERE slug = ? AND archived_at IS NULL
+            UNION ALL
+            SELECT p.id, p.slug
+            FROM product_slug_redirects r
+            JOIN products p ON p.id = r.product_id
+            WHERE r.slug = ? AND p.archived_at IS NULL
+            LIMIT 1
+            "#
+        )
+        .bind(slug)
+        .bind(slug)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Id produk (termasuk yang diarsipkan) yang sedang memakai slug ini.
+    pub async fn find_id_by_slug(
+        pool: &Pool<MySql>,
+        slug: &str,
+    ) -> Result<Option<i64>, sqlx::Error> {
+        sqlx::query_scalar("SELECT id FROM products WHERE slug = ?")
+            .bind(slug)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Slug dari nama yang belum dipakai produk lain, termasuk sebagai slug lama
+    /// (akhiran `-2`, `-3`, ...). `id` = produk yang sedang diubah (0 untuk produk baru).
+    async fn unique_slug(
+        conn: &mut MySqlConnection,
+        name: &str,
+        id: i64,
+    ) -> Result<String, sqlx::Error> {
+        let base = match slugify(name) {
+            slug if slug.is_empty() => "produk".to_string(),
+            slug => slug,
+        };
+        let mut candidate = base.clone();
+        let mut suffix = 2;
+        loop {
+            let taken: bool = sqlx::query_scalar(
+                r#"
+                SELECT EXISTS(SELECT 1 FROM products WHERE slug = ? AND id <> ?)
+                    OR EXISTS(SELECT 1 FROM product_slug_redirects WHERE slug = ? AND product_id <> ?)
+                "#
+            )
+            .bind(&candidate)
+            .bind(id)
+            .bind(&candidate)
+            .bind(id)
+            .fetch_one(&mut *conn)
+            .await?;
+            if !taken {
+                return Ok(candidate);
+            }
+            candidate = format!("{}-{}", base, suffix);
+            suffix += 1;
+        }
+    }
+
+    /// Slug yang sekarang dipakai produk ini tidak lagi menjadi redirect produk mana pun.
+    async fn claim_slug(conn: &mut MySqlConnection, slug: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM product_slug_redirects WHERE slug = ?")
+            .bind(slug)
+            .execute(&mut *conn)
+            .await?;
+        Ok(())
+    }
+}
+
+const FULLTEXT_WHERE_SQL: &str = "p.archived_at IS NULL AND \
+    (MATCH(p.name, p.description, p.action, p.material, p.power, p.rod_length) \
+    AGAINST (? IN NATURAL LANGUAGE MODE) OR MATCH(c.name) AGAINST (? IN NATURAL LANGUAGE MODE))";
+
+const CM_PER_FOOT: f64 = 30.48;
+
+/// Escape karakter wildcard LIKE agar input user dicocokkan apa adanya.
+pub(crate) fn escape_like(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Klausa WHERE untuk listing produk; semua nilai dari user lewat bind parameter.
+fn push_product_filters(
+    query: &mut QueryBuilder<'_, MySql>,
+    filter: &ProductFilterParams,
+    attribute_filters: &[AttributeFilter],
+    archived: bool,
+) {
+    // Listing katalog hanya produk aktif; listing arsip (admin) sebaliknya
+    query.push(if archived {
+        " WHERE p.archived_at IS NOT NULL"
+    } else {
+        " WHERE p.archived_at IS NULL"
+    });
+
+    if let Some(q) = filter.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+        let pattern = format!("%{}%", escape_like(q));
+        query.push(" AND (p.name LIKE ").push_bind(pattern.clone());
+        query.push(" OR p.description LIKE ").push_bind(pattern);
+        query.push(")");
+    }
+    // Kategori mencakup seluruh subkategorinya
+    if let Some(category_id) = filter.category_id {
+        let (head, tail) = CATEGORY_SUBTREE_SQL.split_once('?').unwrap_or_default();
+        query
+            .push(" AND p.category_id IN (")
+            .push(head)
+            .push_bind(category_id)
+            .push(tail)
+            .push(")");
+    }
+    // Rentang harga memakai harga efektif (setelah sale); query harus menyertakan ACTIVE_SALE_JOIN
+    if let Some(min_price) = filter.min_price {
+        query.push(format!(" AND {} >= ", FINAL_PRICE_SQL)).push_bind(min_price);
+    }
+    if let Some(max_price) = filter.max_price {
+        query.push(format!(" AND {} <= ", FINAL_PRICE_SQL)).push_bind(max_price);
+    }
+
+    for (column, value) in [
+        ("p.action", &filter.action),
+        ("p.power", &filter.power),
+        ("p.material", &filter.material),
+    ] {
+        if let Some(value) = value.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+            query.push(format!(" AND {} = ", column)).push_bind(value.to_string());
+        }
+    }
+
+    // Parameter dalam feet, kolom tersimpan dalam cm
+    if let Some(min_length) = filter.min_length {
+        query.push(" AND p.rod_length_cm >= ").push_bind(min_length * CM_PER_FOOT);
+    }
+    if let Some(max_length) = filter.max_length {
+        query.push(" AND p.rod_length_cm <= ").push_bind(max_length * CM_PER_FOOT);
+    }
+
+    // Atribut JSON; kode atribut sudah divalidasi sehingga aman dipakai sebagai path JSON
+    for attribute_filter in attribute_filters {
+        match attribute_filter {
+            AttributeFilter::Equals(code, value) => {
+                query
+                    .push(" AND LOWER(JSON_UNQUOTE(JSON_EXTRACT(p.attributes, ")
+                    .push_bind(format!("$.{}", code))
+                    .push("))) = LOWER(")
+                    .push_bind(value.clone())
+                    .push(")");
+            }
+            AttributeFilter::Min(code, min) => {
+                query
+                    .push(" AND CAST(JSON_EXTRACT(p.attributes, ")
+                    .push_bind(format!("$.{}", code))
+                    .push(") AS DOUBLE) >= ")
+                    .push_bind(*min);
+            }
+            AttributeFilter::Max(code, max) => {
+                query
+                    .push(" AND CAST(JSON_EXTRACT(p.attributes, ")
+                    .push_bind(format!("$.{}", code))
+                    .push(") AS DOUBLE) <= ")
+                    .push_bind(*max);
+            }
+        }
     }
 }
 
@@ -0,0 +1,161 @@
+// src/models/product_image.rs
+
+use crate::dtos::product_image::{NewProductImage, ProductImage, StoredImageKeys};
+use sqlx::{Executor, MySql, MySqlConnection, Pool};
+
+pub struct ProductImageModel;
+
+const IMAGE_COLUMNS: &str =
+    "id, product_id, sort_order, original_url, medium_url, thumbnail_url, width, height";
+
+impl ProductImageModel {
+    pub async fn product_exists(pool: &Pool<MySql>, product_id: i64) -> Result<bool, sqlx::Error> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM products WHERE id = ?")
+            .bind(product_id)
+            .fetch_one(pool)
+            .await?;
+        Ok(count > 0)
+    }
+
+    // --- 1. CREATE (ditambahkan di urutan terakhir) ---
+    pub async fn insert(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        image: NewProductImage,
+    ) -> Result<i64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        // Kunci baris produk agar dua unggahan bersamaan tidak mendapat sort_order yang sama
+        sqlx::query("SELECT id FROM products WHERE id = ? FOR UPDATE")
+            .bind(product_id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO product_images (product_id, sort_order, original_url, medium_url,
+                                        thumbnail_url, original_key, medium_key, thumbnail_key,
+                                        width, height)
+            SELECT ?, COALESCE(MAX(sort_order) + 1, 0), ?, ?, ?, ?, ?, ?, ?, ?
+            FROM product_images WHERE product_id = ?
+            "#,
+        )
+        .bind(product_id)
+        .bind(image.original_url)
+        .bind(image.medium_url)
+        .bind(image.thumbnail_url)
+        .bind(image.keys.original_key)
+        .bind(image.keys.medium_key)
+        .bind(image.keys.thumbnail_key)
+        .bind(image.width)
+        .bind(image.height)
+        .bind(product_id)
+        .execute(&mut *tx)
+        .await?;
+
+        Self::sync_primary(&mut tx, product_id).await?;
+        tx.commit().await?;
+        Ok(result.last_insert_id() as i64)
+    }
+
+    // --- 2. READ ---
+    pub async fn find_by_product(
+        executor: impl Executor<'_, Database = MySql>,
+        product_id: i64,
+    ) -> Result<Vec<ProductImage>, sqlx::Error> {
+        sqlx::query_as::<_, ProductImage>(&format!(
+            "SELECT {} FROM product_images WHERE product_id = ? ORDER BY sort_order ASC, id ASC",
+            IMAGE_COLUMNS
+        ))
+        .bind(product_id)
+        .fetch_all(executor)
+        .await
+    }
+
+    // --- 3. URUTKAN ULANG ---
+    // `image_ids` harus berisi tepat semua gambar produk; `false` jika tidak cocok.
+    pub async fn reorder(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        image_ids: &[i64],
+    ) -> Result<bool, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let mut existing: Vec<i64> =
+            sqlx::query_scalar("SELECT id FROM product_images WHERE product_id = ? FOR UPDATE")
+                .bind(product_id)
+                .fetch_all(&mut *tx)
+                .await?;
+
+        let mut requested = image_ids.to_vec();
+        existing.sort_unstable();
+        requested.sort_unstable();
+        if existing != requested {
+            return Ok(false);
+        }
+
+        for (position, id) in image_ids.iter().enumerate() {
+            sqlx::query("UPDATE product_images SET sort_order = ? WHERE id = ?")
+                .bind(position as i32)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        Self::sync_primary(&mut tx, product_id).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    // --- 4. DELETE ---
+    // Mengembalikan key storage supaya file-nya bisa ikut dihapus.
+    pub async fn delete(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        id: i64,
+    ) -> Result<Option<StoredImageKeys>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let keys = sqlx::query_as::<_, StoredImageKeys>(
+            r#"
+            SELECT original_key, medium_key, thumbnail_key
+            FROM product_images
+            WHERE id = ? AND product_id = ?
+            FOR UPDATE
+            "#,
+        )
+        .bind(id)
+        .bind(product_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if keys.is_none() {
+            return Ok(None);
+        }
+
+        sqlx::query("DELETE FROM product_images WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        Self::sync_primary(&mut tx, product_id).await?;
+        tx.commit().await?;
+        Ok(keys)
+    }
+
+    // `products.image_url` mengikuti gambar pertama (ukuran medium) untuk listing produk.
+    async fn sync_primary(conn: &mut MySqlConnection, product_id: i64) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE products SET image_url = (
+                SELECT medium_url FROM product_images
+                WHERE product_id = ?
+                ORDER BY sort_order ASC, id ASC
+                LIMIT 1
+            )
+            WHERE id = ?
+            "#,
+        )
+        .bind(product_id)
+        .bind(product_id)
+        .execute(conn)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,360 @@
+// src/models/purchase_order.rs
+
+use crate::dtos::inventory::{MovementType, StockError};
+use crate::dtos::pagination::{PaginatedResponse, PaginationMeta, PaginationParams};
+use crate::dtos::purchase_order::{
+    NewPurchaseOrderDto, PurchaseOrder, PurchaseOrderDetail, PurchaseOrderItem, PurchaseOrderStatus,
+};
+use crate::models::inventory::{InventoryModel, NewMovement};
+use crate::models::supplier::SupplierModel;
+use crate::models::variant::VariantModel;
+use crate::models::warehouse::WarehouseModel;
+use sqlx::{FromRow, MySql, MySqlConnection, Pool};
+
+pub struct PurchaseOrderModel;
+
+const PURCHASE_ORDER_SELECT: &str = r#"
+    SELECT po.id, po.supplier_id, s.name AS supplier_name, po.warehouse_id,
+           w.code AS warehouse_code, po.status, po.note,
+           COALESCE((SELECT SUM(poi.quantity * poi.unit_cost) FROM purchase_order_items poi
+                     WHERE poi.purchase_order_id = po.id), 0) AS total_cost,
+           po.created_by, po.created_at, po.ordered_at, po.received_at
+    FROM purchase_orders po
+    JOIN suppliers s ON s.id = po.supplier_id
+    JOIN warehouses w ON w.id = po.warehouse_id
+"#;
+
+/// Item PO yang belum diterima penuh, dikunci saat penerimaan barang.
+#[derive(Debug, FromRow)]
+struct PendingReceipt {
+    id: i64,
+    product_id: i64,
+    variant_id: Option<i64>,
+    remaining: i32,
+}
+
+impl PurchaseOrderModel {
+    // --- 1. CREATE (DRAFT) ---
+    pub async fn create(
+        pool: &Pool<MySql>,
+        order: NewPurchaseOrderDto,
+        created_by: Option<i64>,
+    ) -> Result<i64, StockError> {
+        let mut tx = pool.begin().await?;
+
+        match SupplierModel::find_by_id(&mut *tx, order.supplier_id).await? {
+            Some(supplier) if supplier.is_active => {}
+            Some(_) => {
+                return Err(StockError::Invalid(
+                    "Supplier sudah tidak aktif".to_string(),
+                ));
+            }
+            None => {
+                return Err(StockError::NotFound("Supplier tidak ditemukan".to_string()));
+            }
+        }
+        if WarehouseModel::find_by_id(&mut *tx, order.warehouse_id)
+            .await?
+            .is_none()
+        {
+            return Err(StockError::NotFound(format!(
+                "Gudang {} tidak ditemukan",
+                order.warehouse_id
+            )));
+        }
+        for item in &order.items {
+            Self::check_item(&mut tx, item.product_id, item.variant_id).await?;
+        }
+
+        let result = sqlx::query(
+            "INSERT INTO purchase_orders (supplier_id, warehouse_id, note, created_by) VALUES (?, ?, ?, ?)",
+        )
+        .bind(order.supplier_id)
+        .bind(order.warehouse_id)
+        .bind(order.note.as_deref().map(str::trim))
+        .bind(created_by)
+        .execute(&mut *tx)
+        .await?;
+        let id = result.last_insert_id() as i64;
+
+        for item in &order.items {
+            sqlx::query(
+                r#"
+                INSERT INTO purchase_order_items
+                    (purchase_order_id, product_id, variant_id, quantity, unit_cost)
+                VALUES (?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(id)
+            .bind(item.product_id)
+            .bind(item.variant_id)
+            .bind(item.quantity)
+            .bind(item.unit_cost)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(id)
+    }
+
+    // Produk bervarian dipesan per varian, sama seperti penyesuaian stok.
+    async fn check_item(
+        conn: &mut MySqlConnection,
+        product_id: i64,
+        variant_id: Option<i64>,
+    ) -> Result<(), StockError> {
+        let exists = match variant_id {
+            Some(variant_id) => VariantModel::find_for_product(&mut *conn, product_id, variant_id)
+                .await?
+                .is_some(),
+            None => {
+                if VariantModel::has_active_variants(&mut *conn, product_id).await? {
+                    return Err(StockError::Invalid(format!(
+                        "Produk {} memiliki varian, sertakan variant_id",
+                        product_id
+                    )));
+                }
+                let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM products WHERE id = ?")
+                    .bind(product_id)
+                    .fetch_one(&mut *conn)
+                    .await?;
+                count > 0
+            }
+        };
+        if !exists {
+            return Err(StockError::NotFound(format!(
+                "Produk {} atau variannya tidak ditemukan",
+                product_id
+            )));
+        }
+        Ok(())
+    }
+
+    // --- 2. READ ---
+    pub async fn find_paginated(
+        pool: &Pool<MySql>,
+        status: Option<PurchaseOrderStatus>,
+        params: PaginationParams,
+    ) -> Result<PaginatedResponse<PurchaseOrder>, sqlx::Error> {
+        let status = status.map(PurchaseOrderStatus::as_str);
+        let total: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM purchase_orders WHERE (? IS NULL OR status = ?)",
+        )
+        .bind(status)
+        .bind(status)
+        .fetch_one(pool)
+        .await?;
+
+        let orders = sqlx::query_as::<_, PurchaseOrder>(&format!(
+            "{} WHERE (? IS NULL OR po.status = ?) ORDER BY po.id DESC LIMIT ? OFFSET ?",
+            PURCHASE_ORDER_SELECT
+        ))
+        .bind(status)
+        .bind(status)
+        .bind(params.per_page as i64)
+        .bind(params.offset() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(PaginatedResponse {
+            data: orders,
+            pagination: PaginationMeta::new(params.page, params.per_page, total as u32),
+        })
+    }
+
+    pub async fn find_detail(
+        pool: &Pool<MySql>,
+        id: i64,
+    ) -> Result<Option<PurchaseOrderDetail>, sqlx::Error> {
+        let Some(order) = sqlx::query_as::<_, PurchaseOrder>(&format!(
+            "{} WHERE po.id = ?",
+            PURCHASE_ORDER_SELECT
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let items = sqlx::query_as::<_, PurchaseOrderItem>(
+            r#"
+            SELECT poi.id, poi.product_id, p.name AS product_name, poi.variant_id, v.sku,
+                   poi.quantity, poi.unit_cost, poi.received_quantity
+            FROM purchase_order_items poi
+            LEFT JOIN products p ON p.id = poi.product_id
+            LEFT JOIN product_variants v ON v.id = poi.variant_id
+            WHERE poi.purchase_order_id = ?
+            ORDER BY poi.id ASC
+            "#,
+        )
+        .bind(id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(Some(PurchaseOrderDetail { order, items }))
+    }
+
+    // --- 3. UBAH STATUS ---
+    // DRAFT -> ORDERED: jumlahnya mulai dihitung sebagai stok dalam pemesanan.
+    pub async fn mark_ordered(pool: &Pool<MySql>, id: i64) -> Result<(), StockError> {
+        let result = sqlx::query(
+            "UPDATE purchase_orders SET status = 'ORDERED', ordered_at = NOW() \
+             WHERE id = ? AND status = 'DRAFT'",
+        )
+        .bind(id)
+        .execute(pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(Self::transition_error(pool, id, "dipesan").await);
+        }
+        Ok(())
+    }
+
+    // PO yang sudah diterima tidak bisa dibatalkan; koreksi lewat penyesuaian stok.
+    pub async fn cancel(pool: &Pool<MySql>, id: i64) -> Result<(), StockError> {
+        let result = sqlx::query(
+            "UPDATE purchase_orders SET status = 'CANCELLED' \
+             WHERE id = ? AND status IN ('DRAFT', 'ORDERED')",
+        )
+        .bind(id)
+        .execute(pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(Self::transition_error(pool, id, "dibatalkan").await);
+        }
+        Ok(())
+    }
+
+    async fn transition_error(pool: &Pool<MySql>, id: i64, action: &str) -> StockError {
+        let status: Result<Option<String>, sqlx::Error> =
+            sqlx::query_scalar("SELECT status FROM purchase_orders WHERE id = ?")
+                .bind(id)
+                .fetch_optional(pool)
+                .await;
+        match status {
+            Ok(Some(status)) => StockError::Invalid(format!(
+                "Purchase order berstatus {} tidak bisa {}",
+                status, action
+            )),
+            Ok(None) => StockError::NotFound("Purchase order tidak ditemukan".to_string()),
+            Err(e) => StockError::Database(e),
+        }
+    }
+
+    // --- 4. TERIMA BARANG ---
+    // Semua sisa item masuk ke gudang tujuan dalam satu transaksi dan dicatat sebagai
+    // RECEIPT di buku besar stok. Jika ada item yang produk/variannya sudah dihapus, seluruh
+    // penerimaan ditolak dengan menyebut item tersebut (PO bisa dibatalkan lalu dibuat ulang).
+    pub async fn receive(
+        pool: &Pool<MySql>,
+        id: i64,
+        created_by: Option<i64>,
+    ) -> Result<(), StockError> {
+        let mut tx = pool.begin().await?;
+
+        let order: Option<(String, i64)> = sqlx::query_as(
+            "SELECT status, warehouse_id FROM purchase_orders WHERE id = ? FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let warehouse_id = match order {
+            Some((status, warehouse_id)) if status == PurchaseOrderStatus::Ordered.as_str() => {
+                warehouse_id
+            }
+            Some((status, _)) => {
+                return Err(StockError::Invalid(format!(
+                    "Purchase order berstatus {} tidak bisa diterima",
+                    status
+                )));
+            }
+            None => {
+                return Err(StockError::NotFound(
+                    "Purchase order tidak ditemukan".to_string(),
+                ));
+            }
+        };
+
+        // Urutan kunci sama dengan checkout (produk, varian) agar tidak deadlock
+        let items = sqlx::query_as::<_, PendingReceipt>(
+            r#"
+            SELECT id, product_id, variant_id, quantity - received_quantity AS remaining
+            FROM purchase_order_items
+            WHERE purchase_order_id = ? AND received_quantity < quantity
+            ORDER BY product_id ASC, variant_id ASC
+            "#,
+        )
+        .bind(id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let note = format!("Penerimaan PO #{}", id);
+        for item in items {
+            if InventoryModel::lock(&mut tx, item.product_id, item.variant_id)
+                .await?
+                .is_none()
+            {
+                let target = match item.variant_id {
+                    Some(variant_id) => {
+                        format!("varian #{} dari produk #{}", variant_id, item.product_id)
+                    }
+                    None => format!("produk #{}", item.product_id),
+                };
+                return Err(StockError::Invalid(format!(
+                    "Item PO #{} tidak bisa diterima: {} sudah tidak ada",
+                    item.id, target
+                )));
+            }
+            let counts = InventoryModel::lock_warehouse(
+                &mut tx,
+                warehouse_id,
+                item.product_id,
+                item.variant_id,
+            )
+            .await?;
+            InventoryModel::apply(
+                &mut tx,
+                item.product_id,
+                item.variant_id,
+                warehouse_id,
+                item.remaining,
+                0,
+            )
+            .await?;
+            InventoryModel::record(
+                &mut tx,
+                NewMovement {
+                    product_id: item.product_id,
+                    variant_id: item.variant_id,
+                    warehouse_id,
+                    order_id: None,
+                    purchase_order_id: Some(id),
+                    movement_type: MovementType::Receipt,
+                    quantity: item.remaining,
+                    stock_after: counts.stock + item.remaining,
+                    note: Some(&note),
+                    created_by,
+                },
+            )
+            .await?;
+            sqlx::query(
+                "UPDATE purchase_order_items SET received_quantity = quantity WHERE id = ?",
+            )
+            .bind(item.id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query(
+            "UPDATE purchase_orders SET status = 'RECEIVED', received_at = NOW() WHERE id = ?",
+        )
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,315 @@
+// src/models/question.rs
+//
+// Tanya jawab produk. Pertanyaan dari user yang login dan jawaban admin langsung tampil;
+// admin bisa menyembunyikan keduanya (hidden_at). Penanya mendapat notifikasi saat
+// pertanyaannya dijawab.
+
+use crate::dtos::notification::{NewNotification, NotificationType};
+use crate::dtos::pagination::{PaginatedResponse, PaginationMeta, PaginationParams};
+use crate::dtos::question::{ProductAnswer, ProductQuestion, QuestionQueueFilter};
+use crate::models::notification::NotificationModel;
+use serde_json::json;
+use sqlx::{MySql, Pool, QueryBuilder};
+use std::collections::HashMap;
+
+pub struct QuestionModel;
+
+/// Pertanyaan yang dikunci saat dijawab.
+#[derive(sqlx::FromRow)]
+struct QuestionTarget {
+    user_id: i64,
+    product_name: String,
+    product_slug: String,
+}
+
+/// Kondisi "punya jawaban yang tampil".
+const HAS_VISIBLE_ANSWER_SQL: &str = "EXISTS(SELECT 1 FROM product_answers a \
+    WHERE a.question_id = q.id AND a.hidden_at IS NULL)";
+
+impl QuestionModel {
+    // --- 1. THREAD PUBLIK PER PRODUK ---
+    // Pertanyaan dan jawaban yang disembunyikan tidak ikut.
+    pub async fn find_visible(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        params: PaginationParams,
+    ) -> Result<PaginatedResponse<ProductQuestion>, sqlx::Error> {
+        let total: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM product_questions WHERE product_id = ? AND hidden_at IS NULL",
+        )
+        .bind(product_id)
+        .fetch_one(pool)
+        .await?;
+
+        let mut questions = sqlx::query_as::<_, ProductQuestion>(
+            r#"
+            SELECT q.id, q.product_id, u.name AS asker_name, q.question, q.hidden_at, q.created_at
+            FROM product_questions q
+            JOIN users u ON u.id = q.user_id
+            WHERE q.product_id = ? AND q.hidden_at IS NULL
+            ORDER BY q.created_at DESC, q.id DESC
+            LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(product_id)
+        .bind(params.per_page as i64)
+        .bind(params.offset() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Self::attach_answers(pool, &mut questions, false).await?;
+        Ok(PaginatedResponse {
+            data: questions,
+            pagination: PaginationMeta::new(params.page, params.per_page, total as u32),
+        })
+    }
+
+    // --- 2. AJUKAN PERTANYAAN ---
+    pub async fn create(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        user_id: i64,
+        question: &str,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "INSERT INTO product_questions (product_id, user_id, question) VALUES (?, ?, ?)",
+        )
+        .bind(product_id)
+        .bind(user_id)
+        .bind(question.trim())
+        .execute(pool)
+        .await?;
+        Ok(result.last_insert_id())
+    }
+
+    // --- 3. JAWAB ---
+    // Jawaban dan notifikasi untuk penanya disimpan dalam satu transaksi.
+    // `None` jika pertanyaan tidak ada di produk ini.
+    pub async fn answer(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        question_id: i64,
+        answered_by: i64,
+        answer: &str,
+    ) -> Result<Option<u64>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let target = sqlx::query_as::<_, QuestionTarget>(
+            r#"
+            SELECT q.user_id, p.name AS product_name, p.slug AS product_slug
+            FROM product_questions q
+            JOIN products p ON p.id = q.product_id
+            WHERE q.id = ? AND q.product_id = ?
+            FOR UPDATE
+            "#,
+        )
+        .bind(question_id)
+        .bind(product_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(target) = target else {
+            return Ok(None);
+        };
+
+        let result = sqlx::query(
+            "INSERT INTO product_answers (question_id, user_id, answer) VALUES (?, ?, ?)",
+        )
+        .bind(question_id)
+        .bind(answered_by)
+        .bind(answer.trim())
+        .execute(&mut *tx)
+        .await?;
+
+        if target.user_id != answered_by {
+            NotificationModel::create(
+                &mut *tx,
+                NewNotification {
+                    user_id: target.user_id,
+                    kind: NotificationType::QuestionAnswered,
+                    title: "Pertanyaan Anda sudah dijawab".to_string(),
+                    message: format!(
+                        "Pertanyaan Anda tentang {} sudah dijawab.",
+                        target.product_name
+                    ),
+                    data: Some(json!({
+                        "product_id": product_id,
+                        "product_slug": target.product_slug,
+                        "question_id": question_id,
+                    })),
+                },
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(Some(result.last_insert_id()))
+    }
+
+    // --- 4. ANTREAN ADMIN ---
+    // Termasuk pertanyaan dan jawaban yang disembunyikan. Yang belum dijawab: terlama dulu.
+    pub async fn find_for_queue(
+        pool: &Pool<MySql>,
+        filter: QuestionQueueFilter,
+        params: PaginationParams,
+    ) -> Result<PaginatedResponse<ProductQuestion>, sqlx::Error> {
+        let (condition, order) = match filter {
+            QuestionQueueFilter::Unanswered => (
+                format!("q.hidden_at IS NULL AND NOT {}", HAS_VISIBLE_ANSWER_SQL),
+                "ASC",
+            ),
+            QuestionQueueFilter::Answered => (
+                format!("q.hidden_at IS NULL AND {}", HAS_VISIBLE_ANSWER_SQL),
+                "DESC",
+            ),
+            QuestionQueueFilter::Hidden => ("q.hidden_at IS NOT NULL".to_string(), "DESC"),
+            QuestionQueueFilter::All => ("TRUE".to_string(), "DESC"),
+        };
+
+        let total: i64 = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM product_questions q WHERE {}",
+            condition
+        ))
+        .fetch_one(pool)
+        .await?;
+
+        let mut questions = sqlx::query_as::<_, ProductQuestion>(&format!(
+            r#"
+            SELECT q.id, q.product_id, u.name AS asker_name, q.question, q.hidden_at, q.created_at
+            FROM product_questions q
+            JOIN users u ON u.id = q.user_id
+            WHERE {0}
+            ORDER BY q.created_at {1}, q.id {1}
+            LIMIT ? OFFSET ?
+            "#,
+            condition, order
+        ))
+        .bind(params.per_page as i64)
+        .bind(params.offset() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Self::attach_answers(pool, &mut questions, true).await?;
+        Ok(PaginatedResponse {
+            data: questions,
+            pagination: PaginationMeta::new(params.page, params.per_page, total as u32),
+        })
+    }
+
+    // --- 5. MODERASI ---
+    // `false` jika pertanyaan/jawaban tidak ada.
+    pub async fn set_question_hidden(
+        pool: &Pool<MySql>,
+        question_id: i64,
+        hidden: bool,
+        moderated_by: Option<i64>,
+    ) -> Result<bool, sqlx::Error> {
+        Self::set_hidden(pool, "product_questions", question_id, hidden, moderated_by).await
+    }
+
+    pub async fn set_answer_hidden(
+        pool: &Pool<MySql>,
+        answer_id: i64,
+        hidden: bool,
+        moderated_by: Option<i64>,
+    ) -> Result<bool, sqlx::Error> {
+        Self::set_hidden(pool, "product_answers", answer_id, hidden, moderated_by).await
+    }
+
+    // --- 6. PERTANYAAN MILIK USER (EKSPOR DATA PRIBADI) ---
+    // Termasuk pertanyaan yang disembunyikan admin; jawaban hanya yang tampil.
+    pub async fn find_by_user(
+        pool: &Pool<MySql>,
+        user_id: i64,
+    ) -> Result<Vec<ProductQuestion>, sqlx::Error> {
+        let mut questions = sqlx::query_as::<_, ProductQuestion>(
+            r#"
+            SELECT q.id, q.product_id, u.name AS asker_name, q.question, q.hidden_at, q.created_at
+            FROM product_questions q
+            JOIN users u ON u.id = q.user_id
+            WHERE q.user_id = ?
+            ORDER BY q.created_at DESC, q.id DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+
+        Self::attach_answers(pool, &mut questions, false).await?;
+        Ok(questions)
+    }
+
+    /// `table` hanya dari konstanta di atas, bukan dari input.
+    async fn set_hidden(
+        pool: &Pool<MySql>,
+        table: &str,
+        id: i64,
+        hidden: bool,
+        moderated_by: Option<i64>,
+    ) -> Result<bool, sqlx::Error> {
+        let exists: bool = sqlx::query_scalar(&format!(
+            "SELECT EXISTS(SELECT 1 FROM {} WHERE id = ?)",
+            table
+        ))
+        .bind(id)
+        .fetch_one(pool)
+        .await?;
+        if !exists {
+            return Ok(false);
+        }
+
+        sqlx::query(&format!(
+            "UPDATE {} SET hidden_at = IF(?, COALESCE(hidden_at, NOW()), NULL), hidden_by = ? \
+             WHERE id = ?",
+            table
+        ))
+        .bind(hidden)
+        .bind(if hidden { moderated_by } else { None })
+        .bind(id)
+        .execute(pool)
+        .await?;
+        Ok(true)
+    }
+
+    async fn attach_answers(
+        pool: &Pool<MySql>,
+        questions: &mut [ProductQuestion],
+        include_hidden: bool,
+    ) -> Result<(), sqlx::Error> {
+        if questions.is_empty() {
+            return Ok(());
+        }
+
+        let mut query = QueryBuilder::<MySql>::new(
+            r#"
+            SELECT a.id, a.question_id, u.name AS answerer_name, a.answer, a.hidden_at, a.created_at
+            FROM product_answers a
+            JOIN users u ON u.id = a.user_id
+            WHERE a.question_id IN (
+            "#,
+        );
+        let mut ids = query.separated(", ");
+        for question in questions.iter() {
+            ids.push_bind(question.id);
+        }
+        query.push(")");
+        if !include_hidden {
+            query.push(" AND a.hidden_at IS NULL");
+        }
+        query.push(" ORDER BY a.created_at, a.id");
+
+        let answers = query
+            .build_query_as::<ProductAnswer>()
+            .fetch_all(pool)
+            .await?;
+        let mut by_question: HashMap<i64, Vec<ProductAnswer>> = HashMap::new();
+        for answer in answers {
+            by_question
+                .entry(answer.question_id)
+                .or_default()
+                .push(answer);
+        }
+        for question in questions.iter_mut() {
+            question.answers = by_question.remove(&question.id).unwrap_or_default();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,444 @@
+// src/models/region.rs
+
+use crate::dtos::region::RegionDto;
+use sqlx::{MySql, Pool, QueryBuilder};
+use std::path::Path;
+
+pub struct RegionModel;
+
+/// Level wilayah beserta tabel, kolom parent, dan file CSV sumbernya.
+#[derive(Debug, Clone, Copy)]
+enum RegionLevel {
+    Province,
+    Regency,
+    District,
+    Village,
+}
+
+impl RegionLevel {
+    const ALL: [RegionLevel; 4] = [
+        RegionLevel::Province,
+        RegionLevel::Regency,
+        RegionLevel::District,
+        RegionLevel::Village,
+    ];
+
+    fn table(self) -> &'static str {
+        match self {
+            RegionLevel::Province => "provinces",
+            RegionLevel::Regency => "regencies",
+            RegionLevel::District => "districts",
+            RegionLevel::Village => "villages",
+        }
+    }
+
+    fn parent_column(self) -> Option<&'static str> {
+        match self {
+            RegionLevel::Province => None,
+            RegionLevel::Regency => Some("province_id"),
+            RegionLevel::District => Some("regency_id"),
+            RegionLevel::Village => Some("district_id"),
+        }
+    }
+
+    fn file_name(self) -> &'static str {
+        match self {
+            RegionLevel::Province => "provinces.csv",
+            RegionLevel::Regency => "regencies.csv",
+            RegionLevel::District => "districts.csv",
+            RegionLevel::Village => "villages.csv",
+        }
+    }
+
+    /// Dataset bawaan yang ikut ter-embed ke binary.
+    fn bundled(self) -> &'static str {
+        match self {
+            RegionLevel::Province => include_str!("../../data/regions/provinces.csv"),
+            RegionLevel::Regency => include_str!("../../data/regions/regencies.csv"),
+            RegionLevel::District => include_str!("../../data/regions/districts.csv"),
+            RegionLevel::Village => include_str!("../../data/regions/villages.csv"),
+        }
+    }
+}
+
+/// Variasi penulisan awalan administratif beserta bentuk bakunya. "Kota" dan "Kabupaten"
+/// tetap dibedakan karena banyak pasangan senama di satu provinsi (Kota/Kabupaten Bogor,
+/// Bekasi, Malang, ...). Urutkan dari yang terpanjang.
+const PREFIXES: &[(&str, &str)] = &[
+    ("provinsi ", ""),
+    ("daerah khusus ibukota ", ""),
+    ("daerah istimewa ", ""),
+    ("dki ", ""),
+    ("di ", ""),
+    ("kota administrasi ", "kota "),
+    ("kabupaten administrasi ", "kabupaten "),
+    ("kabupaten ", "kabupaten "),
+    ("kab. ", "kabupaten "),
+    ("kab ", "kabupaten "),
+    ("kota ", "kota "),
+    ("kecamatan ", ""),
+    ("kec. ", ""),
+];
+
+/// Huruf kecil dan spasi dirapikan.
+fn collapse(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Nama dengan awalan administratif diseragamkan ("Kab. Bogor" -> "kabupaten bogor"),
+/// tetapi jenis wilayahnya tetap terbawa.
+fn canonical_name(name: &str) -> String {
+    let mut canonical = collapse(name);
+    for (prefix, replacement) in PREFIXES {
+        if let Some(rest) = canonical.strip_prefix(prefix) {
+            canonical = format!("{}{}", replacement, rest);
+        }
+    }
+    canonical
+}
+
+/// Normalisasi nama wilayah untuk pencocokan longgar: huruf kecil, spasi dirapikan,
+/// dan awalan administratif ("Kota", "Kabupaten", "DKI", dst.) dibuang.
+pub(crate) fn normalize_name(name: &str) -> String {
+    let mut normalized = collapse(name);
+    for (prefix, _) in PREFIXES {
+        if let Some(rest) = normalized.strip_prefix(prefix) {
+            normalized = rest.to_string();
+        }
+    }
+    normalized
+}
+
+/// Cari wilayah berdasarkan nama. Nama lengkap ("Kota Bogor") dicocokkan dulu; nama tanpa
+/// awalan ("Bogor") hanya dipakai jika cocok dengan tepat satu wilayah. `Err` berisi
+/// kandidatnya jika nama tersebut ambigu.
+fn find_by_name<'a>(
+    regions: &'a [RegionDto],
+    name: &str,
+) -> Result<Option<&'a RegionDto>, Vec<&'a RegionDto>> {
+    let wanted = canonical_name(name);
+    if let Some(region) = regions.iter().find(|r| canonical_name(&r.name) == wanted) {
+        return Ok(Some(region));
+    }
+
+    let wanted = normalize_name(name);
+    let mut matches: Vec<&RegionDto> = regions
+        .iter()
+        .filter(|r| normalize_name(&r.name) == wanted)
+        .collect();
+    match matches.len() {
+        0 => Ok(None),
+        1 => Ok(matches.pop()),
+        _ => Err(matches),
+    }
+}
+
+/// Pesan untuk nama yang cocok dengan lebih dari satu wilayah.
+fn ambiguous_message(level: &str, name: &str, parent: &str, candidates: &[&RegionDto]) -> String {
+    let names: Vec<&str> = candidates.iter().map(|r| r.name.as_str()).collect();
+    format!(
+        "{} '{}' di {} ambigu, sebutkan Kota/Kabupaten: {}",
+        level,
+        name,
+        parent,
+        names.join(", ")
+    )
+}
+
+impl RegionModel {
+    // --- 1. LOOKUP UNTUK DROPDOWN BERJENJANG ---
+    pub async fn find_provinces(pool: &Pool<MySql>) -> Result<Vec<RegionDto>, sqlx::Error> {
+        sqlx::query_as::<_, RegionDto>("SELECT id, name FROM provinces ORDER BY name ASC")
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn find_regencies(
+        pool: &Pool<MySql>,
+        province_id: &str,
+    ) -> Result<Vec<RegionDto>, sqlx::Error> {
+        Self::find_children(pool, RegionLevel::Regency, province_id).await
+    }
+
+    pub async fn find_districts(
+        pool: &Pool<MySql>,
+        regency_id: &str,
+    ) -> Result<Vec<RegionDto>, sqlx::Error> {
+        Self::find_children(pool, RegionLevel::District, regency_id).await
+    }
+
+    pub async fn find_villages(
+        pool: &Pool<MySql>,
+        district_id: &str,
+    ) -> Result<Vec<RegionDto>, sqlx::Error> {
+        Self::find_children(pool, RegionLevel::Village, district_id).await
+    }
+
+    async fn find_children(
+        pool: &Pool<MySql>,
+        level: RegionLevel,
+        parent_id: &str,
+    ) -> Result<Vec<RegionDto>, sqlx::Error> {
+        let parent_column = level.parent_column().unwrap_or("id");
+        sqlx::query_as::<_, RegionDto>(&format!(
+            "SELECT id, name FROM {} WHERE {} = ? ORDER BY name ASC",
+            level.table(),
+            parent_column
+        ))
+        .bind(parent_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    // --- 2. VALIDASI ALAMAT TERSTRUKTUR ---
+    // Mengembalikan `Some(pesan)` jika alamat tidak cocok dengan dataset.
+    // Provinsi dan kota/kabupaten selalu divalidasi. Kecamatan hanya divalidasi jika data
+    // kecamatan kota tersebut sudah dimuat (bawaan: DKI Jakarta dan Kota Surabaya).
+    pub async fn validate_address(
+        pool: &Pool<MySql>,
+        province: &str,
+        city: &str,
+        district: &str,
+    ) -> Result<Option<String>, sqlx::Error> {
+        let provinces = Self::find_provinces(pool).await?;
+        if provinces.is_empty() {
+            return Ok(None);
+        }
+
+        let province_row = match find_by_name(&provinces, province) {
+            Ok(Some(row)) => row,
+            Ok(None) => return Ok(Some(format!("Provinsi '{}' tidak dikenal", province))),
+            Err(candidates) => {
+                return Ok(Some(ambiguous_message(
+                    "Provinsi",
+                    province,
+                    "Indonesia",
+                    &candidates,
+                )));
+            }
+        };
+
+        let regencies = Self::find_regencies(pool, &province_row.id).await?;
+        if regencies.is_empty() {
+            return Ok(None);
+        }
+
+        let regency_row = match find_by_name(&regencies, city) {
+            Ok(Some(row)) => row,
+            Ok(None) => {
+                return Ok(Some(format!(
+                    "Kota/Kabupaten '{}' tidak ditemukan di provinsi {}",
+                    city, province_row.name
+                )));
+            }
+            Err(candidates) => {
+                return Ok(Some(ambiguous_message(
+                    "Kota/Kabupaten",
+                    city,
+                    &province_row.name,
+                    &candidates,
+                )));
+            }
+        };
+
+        let districts = Self::find_districts(pool, &regency_row.id).await?;
+        if districts.is_empty() || !matches!(find_by_name(&districts, district), Ok(None)) {
+            return Ok(None);
+        }
+
+        Ok(Some(format!(
+            "Kecamatan '{}' tidak ditemukan di {}",
+            district, regency_row.name
+        )))
+    }
+
+    // --- 3. SEED DATASET ---
+    // Dijalankan setiap start. Setiap level dimuat terpisah dan hanya jika tabelnya punya
+    // lebih sedikit baris dari sumbernya, jadi dataset yang lebih lengkap (bawaan versi baru
+    // atau `REGION_DATA_DIR`) tetap masuk ke database yang sudah terisi sebagian.
+    // File yang tidak ada di `REGION_DATA_DIR` memakai versi bawaan.
+    pub async fn seed(pool: &Pool<MySql>) -> anyhow::Result<u64> {
+        let data_dir = std::env::var("REGION_DATA_DIR").ok();
+        let mut total = 0;
+
+        for level in RegionLevel::ALL {
+            let content = match data_dir
+                .as_deref()
+                .map(|dir| Path::new(dir).join(level.file_name()))
+                .filter(|path| path.exists())
+            {
+                Some(path) => std::fs::read_to_string(path)?,
+                None => level.bundled().to_string(),
+            };
+
+            let rows = Self::parse_csv(level, &content)?;
+            let existing: i64 =
+                sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", level.table()))
+                    .fetch_one(pool)
+                    .await?;
+            if existing as usize >= rows.len() {
+                continue;
+            }
+
+            total += Self::upsert_rows(pool, level, &rows).await?;
+        }
+
+        Ok(total)
+    }
+
+    fn parse_csv(
+        level: RegionLevel,
+        content: &str,
+    ) -> anyhow::Result<Vec<(String, Option<String>, String)>> {
+        let mut reader = csv::Reader::from_reader(content.as_bytes());
+        let mut rows = Vec::new();
+
+        for record in reader.records() {
+            let record = record?;
+            let row = match level.parent_column() {
+                None => (
+                    record[0].trim().to_string(),
+                    None,
+                    record[1].trim().to_string(),
+                ),
+                Some(_) => (
+                    record[0].trim().to_string(),
+                    Some(record[1].trim().to_string()),
+                    record[2].trim().to_string(),
+                ),
+            };
+            rows.push(row);
+        }
+
+        Ok(rows)
+    }
+
+    // Baris yang id-nya sudah ada diperbarui namanya, sisanya ditambahkan.
+    async fn upsert_rows(
+        pool: &Pool<MySql>,
+        level: RegionLevel,
+        rows: &[(String, Option<String>, String)],
+    ) -> anyhow::Result<u64> {
+        let mut inserted = 0;
+        for chunk in rows.chunks(500) {
+            let mut builder: QueryBuilder<MySql> = match level.parent_column() {
+                None => QueryBuilder::new(format!("INSERT INTO {} (id, name) ", level.table())),
+                Some(parent) => QueryBuilder::new(format!(
+                    "INSERT INTO {} (id, {}, name) ",
+                    level.table(),
+                    parent
+                )),
+            };
+
+            builder.push_values(chunk, |mut b, (id, parent_id, name)| {
+                b.push_bind(id);
+                if let Some(parent_id) = parent_id {
+                    b.push_bind(parent_id);
+                }
+                b.push_bind(name);
+            });
+            builder.push(" ON DUPLICATE KEY UPDATE name = VALUES(name)");
+
+            inserted += builder.build().execute(pool).await?.rows_affected();
+        }
+
+        Ok(inserted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn region(id: &str, name: &str) -> RegionDto {
+        RegionDto {
+            id: id.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    fn jawa_barat() -> Vec<RegionDto> {
+        vec![
+            region("3201", "Kabupaten Bogor"),
+            region("3204", "Kabupaten Bandung"),
+            region("3271", "Kota Bogor"),
+            region("3273", "Kota Bandung"),
+            region("3276", "Kota Depok"),
+        ]
+    }
+
+    fn id_of<'a>(result: Result<Option<&'a RegionDto>, Vec<&'a RegionDto>>) -> Option<&'a str> {
+        result.ok().flatten().map(|r| r.id.as_str())
+    }
+
+    #[test]
+    fn kota_and_kabupaten_namesakes_resolve_separately() {
+        let regencies = jawa_barat();
+        assert_eq!(id_of(find_by_name(&regencies, "Kota Bogor")), Some("3271"));
+        assert_eq!(
+            id_of(find_by_name(&regencies, "Kabupaten Bogor")),
+            Some("3201")
+        );
+        assert_eq!(id_of(find_by_name(&regencies, "kab. bogor")), Some("3201"));
+        assert_eq!(id_of(find_by_name(&regencies, "KAB  Bogor")), Some("3201"));
+        assert_eq!(
+            id_of(find_by_name(&regencies, "Kota Administrasi Bogor")),
+            Some("3271")
+        );
+    }
+
+    #[test]
+    fn bare_namesake_is_ambiguous() {
+        let regencies = jawa_barat();
+        let candidates = find_by_name(&regencies, "Bogor").unwrap_err();
+        let ids: Vec<&str> = candidates.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["3201", "3271"]);
+
+        let message = ambiguous_message("Kota/Kabupaten", "Bogor", "Jawa Barat", &candidates);
+        assert!(message.contains("Kabupaten Bogor, Kota Bogor"));
+    }
+
+    #[test]
+    fn bare_unique_name_still_matches() {
+        let regencies = jawa_barat();
+        assert_eq!(id_of(find_by_name(&regencies, "Depok")), Some("3276"));
+        // Jenis wilayah yang salah tetap diterima jika namanya hanya ada satu
+        assert_eq!(
+            id_of(find_by_name(&regencies, "Kabupaten Depok")),
+            Some("3276")
+        );
+        assert_eq!(id_of(find_by_name(&regencies, "Cimahi")), None);
+    }
+
+    #[test]
+    fn province_and_district_prefixes_are_optional() {
+        let provinces = vec![region("31", "DKI Jakarta"), region("32", "Jawa Barat")];
+        assert_eq!(
+            id_of(find_by_name(&provinces, "Daerah Khusus Ibukota Jakarta")),
+            Some("31")
+        );
+        assert_eq!(
+            id_of(find_by_name(&provinces, "Provinsi Jawa Barat")),
+            Some("32")
+        );
+
+        let districts = vec![region("317109", "Tebet")];
+        assert_eq!(
+            id_of(find_by_name(&districts, "Kec. Tebet")),
+            Some("317109")
+        );
+    }
+
+    #[test]
+    fn normalize_name_strips_prefixes_for_loose_matching() {
+        assert_eq!(normalize_name("Kota  Surabaya"), "surabaya");
+        assert_eq!(
+            normalize_name("Kabupaten Administrasi Kepulauan Seribu"),
+            "kepulauan seribu"
+        );
+        assert_eq!(normalize_name("DKI Jakarta"), "jakarta");
+    }
+}
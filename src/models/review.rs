@@ -0,0 +1,375 @@
+// src/models/review.rs
+//
+// Ulasan produk. Hanya ulasan APPROVED yang tampil dan dihitung; ringkasannya disimpan di
+// products.rating_average / rating_count setiap kali status ulasan berubah.
+
+use crate::dtos::pagination::{PaginatedResponse, PaginationMeta, PaginationParams};
+use crate::dtos::product_image::{NewProductImage, StoredImageKeys};
+use crate::dtos::review::{
+    ExportedReview, NewReview, ProductReview, RatingBucket, RatingSummary, ReviewForModeration,
+    ReviewPhoto, ReviewStatus,
+};
+use sqlx::{MySql, MySqlConnection, Pool, QueryBuilder};
+use std::collections::HashMap;
+
+pub struct ReviewModel;
+
+impl ReviewModel {
+    // --- 1. PEMBELI TERVERIFIKASI ---
+    // Punya order PAID yang berisi produk ini.
+    pub async fn is_verified_buyer(
+        pool: &Pool<MySql>,
+        user_id: i64,
+        product_id: i64,
+    ) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT EXISTS(
+                SELECT 1
+                FROM order_items oi
+                JOIN orders o ON o.id = oi.order_id
+                WHERE o.user_id = ? AND oi.product_id = ? AND o.status = 'PAID'
+            )
+            "#,
+        )
+        .bind(user_id)
+        .bind(product_id)
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn has_reviewed(
+        pool: &Pool<MySql>,
+        user_id: i64,
+        product_id: i64,
+    ) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM product_reviews WHERE product_id = ? AND user_id = ?)",
+        )
+        .bind(product_id)
+        .bind(user_id)
+        .fetch_one(pool)
+        .await
+    }
+
+    // --- 2. CREATE ---
+    // Ulasan dan fotonya disimpan dalam satu transaksi; status awal PENDING.
+    pub async fn create(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        user_id: i64,
+        review: NewReview,
+        photos: Vec<NewProductImage>,
+    ) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let result = sqlx::query(
+            r#"
+            INSERT INTO product_reviews (product_id, user_id, rating, title, body)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(product_id)
+        .bind(user_id)
+        .bind(review.rating)
+        .bind(review.title)
+        .bind(review.body.trim())
+        .execute(&mut *tx)
+        .await?;
+        let review_id = result.last_insert_id();
+
+        for (sort_order, photo) in photos.into_iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO product_review_photos
+                    (review_id, sort_order, original_url, medium_url, thumbnail_url,
+                     original_key, medium_key, thumbnail_key)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(review_id)
+            .bind(sort_order as i32)
+            .bind(photo.original_url)
+            .bind(photo.medium_url)
+            .bind(photo.thumbnail_url)
+            .bind(photo.keys.original_key)
+            .bind(photo.keys.medium_key)
+            .bind(photo.keys.thumbnail_key)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(review_id)
+    }
+
+    // --- 3. ULASAN PUBLIK PER PRODUK ---
+    pub async fn find_approved(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        params: &PaginationParams,
+    ) -> Result<(Vec<ProductReview>, i64), sqlx::Error> {
+        let total: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM product_reviews WHERE product_id = ? AND status = 'APPROVED'",
+        )
+        .bind(product_id)
+        .fetch_one(pool)
+        .await?;
+
+        let mut reviews = sqlx::query_as::<_, ProductReview>(
+            r#"
+            SELECT r.id, r.product_id, u.name AS reviewer_name, r.rating, r.title, r.body,
+                   r.created_at
+            FROM product_reviews r
+            JOIN users u ON u.id = r.user_id
+            WHERE r.product_id = ? AND r.status = 'APPROVED'
+            ORDER BY r.created_at DESC, r.id DESC
+            LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(product_id)
+        .bind(params.per_page as i64)
+        .bind(params.offset() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Self::attach_photos(pool, reviews.iter_mut()).await?;
+        Ok((reviews, total))
+    }
+
+    pub async fn rating_summary(
+        pool: &Pool<MySql>,
+        product_id: i64,
+    ) -> Result<RatingSummary, sqlx::Error> {
+        let buckets = sqlx::query_as::<_, RatingBucket>(
+            r#"
+            SELECT rating, COUNT(*) AS count
+            FROM product_reviews
+            WHERE product_id = ? AND status = 'APPROVED'
+            GROUP BY rating
+            "#,
+        )
+        .bind(product_id)
+        .fetch_all(pool)
+        .await?;
+
+        let distribution: Vec<RatingBucket> = (1..=5)
+            .rev()
+            .map(|rating| RatingBucket {
+                rating,
+                count: buckets
+                    .iter()
+                    .find(|b| b.rating == rating)
+                    .map_or(0, |b| b.count),
+            })
+            .collect();
+        let count: i64 = distribution.iter().map(|b| b.count).sum();
+        let stars: i64 = distribution.iter().map(|b| b.rating as i64 * b.count).sum();
+        let average = (count > 0).then(|| (stars as f64 / count as f64 * 100.0).round() / 100.0);
+
+        Ok(RatingSummary {
+            average,
+            count,
+            distribution,
+        })
+    }
+
+    // --- 4. ANTREAN MODERASI ---
+    pub async fn find_for_moderation(
+        pool: &Pool<MySql>,
+        status: ReviewStatus,
+        params: PaginationParams,
+    ) -> Result<PaginatedResponse<ReviewForModeration>, sqlx::Error> {
+        let total: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM product_reviews WHERE status = ?")
+                .bind(status.as_str())
+                .fetch_one(pool)
+                .await?;
+
+        // Antrean PENDING dari yang paling lama menunggu
+        let order = if status == ReviewStatus::Pending {
+            "ASC"
+        } else {
+            "DESC"
+        };
+        let mut reviews = sqlx::query_as::<_, ReviewForModeration>(&format!(
+            r#"
+            SELECT r.id, r.product_id, u.name AS reviewer_name, r.rating, r.title, r.body,
+                   r.created_at, p.name AS product_name, u.email AS reviewer_email, r.status,
+                   r.moderation_note, r.moderated_at
+            FROM product_reviews r
+            JOIN users u ON u.id = r.user_id
+            JOIN products p ON p.id = r.product_id
+            WHERE r.status = ?
+            ORDER BY r.created_at {0}, r.id {0}
+            LIMIT ? OFFSET ?
+            "#,
+            order
+        ))
+        .bind(status.as_str())
+        .bind(params.per_page as i64)
+        .bind(params.offset() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Self::attach_photos(pool, reviews.iter_mut().map(|r| &mut r.review)).await?;
+        Ok(PaginatedResponse {
+            data: reviews,
+            pagination: PaginationMeta::new(params.page, params.per_page, total as u32),
+        })
+    }
+
+    // --- 5. MODERASI ---
+    // Mengembalikan id produk, atau `None` jika ulasan tidak ada. Rating produk dihitung ulang
+    // di transaksi yang sama.
+    pub async fn moderate(
+        pool: &Pool<MySql>,
+        review_id: i64,
+        status: ReviewStatus,
+        note: Option<String>,
+        moderated_by: Option<i64>,
+    ) -> Result<Option<i64>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let product_id: Option<i64> =
+            sqlx::query_scalar("SELECT product_id FROM product_reviews WHERE id = ? FOR UPDATE")
+                .bind(review_id)
+                .fetch_optional(&mut *tx)
+                .await?;
+        let Some(product_id) = product_id else {
+            return Ok(None);
+        };
+
+        sqlx::query(
+            r#"
+            UPDATE product_reviews
+            SET status = ?, moderation_note = ?, moderated_by = ?, moderated_at = NOW()
+            WHERE id = ?
+            "#,
+        )
+        .bind(status.as_str())
+        .bind(note)
+        .bind(moderated_by)
+        .bind(review_id)
+        .execute(&mut *tx)
+        .await?;
+
+        Self::refresh_product_rating(&mut tx, product_id).await?;
+        tx.commit().await?;
+        Ok(Some(product_id))
+    }
+
+    // --- 6. ULASAN MILIK USER (EKSPOR DATA PRIBADI) ---
+    pub async fn find_by_user(
+        pool: &Pool<MySql>,
+        user_id: i64,
+    ) -> Result<Vec<ExportedReview>, sqlx::Error> {
+        let mut reviews = sqlx::query_as::<_, ExportedReview>(
+            r#"
+            SELECT r.id, r.product_id, u.name AS reviewer_name, r.rating, r.title, r.body,
+                   r.created_at, p.name AS product_name, r.status
+            FROM product_reviews r
+            JOIN users u ON u.id = r.user_id
+            JOIN products p ON p.id = r.product_id
+            WHERE r.user_id = ?
+            ORDER BY r.created_at DESC, r.id DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+
+        Self::attach_photos(pool, reviews.iter_mut().map(|r| &mut r.review)).await?;
+        Ok(reviews)
+    }
+
+    // --- 7. HAPUS FOTO ULASAN USER (HAPUS AKUN) ---
+    // Dipanggil di transaksi anonimisasi. Teks dan rating ulasan tetap ada (tampil atas nama
+    // "Deleted User") agar rating produk tidak berubah, tapi foto bisa memuat wajah atau
+    // rumah pengguna sehingga ikut dihapus. Mengembalikan key storage yang harus dihapus
+    // pemanggil setelah commit.
+    pub async fn delete_user_photos(
+        conn: &mut MySqlConnection,
+        user_id: i64,
+    ) -> Result<Vec<StoredImageKeys>, sqlx::Error> {
+        let keys = sqlx::query_as::<_, StoredImageKeys>(
+            r#"
+            SELECT ph.original_key, ph.medium_key, ph.thumbnail_key
+            FROM product_review_photos ph
+            JOIN product_reviews r ON r.id = ph.review_id
+            WHERE r.user_id = ?
+            FOR UPDATE
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        sqlx::query(
+            r#"
+            DELETE ph FROM product_review_photos ph
+            JOIN product_reviews r ON r.id = ph.review_id
+            WHERE r.user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&mut *conn)
+        .await?;
+        Ok(keys)
+    }
+
+    /// Hitung ulang `rating_average` / `rating_count` produk dari ulasan yang disetujui.
+    async fn refresh_product_rating(
+        conn: &mut MySqlConnection,
+        product_id: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE products p
+            LEFT JOIN (
+                SELECT product_id, ROUND(AVG(rating), 2) AS average, COUNT(*) AS total
+                FROM product_reviews
+                WHERE product_id = ? AND status = 'APPROVED'
+                GROUP BY product_id
+            ) r ON r.product_id = p.id
+            SET p.rating_average = r.average, p.rating_count = COALESCE(r.total, 0)
+            WHERE p.id = ?
+            "#,
+        )
+        .bind(product_id)
+        .bind(product_id)
+        .execute(&mut *conn)
+        .await?;
+        Ok(())
+    }
+
+    async fn attach_photos<'a>(
+        pool: &Pool<MySql>,
+        reviews: impl Iterator<Item = &'a mut ProductReview>,
+    ) -> Result<(), sqlx::Error> {
+        let mut by_id: HashMap<i64, &'a mut ProductReview> =
+            reviews.map(|review| (review.id, review)).collect();
+        if by_id.is_empty() {
+            return Ok(());
+        }
+
+        let mut query = QueryBuilder::<MySql>::new(
+            "SELECT id, review_id, original_url, medium_url, thumbnail_url \
+             FROM product_review_photos WHERE review_id IN (",
+        );
+        let mut ids = query.separated(", ");
+        for id in by_id.keys() {
+            ids.push_bind(*id);
+        }
+        query.push(") ORDER BY review_id, sort_order, id");
+
+        let photos = query
+            .build_query_as::<ReviewPhoto>()
+            .fetch_all(pool)
+            .await?;
+        for photo in photos {
+            if let Some(review) = by_id.get_mut(&photo.review_id) {
+                review.photos.push(photo);
+            }
+        }
+        Ok(())
+    }
+}
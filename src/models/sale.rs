@@ -0,0 +1,230 @@
+// src/models/sale.rs
+//
+// Harga sale terjadwal. Harga efektif dihitung di SQL (lihat konstanta di bawah) agar listing,
+// filter & urutan harga, checkout, dan katalog chatbot memakai aturan yang sama:
+// - produk: sale produk yang sedang berlaku, selain itu harga normal
+// - varian: sale varian, harga varian, sale produk induk, lalu harga produk induk
+
+use crate::dtos::pricing::{NewProductSaleDto, PriceChangeType, PricingError, ProductSale};
+use crate::models::price_history::{NewPriceChange, PriceHistoryModel};
+use crate::utils::money::Money;
+use chrono::NaiveDateTime;
+use sqlx::{MySql, Pool};
+
+pub struct SaleModel;
+
+/// LEFT JOIN sale tingkat produk yang sedang berlaku untuk `p` (alias `sale`).
+/// Jadwal sale satu target tidak boleh tumpang tindih, jadi join ini paling banyak satu baris.
+pub(crate) const ACTIVE_SALE_JOIN: &str = " LEFT JOIN product_sales sale \
+    ON sale.product_id = p.id AND sale.variant_id IS NULL \
+    AND sale.starts_at <= NOW() AND sale.ends_at > NOW()";
+
+/// LEFT JOIN sale varian `v` yang sedang berlaku (alias `vsale`).
+pub(crate) const ACTIVE_VARIANT_SALE_JOIN: &str = " LEFT JOIN product_sales vsale \
+    ON vsale.variant_id = v.id AND vsale.starts_at <= NOW() AND vsale.ends_at > NOW()";
+
+/// Harga efektif produk `p`; butuh `ACTIVE_SALE_JOIN`.
+pub(crate) const FINAL_PRICE_SQL: &str = "COALESCE(sale.sale_price, p.price)";
+
+/// Harga efektif varian `v` beserta akhir sale-nya; butuh kedua join di atas.
+pub(crate) const VARIANT_FINAL_PRICE_SQL: &str = "COALESCE(vsale.sale_price, v.price, sale.sale_price, p.price) AS final_price, \
+    CASE WHEN vsale.id IS NOT NULL THEN vsale.ends_at \
+         WHEN v.price IS NULL THEN sale.ends_at END AS sale_ends_at";
+
+/// Baris sale yang dikunci saat dihentikan.
+#[derive(sqlx::FromRow)]
+struct LockedSale {
+    variant_id: Option<i64>,
+    sale_price: Money,
+    starts_at: NaiveDateTime,
+    ends_at: NaiveDateTime,
+    now: NaiveDateTime,
+}
+
+impl SaleModel {
+    // --- 1. SEMUA SALE SATU PRODUK (TERMASUK MILIK VARIANNYA) ---
+    pub async fn find_by_product(
+        pool: &Pool<MySql>,
+        product_id: i64,
+    ) -> Result<Vec<ProductSale>, sqlx::Error> {
+        sqlx::query_as::<_, ProductSale>(
+            r#"
+            SELECT s.id, s.product_id, s.variant_id, v.sku, s.sale_price, s.starts_at, s.ends_at,
+                   CASE WHEN s.starts_at > NOW() THEN 'SCHEDULED'
+                        WHEN s.ends_at > NOW() THEN 'ACTIVE'
+                        ELSE 'ENDED' END AS status,
+                   s.created_by, s.created_at
+            FROM product_sales s
+            LEFT JOIN product_variants v ON s.variant_id = v.id
+            WHERE s.product_id = ?
+            ORDER BY s.starts_at DESC, s.id DESC
+            "#,
+        )
+        .bind(product_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    // --- 2. JADWALKAN SALE ---
+    // Baris produk dikunci agar dua jadwal untuk target yang sama tidak lolos cek tumpang
+    // tindih secara bersamaan.
+    pub async fn create(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        sale: NewProductSaleDto,
+        created_by: Option<i64>,
+    ) -> Result<i64, PricingError> {
+        let mut tx = pool.begin().await?;
+
+        let product_price: Money = sqlx::query_scalar(
+            "SELECT price FROM products WHERE id = ? AND archived_at IS NULL FOR UPDATE",
+        )
+        .bind(product_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| PricingError::NotFound("Produk tidak ditemukan".to_string()))?;
+
+        let regular_price = match sale.variant_id {
+            Some(variant_id) => {
+                let variant_price: Option<Money> = sqlx::query_scalar(
+                    "SELECT price FROM product_variants WHERE id = ? AND product_id = ?",
+                )
+                .bind(variant_id)
+                .bind(product_id)
+                .fetch_optional(&mut *tx)
+                .await?
+                .ok_or_else(|| PricingError::NotFound("Varian tidak ditemukan".to_string()))?;
+                variant_price.unwrap_or(product_price)
+            }
+            None => product_price,
+        };
+        if sale.sale_price >= regular_price {
+            return Err(PricingError::Invalid(format!(
+                "Harga sale harus lebih rendah dari harga normal (Rp {})",
+                regular_price
+            )));
+        }
+
+        let ends_in_future: bool = sqlx::query_scalar("SELECT ? > NOW()")
+            .bind(sale.ends_at)
+            .fetch_one(&mut *tx)
+            .await?;
+        if !ends_in_future {
+            return Err(PricingError::Invalid("ends_at sudah lewat".to_string()));
+        }
+
+        let overlapping: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM product_sales
+            WHERE product_id = ? AND variant_id <=> ? AND starts_at < ? AND ends_at > ?
+            "#,
+        )
+        .bind(product_id)
+        .bind(sale.variant_id)
+        .bind(sale.ends_at)
+        .bind(sale.starts_at)
+        .fetch_one(&mut *tx)
+        .await?;
+        if overlapping > 0 {
+            return Err(PricingError::Invalid(
+                "Jadwal bertabrakan dengan sale lain untuk produk/varian yang sama".to_string(),
+            ));
+        }
+
+        let id = sqlx::query(
+            r#"
+            INSERT INTO product_sales (product_id, variant_id, sale_price, starts_at, ends_at,
+                                       created_by)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(product_id)
+        .bind(sale.variant_id)
+        .bind(sale.sale_price)
+        .bind(sale.starts_at)
+        .bind(sale.ends_at)
+        .bind(created_by)
+        .execute(&mut *tx)
+        .await?
+        .last_insert_id() as i64;
+
+        PriceHistoryModel::record(
+            &mut tx,
+            NewPriceChange {
+                product_id,
+                variant_id: sale.variant_id,
+                change_type: PriceChangeType::SaleScheduled,
+                old_price: Some(regular_price),
+                new_price: Some(sale.sale_price),
+                sale: Some((id, sale.starts_at, sale.ends_at)),
+                changed_by: created_by,
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(id)
+    }
+
+    // --- 3. HENTIKAN SALE ---
+    // Sale yang belum dimulai dihapus (`true`); yang sedang berjalan diakhiri sekarang (`false`).
+    pub async fn end(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        sale_id: i64,
+        changed_by: Option<i64>,
+    ) -> Result<bool, PricingError> {
+        let mut tx = pool.begin().await?;
+
+        let sale = sqlx::query_as::<_, LockedSale>(
+            r#"
+            SELECT variant_id, sale_price, starts_at, ends_at, NOW() AS now
+            FROM product_sales
+            WHERE id = ? AND product_id = ?
+            FOR UPDATE
+            "#,
+        )
+        .bind(sale_id)
+        .bind(product_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| PricingError::NotFound("Sale tidak ditemukan".to_string()))?;
+
+        if sale.ends_at <= sale.now {
+            return Err(PricingError::Invalid("Sale sudah berakhir".to_string()));
+        }
+
+        let cancelled = sale.starts_at > sale.now;
+        let (change_type, ends_at) = if cancelled {
+            sqlx::query("DELETE FROM product_sales WHERE id = ?")
+                .bind(sale_id)
+                .execute(&mut *tx)
+                .await?;
+            (PriceChangeType::SaleCancelled, sale.ends_at)
+        } else {
+            sqlx::query("UPDATE product_sales SET ends_at = ? WHERE id = ?")
+                .bind(sale.now)
+                .bind(sale_id)
+                .execute(&mut *tx)
+                .await?;
+            (PriceChangeType::SaleEnded, sale.now)
+        };
+
+        PriceHistoryModel::record(
+            &mut tx,
+            NewPriceChange {
+                product_id,
+                variant_id: sale.variant_id,
+                change_type,
+                old_price: None,
+                new_price: Some(sale.sale_price),
+                sale: Some((sale_id, sale.starts_at, ends_at)),
+                changed_by,
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(cancelled)
+    }
+}
@@ -0,0 +1,55 @@
+// src/models/search.rs
+
+use crate::dtos::search::{PopularQuery, SuggestSource};
+use sqlx::{MySql, Pool};
+
+pub struct SearchModel;
+
+/// Jumlah query populer yang dimuat ke index saran.
+const POPULAR_QUERY_LIMIT: i64 = 500;
+
+impl SearchModel {
+    // --- 1. CATAT QUERY PENCARIAN ---
+    pub async fn record_query(pool: &Pool<MySql>, query: &str) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO search_queries (query) VALUES (?)
+            ON DUPLICATE KEY UPDATE hits = hits + 1, last_searched_at = NOW()
+            "#,
+        )
+        .bind(query)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    // --- 2. SUMBER DATA INDEX SARAN ---
+    pub async fn find_product_names(pool: &Pool<MySql>) -> Result<Vec<SuggestSource>, sqlx::Error> {
+        sqlx::query_as::<_, SuggestSource>(
+            "SELECT id, name FROM products WHERE archived_at IS NULL",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_category_names(
+        pool: &Pool<MySql>,
+    ) -> Result<Vec<SuggestSource>, sqlx::Error> {
+        sqlx::query_as::<_, SuggestSource>(
+            "SELECT CAST(id AS SIGNED) AS id, name FROM kategori WHERE archived_at IS NULL",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_popular_queries(
+        pool: &Pool<MySql>,
+    ) -> Result<Vec<PopularQuery>, sqlx::Error> {
+        sqlx::query_as::<_, PopularQuery>(
+            "SELECT query, hits FROM search_queries ORDER BY hits DESC LIMIT ?",
+        )
+        .bind(POPULAR_QUERY_LIMIT)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,79 @@
+// src/models/supplier.rs
+
+use crate::dtos::supplier::{NewSupplierDto, Supplier};
+use sqlx::{Executor, MySql, Pool};
+
+pub struct SupplierModel;
+
+const SUPPLIER_COLUMNS: &str = "id, name, contact_name, email, phone, address, is_active";
+
+impl SupplierModel {
+    // --- 1. CREATE ---
+    pub async fn insert(pool: &Pool<MySql>, supplier: NewSupplierDto) -> Result<i64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO suppliers (name, contact_name, email, phone, address, is_active)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(supplier.name.trim())
+        .bind(supplier.contact_name)
+        .bind(supplier.email)
+        .bind(supplier.phone)
+        .bind(supplier.address)
+        .bind(supplier.is_active)
+        .execute(pool)
+        .await?;
+
+        Ok(result.last_insert_id() as i64)
+    }
+
+    // --- 2. READ ---
+    pub async fn find_all(pool: &Pool<MySql>) -> Result<Vec<Supplier>, sqlx::Error> {
+        sqlx::query_as::<_, Supplier>(&format!(
+            "SELECT {} FROM suppliers ORDER BY name ASC",
+            SUPPLIER_COLUMNS
+        ))
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_by_id(
+        executor: impl Executor<'_, Database = MySql>,
+        id: i64,
+    ) -> Result<Option<Supplier>, sqlx::Error> {
+        sqlx::query_as::<_, Supplier>(&format!(
+            "SELECT {} FROM suppliers WHERE id = ?",
+            SUPPLIER_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+    }
+
+    // --- 3. UPDATE ---
+    pub async fn update(
+        pool: &Pool<MySql>,
+        id: i64,
+        supplier: NewSupplierDto,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE suppliers SET
+                name = ?, contact_name = ?, email = ?, phone = ?, address = ?, is_active = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(supplier.name.trim())
+        .bind(supplier.contact_name)
+        .bind(supplier.email)
+        .bind(supplier.phone)
+        .bind(supplier.address)
+        .bind(supplier.is_active)
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -1,3 +1,6 @@
+use crate::dtos::product_image::StoredImageKeys;
+use crate::dtos::user::ExportedSession;
+use crate::models::review::ReviewModel;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use sqlx::{MySql, Pool};
@@ -63,6 +66,17 @@ impl User {
             .await
     }
 
+    // Ambil id user berdasarkan email (dipakai handler yang hanya punya AuthUser)
+    pub async fn find_id_by_email(
+        pool: &Pool<MySql>,
+        email: &str,
+    ) -> Result<Option<i64>, sqlx::Error> {
+        sqlx::query_scalar("SELECT id FROM users WHERE email = ?")
+            .bind(email)
+            .fetch_optional(pool)
+            .await
+    }
+
     // Update role pengguna
     pub async fn update_role(
         pool: &Pool<MySql>,
@@ -139,4 +153,72 @@ impl User {
 
         Ok(())
     }
+
+    // Daftar sesi login (refresh token) milik user, tanpa nilai token
+    pub async fn find_sessions(
+        pool: &Pool<MySql>,
+        user_id: i64,
+    ) -> Result<Vec<ExportedSession>, sqlx::Error> {
+        sqlx::query_as::<_, ExportedSession>(
+            "SELECT id, revoked, expires_at FROM refresh_tokens WHERE user_id = ? ORDER BY id DESC",
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    // Hapus akun: anonimkan data pribadi tapi pertahankan baris users
+    // agar order lama tetap bisa direferensikan untuk pembukuan.
+    // Semua refresh token dan API key milik user ikut dicabut.
+    pub async fn anonymize(
+        pool: &Pool<MySql>,
+        user_id: i64,
+    ) -> Result<Vec<StoredImageKeys>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query(
+            "UPDATE users
+            SET name = 'Deleted User',
+                email = CONCAT('deleted-', id, '@deleted.invalid'),
+                password = '',
+                address = NULL,
+                role = 'user',
+                deleted_at = NOW()
+            WHERE id = ?",
+        )
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("UPDATE refresh_tokens SET revoked = true WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("UPDATE api_keys SET revoked = true WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        // Buku alamat ikut dihapus; order lama tetap menyimpan snapshot alamatnya sendiri
+        sqlx::query("DELETE FROM addresses WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM notifications WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM wishlist_items WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let photo_keys = ReviewModel::delete_user_photos(&mut tx, user_id).await?;
+
+        tx.commit().await?;
+        Ok(photo_keys)
+    }
 }
@@ -0,0 +1,203 @@
+// src/models/variant.rs
+
+use crate::dtos::inventory::MovementType;
+use crate::dtos::variant::{NewVariantDto, ProductVariant};
+use crate::models::inventory::{InventoryModel, NewMovement};
+use crate::models::price_history::PriceHistoryModel;
+use crate::models::sale::{ACTIVE_SALE_JOIN, ACTIVE_VARIANT_SALE_JOIN, VARIANT_FINAL_PRICE_SQL};
+use crate::models::warehouse::WarehouseModel;
+use crate::utils::money::Money;
+use sqlx::types::Json;
+use sqlx::{Executor, MySql, Pool};
+
+pub struct VariantModel;
+
+/// SELECT varian beserta harga efektifnya (lihat `models::sale`).
+fn variant_select() -> String {
+    format!(
+        r#"
+        SELECT v.id, v.product_id, v.sku, v.options, v.price, {}, v.stock,
+               v.stock - v.reserved_stock AS available_stock, v.image_url, v.is_active
+        FROM product_variants v
+        JOIN products p ON v.product_id = p.id
+        {} {}
+        "#,
+        VARIANT_FINAL_PRICE_SQL, ACTIVE_SALE_JOIN, ACTIVE_VARIANT_SALE_JOIN
+    )
+}
+
+impl VariantModel {
+    // --- 1. CREATE ---
+    // Stok awal masuk ke gudang utama dan dicatat sebagai ADJUSTMENT di buku besar stok;
+    // harga khusus varian (jika ada) dicatat di riwayat harga.
+    pub async fn insert(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        variant: NewVariantDto,
+        changed_by: Option<i64>,
+    ) -> Result<i64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let price = variant.price;
+        let result = sqlx::query(
+            r#"
+            INSERT INTO product_variants (product_id, sku, options, price, image_url, is_active)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(product_id)
+        .bind(variant.sku.trim())
+        .bind(Json(variant.options))
+        .bind(variant.price)
+        .bind(variant.image_url)
+        .bind(variant.is_active)
+        .execute(&mut *tx)
+        .await?;
+
+        let id = result.last_insert_id() as i64;
+        PriceHistoryModel::record_price(&mut tx, product_id, Some(id), None, price, changed_by)
+            .await?;
+        if variant.stock > 0 {
+            let warehouse_id = WarehouseModel::find_primary(&mut *tx)
+                .await?
+                .ok_or(sqlx::Error::RowNotFound)?
+                .id;
+            InventoryModel::lock_warehouse(&mut tx, warehouse_id, product_id, Some(id)).await?;
+            InventoryModel::apply(
+                &mut tx,
+                product_id,
+                Some(id),
+                warehouse_id,
+                variant.stock,
+                0,
+            )
+            .await?;
+            InventoryModel::record(
+                &mut tx,
+                NewMovement {
+                    product_id,
+                    variant_id: Some(id),
+                    warehouse_id,
+                    order_id: None,
+                    purchase_order_id: None,
+                    movement_type: MovementType::Adjustment,
+                    quantity: variant.stock,
+                    stock_after: variant.stock,
+                    note: Some("Stok awal varian"),
+                    created_by: None,
+                },
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(id)
+    }
+
+    // --- 2. VARIAN SATU PRODUK ---
+    pub async fn find_by_product(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        active_only: bool,
+    ) -> Result<Vec<ProductVariant>, sqlx::Error> {
+        sqlx::query_as::<_, ProductVariant>(&format!(
+            "{} WHERE v.product_id = ? AND (v.is_active OR ? = FALSE) ORDER BY v.id ASC",
+            variant_select()
+        ))
+        .bind(product_id)
+        .bind(active_only)
+        .fetch_all(pool)
+        .await
+    }
+
+    // --- 3. VARIAN UNTUK CHECKOUT (harus milik produk tersebut) ---
+    pub async fn find_for_product(
+        executor: impl Executor<'_, Database = MySql>,
+        product_id: i64,
+        variant_id: i64,
+    ) -> Result<Option<ProductVariant>, sqlx::Error> {
+        sqlx::query_as::<_, ProductVariant>(&format!(
+            "{} WHERE v.id = ? AND v.product_id = ?",
+            variant_select()
+        ))
+        .bind(variant_id)
+        .bind(product_id)
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn has_active_variants(
+        executor: impl Executor<'_, Database = MySql>,
+        product_id: i64,
+    ) -> Result<bool, sqlx::Error> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM product_variants WHERE product_id = ? AND is_active = TRUE",
+        )
+        .bind(product_id)
+        .fetch_one(executor)
+        .await?;
+        Ok(count > 0)
+    }
+
+    // --- 4. UPDATE ---
+    // Stok tidak ikut diubah; gunakan penyesuaian stok agar tercatat di buku besar.
+    // Perubahan harga varian dicatat di riwayat harga.
+    pub async fn update(
+        pool: &Pool<MySql>,
+        product_id: i64,
+        id: i64,
+        variant: NewVariantDto,
+        changed_by: Option<i64>,
+    ) -> Result<u64, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let old_price: Option<Option<Money>> = sqlx::query_scalar(
+            "SELECT price FROM product_variants WHERE id = ? AND product_id = ? FOR UPDATE",
+        )
+        .bind(id)
+        .bind(product_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(old_price) = old_price else {
+            return Ok(0);
+        };
+        let new_price = variant.price;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE product_variants SET
+                sku = ?, options = ?, price = ?, image_url = ?, is_active = ?
+            WHERE id = ? AND product_id = ?
+            "#,
+        )
+        .bind(variant.sku.trim())
+        .bind(Json(variant.options))
+        .bind(variant.price)
+        .bind(variant.image_url)
+        .bind(variant.is_active)
+        .bind(id)
+        .bind(product_id)
+        .execute(&mut *tx)
+        .await?;
+
+        PriceHistoryModel::record_price(
+            &mut tx,
+            product_id,
+            Some(id),
+            old_price,
+            new_price,
+            changed_by,
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(result.rows_affected())
+    }
+
+    // --- 5. DELETE ---
+    pub async fn delete(pool: &Pool<MySql>, product_id: i64, id: i64) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM product_variants WHERE id = ? AND product_id = ?")
+            .bind(id)
+            .bind(product_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
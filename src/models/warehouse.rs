@@ -0,0 +1,121 @@
+// src/models/warehouse.rs
+
+use crate::dtos::warehouse::{NewWarehouseDto, Warehouse, WarehouseAvailability};
+use sqlx::{Executor, MySql, Pool};
+
+pub struct WarehouseModel;
+
+const WAREHOUSE_COLUMNS: &str = "id, code, name, province, city, address, priority, is_active";
+
+impl WarehouseModel {
+    // --- 1. CREATE ---
+    pub async fn insert(
+        pool: &Pool<MySql>,
+        warehouse: NewWarehouseDto,
+    ) -> Result<i64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO warehouses (code, name, province, city, address, priority, is_active)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(warehouse.code.trim())
+        .bind(warehouse.name.trim())
+        .bind(warehouse.province.trim())
+        .bind(warehouse.city.trim())
+        .bind(warehouse.address)
+        .bind(warehouse.priority)
+        .bind(warehouse.is_active)
+        .execute(pool)
+        .await?;
+
+        Ok(result.last_insert_id() as i64)
+    }
+
+    // --- 2. READ ---
+    pub async fn find_all(
+        executor: impl Executor<'_, Database = MySql>,
+    ) -> Result<Vec<Warehouse>, sqlx::Error> {
+        sqlx::query_as::<_, Warehouse>(&format!(
+            "SELECT {} FROM warehouses ORDER BY priority ASC, id ASC",
+            WAREHOUSE_COLUMNS
+        ))
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn find_by_id(
+        executor: impl Executor<'_, Database = MySql>,
+        id: i64,
+    ) -> Result<Option<Warehouse>, sqlx::Error> {
+        sqlx::query_as::<_, Warehouse>(&format!(
+            "SELECT {} FROM warehouses WHERE id = ?",
+            WAREHOUSE_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(executor)
+        .await
+    }
+
+    // Gudang utama: gudang aktif dengan prioritas tertinggi
+    pub async fn find_primary(
+        executor: impl Executor<'_, Database = MySql>,
+    ) -> Result<Option<Warehouse>, sqlx::Error> {
+        sqlx::query_as::<_, Warehouse>(&format!(
+            "SELECT {} FROM warehouses WHERE is_active = TRUE ORDER BY priority ASC, id ASC LIMIT 1",
+            WAREHOUSE_COLUMNS
+        ))
+        .fetch_optional(executor)
+        .await
+    }
+
+    // --- 3. UPDATE ---
+    // `code` tidak ikut diubah karena dipakai sebagai identitas gudang di luar sistem.
+    pub async fn update(
+        pool: &Pool<MySql>,
+        id: i64,
+        warehouse: NewWarehouseDto,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE warehouses SET
+                name = ?, province = ?, city = ?, address = ?, priority = ?, is_active = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(warehouse.name.trim())
+        .bind(warehouse.province.trim())
+        .bind(warehouse.city.trim())
+        .bind(warehouse.address)
+        .bind(warehouse.priority)
+        .bind(warehouse.is_active)
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    // --- 4. KETERSEDIAAN PER GUDANG (DETAIL PRODUK) ---
+    pub async fn find_availability(
+        pool: &Pool<MySql>,
+        product_id: i64,
+    ) -> Result<Vec<WarehouseAvailability>, sqlx::Error> {
+        sqlx::query_as::<_, WarehouseAvailability>(
+            r#"
+            SELECT w.id AS warehouse_id, w.code AS warehouse_code, w.city, ws.variant_id,
+                   ws.stock - ws.reserved_stock AS available_stock
+            FROM warehouse_stock ws
+            JOIN warehouses w ON w.id = ws.warehouse_id
+            LEFT JOIN product_variants v ON v.id = ws.variant_id
+            WHERE ws.product_id = ? AND w.is_active = TRUE
+              AND (ws.variant_id IS NULL OR v.is_active = TRUE)
+              AND ws.stock - ws.reserved_stock > 0
+            ORDER BY w.priority ASC, w.id ASC, ws.variant_id ASC
+            "#,
+        )
+        .bind(product_id)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,282 @@
+// src/models/wishlist.rs
+//
+// Wishlist user. Setiap baris menyimpan harga efektif dan status stok terakhir yang sudah
+// diketahui user; `notify_changes` (dijalankan berkala) membandingkannya dengan kondisi
+// sekarang. Dengan begitu semua sumber perubahan ikut terdeteksi tanpa hook satu per satu:
+// ubah harga, sale yang mulai/berakhir sesuai jadwal, penyesuaian stok, penerimaan PO, dan
+// reservasi order yang dilepas.
+
+use crate::dtos::notification::{NewNotification, NotificationType};
+use crate::dtos::pagination::{PaginatedResponse, PaginationMeta, PaginationParams};
+use crate::dtos::wishlist::{WishlistChange, WishlistDemand, WishlistItem};
+use crate::models::notification::NotificationModel;
+use crate::models::sale::{ACTIVE_SALE_JOIN, FINAL_PRICE_SQL};
+use serde_json::json;
+use sqlx::{MySql, Pool};
+
+pub struct WishlistModel;
+
+/// Produk `p` punya stok yang bisa dibeli. Produk dengan varian aktif dilihat dari stok
+/// variannya, selain itu dari stok produk.
+const IN_STOCK_SQL: &str = "CASE WHEN EXISTS(SELECT 1 FROM product_variants v \
+        WHERE v.product_id = p.id AND v.is_active) \
+    THEN EXISTS(SELECT 1 FROM product_variants v \
+        WHERE v.product_id = p.id AND v.is_active AND v.stock - v.reserved_stock > 0) \
+    ELSE p.stock - p.reserved_stock > 0 END";
+
+/// Jumlah perubahan yang diproses per putaran job.
+const NOTIFY_BATCH_SIZE: i64 = 500;
+
+impl WishlistModel {
+    // --- 1. WISHLIST USER ---
+    // Produk yang diarsipkan tidak ditampilkan, tapi tetap tersimpan jika dipulihkan.
+    pub async fn find_by_user(
+        pool: &Pool<MySql>,
+        user_id: i64,
+        params: PaginationParams,
+    ) -> Result<PaginatedResponse<WishlistItem>, sqlx::Error> {
+        let total: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*)
+            FROM wishlist_items w
+            JOIN products p ON p.id = w.product_id
+            WHERE w.user_id = ? AND p.archived_at IS NULL
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(pool)
+        .await?;
+
+        let items = sqlx::query_as::<_, WishlistItem>(&format!(
+            r#"
+            SELECT p.id AS product_id, p.name, p.slug, p.image_url, p.price,
+                   {} AS final_price, sale.ends_at AS sale_ends_at, {} AS in_stock,
+                   w.created_at AS added_at
+            FROM wishlist_items w
+            JOIN products p ON p.id = w.product_id
+            {}
+            WHERE w.user_id = ? AND p.archived_at IS NULL
+            ORDER BY w.created_at DESC, p.id DESC
+            LIMIT ? OFFSET ?
+            "#,
+            FINAL_PRICE_SQL, IN_STOCK_SQL, ACTIVE_SALE_JOIN
+        ))
+        .bind(user_id)
+        .bind(params.per_page as i64)
+        .bind(params.offset() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(PaginatedResponse {
+            data: items,
+            pagination: PaginationMeta::new(params.page, params.per_page, total as u32),
+        })
+    }
+
+    // --- 2. TAMBAH ---
+    // Harga dan stok saat ini menjadi titik awal notifikasi. `None` jika produk tidak ada atau
+    // diarsipkan, `Some(false)` jika produk sudah ada di wishlist.
+    pub async fn add(
+        pool: &Pool<MySql>,
+        user_id: i64,
+        product_id: i64,
+    ) -> Result<Option<bool>, sqlx::Error> {
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM products WHERE id = ? AND archived_at IS NULL)",
+        )
+        .bind(product_id)
+        .fetch_one(pool)
+        .await?;
+        if !exists {
+            return Ok(None);
+        }
+
+        let result = sqlx::query(&format!(
+            r#"
+            INSERT IGNORE INTO wishlist_items (user_id, product_id, last_price, last_in_stock)
+            SELECT ?, p.id, {}, {}
+            FROM products p
+            {}
+            WHERE p.id = ?
+            "#,
+            FINAL_PRICE_SQL, IN_STOCK_SQL, ACTIVE_SALE_JOIN
+        ))
+        .bind(user_id)
+        .bind(product_id)
+        .execute(pool)
+        .await?;
+        Ok(Some(result.rows_affected() > 0))
+    }
+
+    // --- 3. HAPUS ---
+    pub async fn remove(
+        pool: &Pool<MySql>,
+        user_id: i64,
+        product_id: i64,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM wishlist_items WHERE user_id = ? AND product_id = ?")
+            .bind(user_id)
+            .bind(product_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    // --- 4. PERMINTAAN PER PRODUK (ADMIN) ---
+    // Produk paling banyak di-wishlist dulu.
+    pub async fn find_demand(
+        pool: &Pool<MySql>,
+        params: PaginationParams,
+    ) -> Result<PaginatedResponse<WishlistDemand>, sqlx::Error> {
+        let total: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(DISTINCT w.product_id)
+            FROM wishlist_items w
+            JOIN products p ON p.id = w.product_id
+            WHERE p.archived_at IS NULL
+            "#,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let demand = sqlx::query_as::<_, WishlistDemand>(&format!(
+            r#"
+            SELECT p.id AS product_id, p.name, p.slug, {} AS final_price, {} AS in_stock,
+                   COUNT(*) AS wishlist_count,
+                   CAST(SUM(w.created_at >= NOW() - INTERVAL 30 DAY) AS SIGNED)
+                       AS added_last_30_days
+            FROM wishlist_items w
+            JOIN products p ON p.id = w.product_id
+            {}
+            WHERE p.archived_at IS NULL
+            GROUP BY p.id, sale.id
+            ORDER BY wishlist_count DESC, p.id DESC
+            LIMIT ? OFFSET ?
+            "#,
+            FINAL_PRICE_SQL, IN_STOCK_SQL, ACTIVE_SALE_JOIN
+        ))
+        .bind(params.per_page as i64)
+        .bind(params.offset() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(PaginatedResponse {
+            data: demand,
+            pagination: PaginationMeta::new(params.page, params.per_page, total as u32),
+        })
+    }
+
+    // --- 5. NOTIFIKASI HARGA TURUN & STOK KEMBALI ---
+    // Setiap baris yang berubah diperbarui bersyarat nilai lamanya, di transaksi yang sama
+    // dengan notifikasinya, jadi job yang berjalan bersamaan tidak mengirim notifikasi ganda.
+    // Harga yang naik (mis. sale berakhir) hanya menggeser titik acuan. Mengembalikan jumlah
+    // notifikasi yang dibuat.
+    pub async fn notify_changes(pool: &Pool<MySql>) -> Result<u64, sqlx::Error> {
+        let changes = sqlx::query_as::<_, WishlistChange>(&format!(
+            r#"
+            SELECT w.user_id, w.product_id, p.name, p.slug, w.last_price, w.last_in_stock,
+                   {} AS final_price, {} AS in_stock
+            FROM wishlist_items w
+            JOIN products p ON p.id = w.product_id
+            {}
+            WHERE p.archived_at IS NULL
+            HAVING final_price <> last_price OR in_stock <> last_in_stock
+            LIMIT ?
+            "#,
+            FINAL_PRICE_SQL, IN_STOCK_SQL, ACTIVE_SALE_JOIN
+        ))
+        .bind(NOTIFY_BATCH_SIZE)
+        .fetch_all(pool)
+        .await?;
+
+        let mut sent = 0;
+        for change in changes {
+            let mut tx = pool.begin().await?;
+            let claimed = sqlx::query(
+                r#"
+                UPDATE wishlist_items SET last_price = ?, last_in_stock = ?
+                WHERE user_id = ? AND product_id = ? AND last_price = ? AND last_in_stock = ?
+                "#,
+            )
+            .bind(change.final_price)
+            .bind(change.in_stock)
+            .bind(change.user_id)
+            .bind(change.product_id)
+            .bind(change.last_price)
+            .bind(change.last_in_stock)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected()
+                > 0;
+            if !claimed {
+                continue;
+            }
+
+            if change.final_price < change.last_price {
+                NotificationModel::create(
+                    &mut *tx,
+                    NewNotification {
+                        user_id: change.user_id,
+                        kind: NotificationType::PriceDropped,
+                        title: "Harga produk di wishlist turun".to_string(),
+                        message: format!(
+                            "Harga {} turun dari Rp {} menjadi Rp {}.",
+                            change.name, change.last_price, change.final_price
+                        ),
+                        data: Some(json!({
+                            "product_id": change.product_id,
+                            "product_slug": change.slug,
+                            "old_price": change.last_price,
+                            "new_price": change.final_price,
+                        })),
+                    },
+                )
+                .await?;
+                sent += 1;
+            }
+            if change.in_stock && !change.last_in_stock {
+                NotificationModel::create(
+                    &mut *tx,
+                    NewNotification {
+                        user_id: change.user_id,
+                        kind: NotificationType::BackInStock,
+                        title: "Produk di wishlist tersedia lagi".to_string(),
+                        message: format!("{} sudah tersedia lagi.", change.name),
+                        data: Some(json!({
+                            "product_id": change.product_id,
+                            "product_slug": change.slug,
+                        })),
+                    },
+                )
+                .await?;
+                sent += 1;
+            }
+            tx.commit().await?;
+        }
+        Ok(sent)
+    }
+
+    // --- 6. WISHLIST LENGKAP USER (EKSPOR DATA PRIBADI) ---
+    // Termasuk produk yang sudah diarsipkan.
+    pub async fn find_all_by_user(
+        pool: &Pool<MySql>,
+        user_id: i64,
+    ) -> Result<Vec<WishlistItem>, sqlx::Error> {
+        sqlx::query_as::<_, WishlistItem>(&format!(
+            r#"
+            SELECT p.id AS product_id, p.name, p.slug, p.image_url, p.price,
+                   {} AS final_price, sale.ends_at AS sale_ends_at, {} AS in_stock,
+                   w.created_at AS added_at
+            FROM wishlist_items w
+            JOIN products p ON p.id = w.product_id
+            {}
+            WHERE w.user_id = ?
+            ORDER BY w.created_at DESC, p.id DESC
+            "#,
+            FINAL_PRICE_SQL, IN_STOCK_SQL, ACTIVE_SALE_JOIN
+        ))
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,21 @@
+use crate::AppState;
+use crate::handlers::address::{
+    create::create_address, delete::delete_address, get_all::get_all_addresses,
+    get_by_id::get_address_by_id, set_default::set_default_address, update::update_address,
+};
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
+use std::sync::Arc;
+
+// Buku alamat, di-nest di bawah /user/addresses. Autentikasi lewat extractor AuthUser.
+pub fn address_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_all_addresses))
+        .route("/create", post(create_address))
+        .route("/{id}", get(get_address_by_id))
+        .route("/{id}/update", put(update_address))
+        .route("/{id}/default", put(set_default_address))
+        .route("/{id}/delete", delete(delete_address))
+}
@@ -0,0 +1,28 @@
+use crate::AppState;
+use crate::handlers::api_key::{
+    create::create_api_key, get_all::get_all_api_keys, revoke::revoke_api_key,
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{delete, get, post},
+};
+use std::sync::Arc;
+
+// Semua endpoint API key hanya untuk admin
+pub fn api_key_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route(
+            "/",
+            get(get_all_api_keys).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/create",
+            post(create_api_key).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/{id}/revoke",
+            delete(revoke_api_key).route_layer(from_fn(admin_auth_middleware)),
+        )
+}
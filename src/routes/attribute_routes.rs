@@ -0,0 +1,30 @@
+use crate::AppState;
+use crate::handlers::attribute::{
+    create::create_category_attribute, delete::delete_category_attribute,
+    get_all::get_category_attributes, update::update_category_attribute,
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{delete, get, post, put},
+};
+use std::sync::Arc;
+
+// Skema atribut kategori, di-nest di bawah /categories/{id}/attributes
+pub fn attribute_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_category_attributes))
+        .route(
+            "/create",
+            post(create_category_attribute).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/{attribute_id}/update",
+            put(update_category_attribute).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/{attribute_id}/delete",
+            delete(delete_category_attribute).route_layer(from_fn(admin_auth_middleware)),
+        )
+}
@@ -10,10 +10,13 @@ use std::sync::Arc;
 
 use crate::handlers::category::{
     create::create_category, delete::delete_category, get_all::get_all_categories,
-    get_by_id::get_category_by_id, update::update_category,
+    get_archived::get_archived_categories, get_by_id::get_category_by_id,
+    get_products::get_category_products, get_tree::get_category_tree, restore::restore_category,
+    update::update_category,
 };
 
 use crate::middleware::auth::admin_auth_middleware;
+use crate::routes::attribute_routes::attribute_routes;
 
 pub fn category_routes() -> axum::Router<Arc<AppState>> {
     Router::new()
@@ -22,7 +25,14 @@ pub fn category_routes() -> axum::Router<Arc<AppState>> {
             "/create",
             post(create_category).route_layer(from_fn(admin_auth_middleware)),
         )
+        .route(
+            "/archived",
+            get(get_archived_categories).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route("/tree", get(get_category_tree))
         .route("/{id}", get(get_category_by_id))
+        // Segmen ini berisi slug; nama parameternya harus sama dengan rute `/{id}/...` lain
+        .route("/{id}/products", get(get_category_products))
         .route(
             "/{id}/update",
             put(update_category).route_layer(from_fn(admin_auth_middleware)),
@@ -31,4 +41,9 @@ pub fn category_routes() -> axum::Router<Arc<AppState>> {
             "/{id}/delete",
             delete(delete_category).route_layer(from_fn(admin_auth_middleware)),
         )
+        .route(
+            "/{id}/restore",
+            post(restore_category).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .nest("/{id}/attributes", attribute_routes())
 }
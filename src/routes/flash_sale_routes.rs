@@ -0,0 +1,29 @@
+use crate::AppState;
+use crate::handlers::flash_sale::{
+    create::create_flash_sale, end::end_flash_sale, get_active::get_active_flash_sales,
+    get_all::get_all_flash_sales,
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{get, post},
+};
+use std::sync::Arc;
+
+pub fn flash_sale_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_active_flash_sales))
+        .route(
+            "/all",
+            get(get_all_flash_sales).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/create",
+            post(create_flash_sale).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/{id}/end",
+            post(end_flash_sale).route_layer(from_fn(admin_auth_middleware)),
+        )
+}
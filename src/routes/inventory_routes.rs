@@ -0,0 +1,32 @@
+use crate::AppState;
+use crate::handlers::inventory::{
+    adjust::adjust_stock, get_stock::get_product_stock, low_stock::get_low_stock,
+    low_stock_reports::get_low_stock_reports, movements::get_stock_movements,
+    reorder::set_reorder_threshold, transfer::transfer_stock,
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{get, post, put},
+};
+use std::sync::Arc;
+
+// Stok & buku besar stok (admin), di-nest di bawah /products/{id}/stock
+pub fn inventory_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_product_stock))
+        .route("/adjust", post(adjust_stock))
+        .route("/transfer", post(transfer_stock))
+        .route("/reorder", put(set_reorder_threshold))
+        .route("/movements", get(get_stock_movements))
+        .route_layer(from_fn(admin_auth_middleware))
+}
+
+// Laporan stok lintas produk (admin), di-nest di bawah /inventory
+pub fn stock_report_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/low-stock", get(get_low_stock))
+        .route("/low-stock/reports", get(get_low_stock_reports))
+        .route_layer(from_fn(admin_auth_middleware))
+}
@@ -1,6 +1,22 @@
+pub mod address_routes;
+pub mod api_key_routes;
+pub mod attribute_routes;
 pub mod auth_routes;
 pub mod category_routes;
+pub mod flash_sale_routes;
+pub mod inventory_routes;
+pub mod notification_routes;
 pub mod order_routes;
+pub mod product_image_routes;
 pub mod product_routes;
+pub mod purchase_order_routes;
+pub mod question_routes;
+pub mod region_routes;
+pub mod review_routes;
+pub mod sale_routes;
+pub mod supplier_routes;
 pub mod user_routes;
+pub mod variant_routes;
+pub mod warehouse_routes;
+pub mod wishlist_routes;
 pub mod chatbot_routes;
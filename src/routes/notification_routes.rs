@@ -0,0 +1,18 @@
+use crate::AppState;
+use crate::handlers::notification::{
+    get_all::get_notifications,
+    mark_read::{mark_all_notifications_read, mark_notification_read},
+};
+use axum::{
+    Router,
+    routing::{get, post},
+};
+use std::sync::Arc;
+
+// Notifikasi user, di-nest di bawah /user/notifications. Autentikasi lewat extractor AuthUser.
+pub fn notification_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_notifications))
+        .route("/read-all", post(mark_all_notifications_read))
+        .route("/{id}/read", post(mark_notification_read))
+}
@@ -8,7 +8,7 @@ use std::sync::Arc;
 
 use crate::handlers::order::{
     checkout::checkout, process_payment::process_payment, query_midtrans::query_midtrans_status,
-    status_db::get_order_status_db,
+    refunds::get_refund_required_orders, status_db::get_order_status_db,
 };
 
 use crate::middleware::auth::{admin_auth_middleware, auth_user_middleware};
@@ -19,6 +19,10 @@ pub fn order_routes() -> axum::Router<Arc<AppState>> {
             "/checkout",
             post(checkout).route_layer(from_fn(auth_user_middleware)),
         )
+        .route(
+            "/refunds",
+            get(get_refund_required_orders).route_layer(from_fn(admin_auth_middleware)),
+        )
         .route(
             "/{id}/payment",
             put(process_payment).route_layer(from_fn(admin_auth_middleware)),
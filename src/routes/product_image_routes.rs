@@ -0,0 +1,33 @@
+use crate::AppState;
+use crate::handlers::product_image::{
+    MAX_UPLOAD_BYTES, delete::delete_product_image, get_all::get_product_images,
+    reorder::reorder_product_images, upload::upload_product_images,
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    extract::DefaultBodyLimit,
+    middleware::from_fn,
+    routing::{delete, get, post, put},
+};
+use std::sync::Arc;
+
+// Galeri gambar produk, di-nest di bawah /products/{id}/images
+pub fn product_image_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_product_images))
+        .route(
+            "/upload",
+            post(upload_product_images)
+                .layer(DefaultBodyLimit::max(MAX_UPLOAD_BYTES))
+                .route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/order",
+            put(reorder_product_images).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/{image_id}/delete",
+            delete(delete_product_image).route_layer(from_fn(admin_auth_middleware)),
+        )
+}
@@ -1,11 +1,22 @@
 use crate::AppState;
 use crate::handlers::product::{
-    create::create_product, delete::delete_product, get_all::get_all_products,
-    get_by_id::find_product_by_id, update::update_product,
+    create::create_product, delete::delete_product, export::export_products,
+    get_all::get_all_products, get_archived::get_archived_products, get_by_id::find_product_by_id,
+    get_by_slug::find_product_by_slug, import::import_products, restore::restore_product,
+    search::search_products, suggest::suggest_products, update::update_product,
 };
+use crate::handlers::sale::price_history::get_price_history;
 use crate::middleware::auth::admin_auth_middleware;
+use crate::routes::inventory_routes::inventory_routes;
+use crate::routes::product_image_routes::product_image_routes;
+use crate::routes::question_routes::question_routes;
+use crate::routes::review_routes::review_routes;
+use crate::routes::sale_routes::sale_routes;
+use crate::routes::variant_routes::variant_routes;
+use crate::utils::product_transfer::MAX_IMPORT_BYTES;
 use axum::{
     Router,
+    extract::DefaultBodyLimit,
     middleware::from_fn,
     routing::{delete, get, post, put},
 };
@@ -19,6 +30,23 @@ pub fn product_routes() -> Router<Arc<AppState>> {
             "/create",
             post(create_product).route_layer(from_fn(admin_auth_middleware)),
         )
+        .route(
+            "/archived",
+            get(get_archived_products).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/import",
+            post(import_products)
+                .layer(DefaultBodyLimit::max(MAX_IMPORT_BYTES))
+                .route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/export",
+            get(export_products).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route("/search", get(search_products))
+        .route("/suggest", get(suggest_products))
+        .route("/slug/{slug}", get(find_product_by_slug))
         .route("/{id}", get(find_product_by_id))
         .route(
             "/{id}/update",
@@ -28,4 +56,18 @@ pub fn product_routes() -> Router<Arc<AppState>> {
             "/{id}/delete",
             delete(delete_product).route_layer(from_fn(admin_auth_middleware)),
         )
+        .route(
+            "/{id}/restore",
+            post(restore_product).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/{id}/price-history",
+            get(get_price_history).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .nest("/{id}/variants", variant_routes())
+        .nest("/{id}/stock", inventory_routes())
+        .nest("/{id}/images", product_image_routes())
+        .nest("/{id}/sales", sale_routes())
+        .nest("/{id}/reviews", review_routes())
+        .nest("/{id}/questions", question_routes())
 }
@@ -0,0 +1,24 @@
+use crate::AppState;
+use crate::handlers::purchase_order::{
+    cancel::cancel_purchase_order, create::create_purchase_order, get_all::get_all_purchase_orders,
+    get_by_id::get_purchase_order, order::order_purchase_order, receive::receive_purchase_order,
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{get, post},
+};
+use std::sync::Arc;
+
+// Purchase order ke supplier (admin): DRAFT -> ORDERED -> RECEIVED / CANCELLED
+pub fn purchase_order_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_all_purchase_orders))
+        .route("/create", post(create_purchase_order))
+        .route("/{id}", get(get_purchase_order))
+        .route("/{id}/order", post(order_purchase_order))
+        .route("/{id}/receive", post(receive_purchase_order))
+        .route("/{id}/cancel", post(cancel_purchase_order))
+        .route_layer(from_fn(admin_auth_middleware))
+}
@@ -0,0 +1,36 @@
+use crate::AppState;
+use crate::handlers::question::{
+    answer::answer_question,
+    create::create_question,
+    get_all::get_product_questions,
+    get_queue::get_question_queue,
+    moderate::{moderate_answer, moderate_question},
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{get, post},
+};
+use std::sync::Arc;
+
+// Tanya jawab produk, di-nest di bawah /products/{id}/questions. Pertanyaan diajukan user
+// yang login (extractor AuthUser), jawaban hanya dari admin.
+pub fn question_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_product_questions))
+        .route("/create", post(create_question))
+        .route(
+            "/{question_id}/answers/create",
+            post(answer_question).route_layer(from_fn(admin_auth_middleware)),
+        )
+}
+
+// Antrean dan moderasi tanya jawab (admin), di-nest di bawah /questions
+pub fn question_moderation_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_question_queue))
+        .route("/{id}/moderate", post(moderate_question))
+        .route("/answers/{id}/moderate", post(moderate_answer))
+        .route_layer(from_fn(admin_auth_middleware))
+}
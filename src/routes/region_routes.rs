@@ -0,0 +1,16 @@
+use crate::AppState;
+use crate::handlers::region::{
+    districts::get_districts, provinces::get_provinces, regencies::get_regencies,
+    villages::get_villages,
+};
+use axum::{Router, routing::get};
+use std::sync::Arc;
+
+// Data wilayah bersifat publik, dipakai untuk dropdown alamat berjenjang.
+pub fn region_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/provinces", get(get_provinces))
+        .route("/provinces/{id}/regencies", get(get_regencies))
+        .route("/regencies/{id}/districts", get(get_districts))
+        .route("/districts/{id}/villages", get(get_villages))
+}
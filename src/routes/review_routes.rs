@@ -0,0 +1,29 @@
+use crate::AppState;
+use crate::handlers::review::{
+    MAX_REVIEW_UPLOAD_BYTES, create::create_review, get_all::get_product_reviews,
+    get_queue::get_review_queue, moderate::moderate_review,
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    extract::DefaultBodyLimit,
+    middleware::from_fn,
+    routing::{get, post},
+};
+use std::sync::Arc;
+
+// Ulasan produk, di-nest di bawah /products/{id}/reviews. Autentikasi create lewat extractor AuthUser.
+pub fn review_routes() -> Router<Arc<AppState>> {
+    Router::new().route("/", get(get_product_reviews)).route(
+        "/create",
+        post(create_review).layer(DefaultBodyLimit::max(MAX_REVIEW_UPLOAD_BYTES)),
+    )
+}
+
+// Moderasi ulasan (admin), di-nest di bawah /reviews
+pub fn review_moderation_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_review_queue))
+        .route("/{id}/moderate", post(moderate_review))
+        .route_layer(from_fn(admin_auth_middleware))
+}
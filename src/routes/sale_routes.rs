@@ -0,0 +1,20 @@
+use crate::AppState;
+use crate::handlers::sale::{
+    create::create_product_sale, end::end_product_sale, get_all::get_product_sales,
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{get, post},
+};
+use std::sync::Arc;
+
+// Jadwal harga sale (admin), di-nest di bawah /products/{id}/sales
+pub fn sale_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_product_sales))
+        .route("/create", post(create_product_sale))
+        .route("/{sale_id}/end", post(end_product_sale))
+        .route_layer(from_fn(admin_auth_middleware))
+}
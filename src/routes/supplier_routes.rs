@@ -0,0 +1,19 @@
+use crate::AppState;
+use crate::handlers::supplier::{
+    create::create_supplier, get_all::get_all_suppliers, update::update_supplier,
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{get, post, put},
+};
+use std::sync::Arc;
+
+pub fn supplier_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_all_suppliers))
+        .route("/create", post(create_supplier))
+        .route("/{id}/update", put(update_supplier))
+        .route_layer(from_fn(admin_auth_middleware))
+}
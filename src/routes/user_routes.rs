@@ -1,8 +1,12 @@
 use crate::AppState;
+use crate::handlers::user::account::{delete_account, export_user_data};
 use crate::handlers::user::profile::{get_profile, update_profile};
+use crate::routes::address_routes::address_routes;
+use crate::routes::notification_routes::notification_routes;
+use crate::routes::wishlist_routes::wishlist_routes;
 use axum::{
     Router,
-    routing::{get, put},
+    routing::{delete, get, put},
 };
 use std::sync::Arc;
 
@@ -10,4 +14,9 @@ pub fn user_routes() -> Router<Arc<AppState>> {
     Router::new()
         .route("/profile", get(get_profile))
         .route("/profile", put(update_profile))
+        .route("/export", get(export_user_data))
+        .route("/delete", delete(delete_account))
+        .nest("/addresses", address_routes())
+        .nest("/notifications", notification_routes())
+        .nest("/wishlist", wishlist_routes())
 }
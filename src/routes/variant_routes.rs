@@ -0,0 +1,30 @@
+use crate::AppState;
+use crate::handlers::variant::{
+    create::create_variant, delete::delete_variant, get_all::get_product_variants,
+    update::update_variant,
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{delete, get, post, put},
+};
+use std::sync::Arc;
+
+// Varian produk, di-nest di bawah /products/{id}/variants
+pub fn variant_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_product_variants))
+        .route(
+            "/create",
+            post(create_variant).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/{variant_id}/update",
+            put(update_variant).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/{variant_id}/delete",
+            delete(delete_variant).route_layer(from_fn(admin_auth_middleware)),
+        )
+}
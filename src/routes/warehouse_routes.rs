@@ -0,0 +1,24 @@
+use crate::AppState;
+use crate::handlers::warehouse::{
+    create::create_warehouse, get_all::get_all_warehouses, update::update_warehouse,
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{get, post, put},
+};
+use std::sync::Arc;
+
+pub fn warehouse_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_all_warehouses))
+        .route(
+            "/create",
+            post(create_warehouse).route_layer(from_fn(admin_auth_middleware)),
+        )
+        .route(
+            "/{id}/update",
+            put(update_warehouse).route_layer(from_fn(admin_auth_middleware)),
+        )
+}
@@ -0,0 +1,27 @@
+use crate::AppState;
+use crate::handlers::wishlist::{
+    create::add_to_wishlist, delete::remove_from_wishlist, get_all::get_wishlist,
+    get_demand::get_wishlist_demand,
+};
+use crate::middleware::auth::admin_auth_middleware;
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{delete, get, post},
+};
+use std::sync::Arc;
+
+// Wishlist, di-nest di bawah /user/wishlist. Autentikasi lewat extractor AuthUser.
+pub fn wishlist_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_wishlist))
+        .route("/create", post(add_to_wishlist))
+        .route("/{product_id}/delete", delete(remove_from_wishlist))
+}
+
+// Jumlah wishlist per produk (admin), di-nest di bawah /wishlists
+pub fn wishlist_demand_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_wishlist_demand))
+        .route_layer(from_fn(admin_auth_middleware))
+}
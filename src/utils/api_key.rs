@@ -0,0 +1,198 @@
+use axum::http::Method;
+use rand::{Rng, distributions::Alphanumeric};
+use sha2::{Digest, Sha256};
+
+/// Prefix tetap untuk semua API key, memudahkan membedakan API key dari JWT.
+pub const API_KEY_PREFIX: &str = "bk_";
+
+/// Daftar scope yang boleh diberikan ke API key.
+/// Format `<resource>:<read|write>`; `*` berarti akses penuh sesuai role pemilik.
+pub const ALLOWED_SCOPES: &[&str] = &[
+    "*",
+    "products:read",
+    "products:write",
+    "categories:read",
+    "categories:write",
+    "orders:read",
+    "orders:write",
+    "user:read",
+    "user:write",
+    "warehouses:read",
+    "warehouses:write",
+    "inventory:read",
+    "inventory:write",
+    "suppliers:read",
+    "suppliers:write",
+    "purchase-orders:read",
+    "purchase-orders:write",
+    "flash-sales:read",
+    "flash-sales:write",
+    "reviews:read",
+    "reviews:write",
+    "questions:read",
+    "questions:write",
+    "wishlists:read",
+    "wishlists:write",
+];
+
+/// Segmen path yang tidak boleh diakses lewat API key, bahkan dengan scope `*`: login/token
+/// dan pengelolaan API key itu sendiri.
+const API_KEY_FORBIDDEN: &[&str] = &["auth", "api-keys"];
+
+/// Endpoint akun yang juga hanya boleh lewat sesi login: hapus akun dan ekspor data pribadi.
+/// Key yang bocor tidak boleh bisa menghapus akun (akun Google tanpa konfirmasi password)
+/// atau menarik seluruh data pribadi pemiliknya.
+const API_KEY_FORBIDDEN_PATHS: &[&str] = &["/user/delete", "/user/export"];
+
+/// Membuat API key baru. Mengembalikan `(full_key, prefix)`.
+/// Bentuk key: `bk_<prefix 8 char>_<secret 32 char>`.
+pub fn generate_api_key() -> (String, String) {
+    let mut rng = rand::thread_rng();
+    let prefix: String = (&mut rng)
+        .sample_iter(&Alphanumeric)
+        .take(8)
+        .map(char::from)
+        .collect();
+    let secret: String = (&mut rng)
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect();
+
+    (format!("{}{}_{}", API_KEY_PREFIX, prefix, secret), prefix)
+}
+
+/// Hash SHA-256 (hex) dari API key. Key sudah acak dan panjang,
+/// jadi hash cepat cukup dan tidak membebani setiap request seperti bcrypt.
+pub fn hash_api_key(key: &str) -> String {
+    Sha256::digest(key.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Ambil bagian prefix dari API key lengkap, `None` jika format tidak dikenal.
+pub fn parse_prefix(key: &str) -> Option<&str> {
+    let rest = key.strip_prefix(API_KEY_PREFIX)?;
+    let (prefix, secret) = rest.split_once('_')?;
+    if prefix.is_empty() || secret.is_empty() {
+        return None;
+    }
+    Some(prefix)
+}
+
+/// Menentukan scope yang dibutuhkan untuk sebuah request: segmen path pertama sebagai
+/// resource dan method HTTP sebagai akses, mis. `GET /warehouses/1` butuh `warehouses:read`.
+/// `None` berarti endpoint tersebut tidak boleh diakses lewat API key (`/auth`, `/api-keys`,
+/// hapus akun, dan ekspor data pribadi).
+pub fn required_scope(method: &Method, path: &str) -> Option<String> {
+    let normalized = format!("/{}", path.trim_matches('/'));
+    if API_KEY_FORBIDDEN_PATHS.contains(&normalized.as_str()) {
+        return None;
+    }
+
+    let resource = path.trim_start_matches('/').split('/').next().unwrap_or("");
+    if resource.is_empty() || API_KEY_FORBIDDEN.contains(&resource) {
+        return None;
+    }
+
+    let access = if method == Method::GET || method == Method::HEAD {
+        "read"
+    } else {
+        "write"
+    };
+    Some(format!("{}:{}", resource, access))
+}
+
+/// Cek apakah daftar scope milik key mengizinkan scope yang dibutuhkan.
+/// Scope `write` juga mencakup `read` untuk resource yang sama.
+pub fn scope_allows(scopes: &[String], required: &str) -> bool {
+    scopes.iter().any(|s| {
+        if s == "*" || s == required {
+            return true;
+        }
+        match (s.strip_suffix(":write"), required.strip_suffix(":read")) {
+            (Some(granted), Some(needed)) => granted == needed,
+            _ => false,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scopes(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn required_scope_uses_first_segment_and_method() {
+        assert_eq!(
+            required_scope(&Method::GET, "/warehouses/1").as_deref(),
+            Some("warehouses:read")
+        );
+        assert_eq!(
+            required_scope(&Method::HEAD, "/products").as_deref(),
+            Some("products:read")
+        );
+        assert_eq!(
+            required_scope(&Method::POST, "/purchase-orders/3/receive").as_deref(),
+            Some("purchase-orders:write")
+        );
+        assert_eq!(
+            required_scope(&Method::DELETE, "/user/wishlist/5/delete").as_deref(),
+            Some("user:write")
+        );
+    }
+
+    #[test]
+    fn session_only_routes_reject_api_keys() {
+        assert_eq!(required_scope(&Method::POST, "/auth/login"), None);
+        assert_eq!(required_scope(&Method::GET, "/api-keys"), None);
+        assert_eq!(required_scope(&Method::GET, "/"), None);
+        assert_eq!(required_scope(&Method::DELETE, "/user/delete"), None);
+        assert_eq!(required_scope(&Method::GET, "/user/export/"), None);
+        assert_eq!(
+            required_scope(&Method::GET, "/user/profile").as_deref(),
+            Some("user:read")
+        );
+    }
+
+    #[test]
+    fn wildcard_allows_every_resource() {
+        let all = scopes(&["*"]);
+        assert!(scope_allows(&all, "flash-sales:write"));
+        assert!(scope_allows(&all, "questions:read"));
+        assert!(scope_allows(&all, "anything-new:write"));
+    }
+
+    #[test]
+    fn write_implies_read_for_the_same_resource_only() {
+        let granted = scopes(&["inventory:write", "reviews:read"]);
+        assert!(scope_allows(&granted, "inventory:write"));
+        assert!(scope_allows(&granted, "inventory:read"));
+        assert!(scope_allows(&granted, "reviews:read"));
+        assert!(!scope_allows(&granted, "reviews:write"));
+        assert!(!scope_allows(&granted, "warehouses:read"));
+        assert!(!scope_allows(&[], "products:read"));
+    }
+
+    #[test]
+    fn allowed_scopes_are_resource_and_access() {
+        for scope in ALLOWED_SCOPES.iter().filter(|s| **s != "*") {
+            let (resource, access) = scope.split_once(':').unwrap();
+            assert!(!resource.is_empty() && !API_KEY_FORBIDDEN.contains(&resource));
+            assert!(access == "read" || access == "write", "{}", scope);
+        }
+    }
+
+    #[test]
+    fn generated_key_round_trips_prefix() {
+        let (key, prefix) = generate_api_key();
+        assert_eq!(parse_prefix(&key), Some(prefix.as_str()));
+        assert_eq!(hash_api_key(&key).len(), 64);
+        assert_eq!(parse_prefix("bk_only"), None);
+        assert_eq!(parse_prefix("sk_abc_def"), None);
+    }
+}
@@ -41,6 +41,15 @@ impl<T: Serialize> ApiResponse<T> {
         }
     }
 
+    // error_data - error yang membawa rincian, mis. item checkout yang stoknya kurang
+    pub fn error_data(msg: String, data: T) -> Self {
+        Self {
+            status: "error".to_string(),
+            message: msg,
+            data: Some(data),
+        }
+    }
+
     // error (generic error helper)
     fn error_base(msg: &str) -> ApiResponse<()> {
         ApiResponse {
@@ -0,0 +1,48 @@
+use axum_extra::extract::cookie::{Cookie, SameSite};
+use time::Duration;
+
+// Semua pengaturan atribut cookie (Secure, SameSite, Path) dikumpulkan di sini
+// supaya login, logout, Google callback, dan CSRF memakai aturan yang sama.
+
+/// `SECURE_COOKIE=true` untuk mengaktifkan flag Secure (wajib di production/HTTPS).
+pub fn secure_cookie() -> bool {
+    std::env::var("SECURE_COOKIE").unwrap_or_else(|_| "false".into()) == "true"
+}
+
+/// `COOKIE_SAMESITE` = `strict` | `lax` | `none` (default `lax`).
+pub fn same_site() -> SameSite {
+    match std::env::var("COOKIE_SAMESITE")
+        .unwrap_or_default()
+        .to_lowercase()
+        .as_str()
+    {
+        "strict" => SameSite::Strict,
+        "none" => SameSite::None,
+        _ => SameSite::Lax,
+    }
+}
+
+/// Membuat cookie dengan atribut standar aplikasi.
+/// Browser menolak `SameSite=None` tanpa `Secure`, jadi kombinasi itu selalu dipaksa Secure.
+pub fn build_cookie(
+    name: &'static str,
+    value: String,
+    http_only: bool,
+    max_age: Duration,
+) -> Cookie<'static> {
+    let same_site = same_site();
+    let secure = secure_cookie() || same_site == SameSite::None;
+
+    Cookie::build((name, value))
+        .http_only(http_only)
+        .secure(secure)
+        .same_site(same_site)
+        .path("/")
+        .max_age(max_age)
+        .build()
+}
+
+/// Cookie kosong dengan max-age 0 untuk menghapus cookie di browser.
+pub fn removal_cookie(name: &'static str, http_only: bool) -> Cookie<'static> {
+    build_cookie(name, String::new(), http_only, Duration::seconds(0))
+}
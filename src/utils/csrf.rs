@@ -0,0 +1,50 @@
+use axum::http::{HeaderMap, Method, StatusCode};
+use axum_extra::extract::cookie::CookieJar;
+use rand::{Rng, distributions::Alphanumeric};
+
+/// Cookie yang berisi token CSRF. Sengaja tidak httpOnly agar frontend bisa membacanya
+/// dan mengirim ulang lewat header (pola double-submit cookie).
+pub const CSRF_COOKIE: &str = "csrf_token";
+pub const CSRF_HEADER: &str = "x-csrf-token";
+
+pub fn generate_csrf_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect()
+}
+
+/// Method yang tidak mengubah data tidak perlu dicek.
+pub fn requires_csrf(method: &Method) -> bool {
+    !matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+/// Bandingkan header `X-CSRF-Token` dengan cookie `csrf_token`.
+/// Dipanggil hanya jika kredensial berasal dari cookie; request dengan Bearer/API key
+/// tidak bisa dipalsukan lintas situs sehingga tidak perlu dicek.
+pub fn verify_csrf(headers: &HeaderMap) -> Result<(), (StatusCode, String)> {
+    let jar = CookieJar::from_headers(headers);
+    let cookie_token = jar.get(CSRF_COOKIE).map(|c| c.value().to_string());
+    let header_token = headers
+        .get(CSRF_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_string());
+
+    match (cookie_token, header_token) {
+        (Some(c), Some(h)) if !c.is_empty() && constant_time_eq(c.as_bytes(), h.as_bytes()) => {
+            Ok(())
+        }
+        _ => Err((
+            StatusCode::FORBIDDEN,
+            "CSRF token tidak valid atau tidak ditemukan".to_string(),
+        )),
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
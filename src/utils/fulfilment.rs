@@ -0,0 +1,199 @@
+// src/utils/fulfilment.rs
+//
+// Pemilihan gudang pengirim saat checkout: gudang terdekat yang bisa memenuhi seluruh
+// order, atau jika tidak ada, setiap item diambil dari gudang terdekat yang masih punya stok.
+
+use crate::dtos::warehouse::Warehouse;
+use crate::models::region::normalize_name;
+use std::collections::HashMap;
+
+/// Urutkan gudang dari yang paling dekat ke alamat tujuan: kota sama, lalu provinsi sama,
+/// lalu sisanya; gudang dengan jarak setara diurutkan berdasarkan `priority`.
+pub fn rank_warehouses(
+    warehouses: &[Warehouse],
+    province: Option<&str>,
+    city: Option<&str>,
+) -> Vec<i64> {
+    let province = province.map(normalize_name);
+    let city = city.map(normalize_name);
+
+    let distance = |w: &Warehouse| {
+        if city.as_deref() == Some(normalize_name(&w.city).as_str()) {
+            0
+        } else if province.as_deref() == Some(normalize_name(&w.province).as_str()) {
+            1
+        } else {
+            2
+        }
+    };
+
+    let mut ranked: Vec<&Warehouse> = warehouses.iter().filter(|w| w.is_active).collect();
+    ranked.sort_by_key(|w| (distance(w), w.priority, w.id));
+    ranked.into_iter().map(|w| w.id).collect()
+}
+
+/// Kebutuhan satu item order dan stok tersedia per gudang.
+pub struct LineDemand {
+    pub quantity: i32,
+    pub available: HashMap<i64, i32>,
+}
+
+impl LineDemand {
+    pub fn total_available(&self, ranked: &[i64]) -> i32 {
+        ranked
+            .iter()
+            .map(|id| self.available.get(id).copied().unwrap_or(0).max(0))
+            .sum()
+    }
+}
+
+/// Alokasi (warehouse_id, jumlah) untuk setiap item, dengan urutan yang sama seperti `lines`.
+/// Semua item diasumsikan sudah lolos cek `total_available`.
+pub fn plan_fulfilment(ranked: &[i64], lines: &[LineDemand]) -> Vec<Vec<(i64, i32)>> {
+    // Satu gudang untuk seluruh order jika memungkinkan (satu paket, satu ongkir)
+    if let Some(&warehouse_id) = ranked.iter().find(|id| {
+        lines
+            .iter()
+            .all(|line| line.available.get(id).copied().unwrap_or(0) >= line.quantity)
+    }) {
+        return lines
+            .iter()
+            .map(|line| vec![(warehouse_id, line.quantity)])
+            .collect();
+    }
+
+    // Split: tiap item diambil dari gudang terdekat lebih dulu
+    lines
+        .iter()
+        .map(|line| {
+            let mut remaining = line.quantity;
+            let mut allocations = Vec::new();
+            for &warehouse_id in ranked {
+                if remaining == 0 {
+                    break;
+                }
+                let available = line.available.get(&warehouse_id).copied().unwrap_or(0);
+                let take = available.min(remaining);
+                if take > 0 {
+                    allocations.push((warehouse_id, take));
+                    remaining -= take;
+                }
+            }
+            allocations
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JKT: i64 = 1;
+    const SBY: i64 = 2;
+    const BDG: i64 = 3;
+
+    fn warehouse(id: i64, province: &str, city: &str, priority: i32) -> Warehouse {
+        Warehouse {
+            id,
+            code: format!("W{}", id),
+            name: format!("Gudang {}", city),
+            province: province.to_string(),
+            city: city.to_string(),
+            address: None,
+            priority,
+            is_active: true,
+        }
+    }
+
+    fn warehouses() -> Vec<Warehouse> {
+        vec![
+            warehouse(JKT, "DKI Jakarta", "Jakarta Utara", 0),
+            warehouse(SBY, "Jawa Timur", "Surabaya", 1),
+            warehouse(BDG, "Jawa Barat", "Bandung", 2),
+        ]
+    }
+
+    fn line(quantity: i32, available: &[(i64, i32)]) -> LineDemand {
+        LineDemand {
+            quantity,
+            available: available.iter().copied().collect(),
+        }
+    }
+
+    #[test]
+    fn ranks_same_city_then_province_then_priority() {
+        let all = warehouses();
+        assert_eq!(
+            rank_warehouses(&all, Some("Jawa Timur"), Some("Kota Surabaya")),
+            vec![SBY, JKT, BDG]
+        );
+        assert_eq!(
+            rank_warehouses(&all, Some("Jawa Barat"), Some("Kabupaten Bogor")),
+            vec![BDG, JKT, SBY]
+        );
+        assert_eq!(rank_warehouses(&all, None, None), vec![JKT, SBY, BDG]);
+    }
+
+    #[test]
+    fn tie_on_priority_falls_back_to_id() {
+        let all = vec![
+            warehouse(7, "Jawa Timur", "Malang", 1),
+            warehouse(4, "Jawa Timur", "Sidoarjo", 1),
+            warehouse(5, "Jawa Timur", "Gresik", 0),
+        ];
+        assert_eq!(
+            rank_warehouses(&all, Some("Jawa Timur"), Some("Surabaya")),
+            vec![5, 4, 7]
+        );
+    }
+
+    #[test]
+    fn skips_inactive_warehouses() {
+        let mut all = warehouses();
+        all[1].is_active = false;
+        assert_eq!(
+            rank_warehouses(&all, Some("Jawa Timur"), Some("Surabaya")),
+            vec![JKT, BDG]
+        );
+    }
+
+    #[test]
+    fn whole_order_from_nearest_warehouse_that_fits() {
+        let ranked = [SBY, JKT, BDG];
+        let lines = [line(2, &[(SBY, 5), (JKT, 5)]), line(1, &[(SBY, 1)])];
+        assert_eq!(
+            plan_fulfilment(&ranked, &lines),
+            vec![vec![(SBY, 2)], vec![(SBY, 1)]]
+        );
+    }
+
+    #[test]
+    fn prefers_single_farther_warehouse_over_split() {
+        // Gudang terdekat tidak punya item kedua, gudang berikutnya punya semuanya
+        let ranked = [SBY, JKT, BDG];
+        let lines = [line(2, &[(SBY, 5), (JKT, 2)]), line(3, &[(JKT, 3)])];
+        assert_eq!(
+            plan_fulfilment(&ranked, &lines),
+            vec![vec![(JKT, 2)], vec![(JKT, 3)]]
+        );
+    }
+
+    #[test]
+    fn splits_across_warehouses_nearest_first() {
+        let ranked = [SBY, JKT, BDG];
+        let lines = [
+            line(5, &[(SBY, 3), (JKT, 1), (BDG, 4)]),
+            line(2, &[(JKT, 2)]),
+        ];
+        assert_eq!(
+            plan_fulfilment(&ranked, &lines),
+            vec![vec![(SBY, 3), (JKT, 1), (BDG, 1)], vec![(JKT, 2)]]
+        );
+    }
+
+    #[test]
+    fn total_available_ignores_unranked_and_negative_stock() {
+        let demand = line(1, &[(SBY, 3), (JKT, -2), (99, 10)]);
+        assert_eq!(demand.total_available(&[SBY, JKT, BDG]), 3);
+    }
+}
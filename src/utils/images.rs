@@ -0,0 +1,95 @@
+// src/utils/images.rs
+//
+// Validasi dan resize gambar yang diunggah (galeri produk oleh admin, foto ulasan oleh user).
+
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader};
+use std::io::Cursor;
+
+/// Sisi terpanjang (px) untuk ukuran turunan; gambar yang lebih kecil tidak diperbesar.
+pub const MEDIUM_SIZE: u32 = 800;
+pub const THUMBNAIL_SIZE: u32 = 200;
+
+const JPEG_QUALITY: u8 = 85;
+
+/// Hasil olahan satu unggahan: file asli (apa adanya, atau di-encode ulang untuk unggahan
+/// user) plus medium & thumbnail dalam JPEG.
+pub struct ProcessedImage {
+    pub extension: &'static str,
+    pub content_type: &'static str,
+    pub original: Vec<u8>,
+    pub medium: Vec<u8>,
+    pub thumbnail: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Decode dan resize; CPU-bound, jadi panggil lewat `spawn_blocking`.
+pub fn process(bytes: Vec<u8>) -> Result<ProcessedImage, String> {
+    let (extension, content_type, decoded) = decode(&bytes)?;
+    Ok(ProcessedImage {
+        extension,
+        content_type,
+        width: decoded.width(),
+        height: decoded.height(),
+        medium: resize_jpeg(&decoded, MEDIUM_SIZE)?,
+        thumbnail: resize_jpeg(&decoded, THUMBNAIL_SIZE)?,
+        original: bytes,
+    })
+}
+
+/// Seperti `process`, tetapi file asli di-encode ulang ke JPEG ukuran penuh sehingga
+/// metadata (EXIF: lokasi GPS, perangkat, dst.) tidak ikut tersimpan. Untuk unggahan user
+/// yang ditampilkan publik.
+pub fn process_stripped(bytes: Vec<u8>) -> Result<ProcessedImage, String> {
+    let (_, _, decoded) = decode(&bytes)?;
+    Ok(ProcessedImage {
+        extension: "jpg",
+        content_type: "image/jpeg",
+        width: decoded.width(),
+        height: decoded.height(),
+        medium: resize_jpeg(&decoded, MEDIUM_SIZE)?,
+        thumbnail: resize_jpeg(&decoded, THUMBNAIL_SIZE)?,
+        original: resize_jpeg(&decoded, u32::MAX)?,
+    })
+}
+
+/// Cek format lalu decode. Orientasi EXIF diterapkan ke piksel, jadi hasil turunan tetap
+/// tegak walau metadatanya tidak ikut disalin.
+fn decode(bytes: &[u8]) -> Result<(&'static str, &'static str, DynamicImage), String> {
+    let format =
+        image::guess_format(bytes).map_err(|_| "File bukan gambar yang dikenali".to_string())?;
+    let (extension, content_type) = match format {
+        ImageFormat::Jpeg => ("jpg", "image/jpeg"),
+        ImageFormat::Png => ("png", "image/png"),
+        ImageFormat::WebP => ("webp", "image/webp"),
+        _ => return Err("Format gambar harus JPEG, PNG, atau WebP".to_string()),
+    };
+
+    let unreadable = |e: image::ImageError| format!("Gambar tidak bisa dibaca: {}", e);
+    let mut decoder = ImageReader::with_format(Cursor::new(bytes), format)
+        .into_decoder()
+        .map_err(unreadable)?;
+    let orientation = decoder.orientation().map_err(unreadable)?;
+    let mut decoded = DynamicImage::from_decoder(decoder).map_err(unreadable)?;
+    decoded.apply_orientation(orientation);
+
+    Ok((extension, content_type, decoded))
+}
+
+fn resize_jpeg(source: &DynamicImage, max_side: u32) -> Result<Vec<u8>, String> {
+    let resized = if source.width() > max_side || source.height() > max_side {
+        source.resize(max_side, max_side, FilterType::Lanczos3)
+    } else {
+        source.clone()
+    };
+
+    // JPEG tidak punya kanal alpha
+    let rgb = DynamicImage::ImageRgb8(resized.to_rgb8());
+    let mut buffer = Vec::new();
+    JpegEncoder::new_with_quality(&mut buffer, JPEG_QUALITY)
+        .encode_image(&rgb)
+        .map_err(|e| format!("Gagal mengompres gambar: {}", e))?;
+    Ok(buffer)
+}
@@ -1,4 +1,5 @@
 use crate::AppState;
+use crate::utils::money::Money;
 use reqwest::Client;
 use serde_json::{Value, json};
 
@@ -6,14 +7,14 @@ use serde_json::{Value, json};
 pub async fn create_midtrans_transaction(
     state: &AppState,
     order_id: &str,
-    gross_amount: i64,
+    gross_amount: Money,
 ) -> Result<Value, String> {
     let client = Client::new();
 
     let body = json!({
         "transaction_details": {
             "order_id": order_id,
-            "gross_amount": gross_amount
+            "gross_amount": gross_amount.rupiah()
         },
         "credit_card": {
             "secure": true
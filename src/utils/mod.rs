@@ -1,7 +1,19 @@
 // Deklarasi sub-modul
+pub mod api_key;
 pub mod api_response;
+pub mod cookie;
+pub mod csrf;
+pub mod fulfilment;
+pub mod images;
 pub mod jwt;
 pub mod midtrans;
+pub mod money;
+pub mod product_transfer;
+pub mod rod_specs;
+pub mod search;
+pub mod slug;
+pub mod storage;
+pub mod suggest;
 // Re-export ApiResponse agar bisa diakses langsung via crate::utils::ApiResponse
 pub use api_response::ApiResponse;
 // note: midtrans helper left as module; not re-exporting its function to avoid unused warnings
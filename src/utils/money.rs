@@ -0,0 +1,229 @@
+// src/utils/money.rs
+//
+// Nominal uang dalam rupiah utuh. Semua harga, subtotal, dan total order memakai tipe ini,
+// sehingga aturan pembulatan hanya ada di sini:
+// - nilai pecahan (dari DB, JSON, atau query string) dibulatkan ke rupiah terdekat,
+//   setengah rupiah dibulatkan menjauhi nol (1.5 -> 2, -1.5 -> -2)
+// - perhitungan (harga x kuantitas, penjumlahan) dilakukan dalam integer, tanpa pembulatan lagi
+//
+// Di database disimpan sebagai DECIMAL, di JSON sebagai angka integer (mis. 1500000).
+
+use bigdecimal::ToPrimitive;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use sqlx::MySql;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::mysql::{MySqlTypeInfo, MySqlValueRef};
+use sqlx::types::BigDecimal;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, ToSchema,
+)]
+#[serde(transparent)]
+#[schema(example = 1500000)]
+pub struct Money(i64);
+
+impl Money {
+    pub const ZERO: Money = Money(0);
+
+    pub const fn rupiah(self) -> i64 {
+        self.0
+    }
+
+    /// Bulatkan nilai desimal ke rupiah utuh; `None` jika di luar jangkauan i64.
+    pub fn from_decimal(value: &BigDecimal) -> Option<Self> {
+        // `round` milik BigDecimal membulatkan setengah menjauhi nol
+        value.round(0).to_i64().map(Money)
+    }
+
+    pub fn to_decimal(self) -> BigDecimal {
+        BigDecimal::from(self.0)
+    }
+
+    pub const fn is_positive(self) -> bool {
+        self.0 > 0
+    }
+
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Subtotal satu baris: harga satuan x kuantitas; `None` jika melampaui jangkauan i64.
+    pub const fn times(self, quantity: i32) -> Option<Self> {
+        match self.0.checked_mul(quantity as i64) {
+            Some(value) => Some(Money(value)),
+            None => None,
+        }
+    }
+
+    /// Penjumlahan yang menolak overflow, untuk total dari input pengguna.
+    pub const fn checked_add(self, other: Money) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(value) => Some(Money(value)),
+            None => None,
+        }
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Money {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value =
+            BigDecimal::from_str(s.trim()).map_err(|_| format!("Nominal tidak valid: {}", s))?;
+        Money::from_decimal(&value).ok_or_else(|| format!("Nominal terlalu besar: {}", s))
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        self.0 += other.0;
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+
+// --- JSON / QUERY STRING ---
+// Menerima angka integer, angka pecahan, atau string angka (query string selalu string).
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MoneyVisitor;
+
+        impl Visitor<'_> for MoneyVisitor {
+            type Value = Money;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("nominal rupiah")
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Money, E> {
+                Ok(Money(v))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Money, E> {
+                i64::try_from(v)
+                    .map(Money)
+                    .map_err(|_| E::custom("nominal terlalu besar"))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Money, E> {
+                if !v.is_finite() {
+                    return Err(E::custom("nominal tidak valid"));
+                }
+                // Lewat representasi desimalnya agar pembulatannya sama dengan nilai dari DB
+                v.to_string().parse().map_err(E::custom)
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Money, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(MoneyVisitor)
+    }
+}
+
+// --- DATABASE (DECIMAL) ---
+impl sqlx::Type<MySql> for Money {
+    fn type_info() -> MySqlTypeInfo {
+        <BigDecimal as sqlx::Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <BigDecimal as sqlx::Type<MySql>>::compatible(ty)
+    }
+}
+
+impl sqlx::Encode<'_, MySql> for Money {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        <BigDecimal as sqlx::Encode<MySql>>::encode(self.to_decimal(), buf)
+    }
+}
+
+impl<'r> sqlx::Decode<'r, MySql> for Money {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        let decimal = <BigDecimal as sqlx::Decode<MySql>>::decode(value)?;
+        Money::from_decimal(&decimal)
+            .ok_or_else(|| format!("Nominal di luar jangkauan: {}", decimal).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_rounds_half_away_from_zero() {
+        assert_eq!("1500000".parse(), Ok(Money(1_500_000)));
+        assert_eq!(" 1500000.00 ".parse(), Ok(Money(1_500_000)));
+        assert_eq!("1.5".parse(), Ok(Money(2)));
+        assert_eq!("1.49".parse(), Ok(Money(1)));
+        assert_eq!("-1.5".parse(), Ok(Money(-2)));
+        assert!("Rp 10.000".parse::<Money>().is_err());
+        assert!("99999999999999999999".parse::<Money>().is_err());
+    }
+
+    #[test]
+    fn deserializes_integers_fractions_and_strings() {
+        let parse = |json: &str| serde_json::from_str::<Money>(json);
+        assert_eq!(parse("1500000").unwrap(), Money(1_500_000));
+        assert_eq!(parse("2500.5").unwrap(), Money(2501));
+        assert_eq!(parse("\"25000\"").unwrap(), Money(25_000));
+        assert!(parse("\"abc\"").is_err());
+        assert!(parse("18446744073709551615").is_err());
+    }
+
+    #[test]
+    fn serializes_as_integer() {
+        assert_eq!(serde_json::to_string(&Money(1_500_000)).unwrap(), "1500000");
+        assert_eq!(Money(-25).to_string(), "-25");
+    }
+
+    #[test]
+    fn arithmetic_stays_in_whole_rupiah() {
+        assert_eq!(Money(33_333).times(3), Some(Money(99_999)));
+        assert_eq!(Money(i64::MAX / 2).times(3), None);
+        assert_eq!(Money(i64::MAX).checked_add(Money(1)), None);
+        let mut total = Money::ZERO;
+        total += Money(10_000);
+        assert_eq!(total + Money(5_000), Money(15_000));
+        assert_eq!(
+            [Money(1), Money(2), Money(3)].into_iter().sum::<Money>(),
+            Money(6)
+        );
+        assert!(Money(1).is_positive() && !Money::ZERO.is_positive());
+        assert!(Money(-1).is_negative());
+    }
+
+    #[test]
+    fn decimal_round_trip() {
+        let value = BigDecimal::from_str("1250000.50").unwrap();
+        let money = Money::from_decimal(&value).unwrap();
+        assert_eq!(money, Money(1_250_001));
+        assert_eq!(money.to_decimal(), BigDecimal::from(1_250_001));
+    }
+}
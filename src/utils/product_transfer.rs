@@ -0,0 +1,175 @@
+// src/utils/product_transfer.rs
+//
+// Baca/tulis file katalog produk (JSON & CSV) untuk impor/ekspor massal.
+// Kedua format memakai kolom yang sama dengan `ProductTransferRow`; di CSV kolom
+// `attributes` berisi teks JSON dan kolom kosong berarti tidak diisi. Kolom `slug` dan meta
+// SEO boleh tidak ada (file dari versi sebelumnya).
+
+use crate::dtos::product_transfer::{ProductTransferRow, TransferFormat};
+use crate::utils::money::Money;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::types::Json;
+
+/// Batas baris per impor agar satu transaksi tidak terlalu besar.
+pub const MAX_IMPORT_ROWS: usize = 5000;
+
+/// Batas ukuran body impor: ±4 KB per baris (deskripsi, atribut, meta SEO) untuk
+/// `MAX_IMPORT_ROWS` baris. Batas bawaan axum (2 MB) terlalu kecil untuk katalog penuh.
+pub const MAX_IMPORT_BYTES: usize = MAX_IMPORT_ROWS * 4 * 1024;
+
+const CSV_HEADERS: [&str; 17] = [
+    "sku",
+    "name",
+    "description",
+    "category_id",
+    "rod_length",
+    "line_weight",
+    "cast_weight",
+    "action",
+    "material",
+    "power",
+    "reel_size",
+    "price",
+    "image_url",
+    "attributes",
+    "slug",
+    "meta_title",
+    "meta_description",
+];
+
+/// Baris CSV: sama dengan `ProductTransferRow`, tetapi `attributes` berupa teks JSON.
+#[derive(Serialize, Deserialize)]
+struct CsvRow {
+    sku: String,
+    name: String,
+    description: String,
+    category_id: i32,
+    rod_length: Option<String>,
+    line_weight: Option<String>,
+    cast_weight: Option<String>,
+    action: Option<String>,
+    material: Option<String>,
+    power: Option<String>,
+    reel_size: Option<String>,
+    price: Money,
+    image_url: Option<String>,
+    attributes: Option<String>,
+    #[serde(default)]
+    slug: Option<String>,
+    #[serde(default)]
+    meta_title: Option<String>,
+    #[serde(default)]
+    meta_description: Option<String>,
+}
+
+impl CsvRow {
+    fn into_row(self) -> Result<ProductTransferRow, String> {
+        let attributes = match self.attributes {
+            Some(text) => Some(Json(
+                serde_json::from_str::<Map<String, Value>>(&text)
+                    .map_err(|_| "attributes harus berupa objek JSON".to_string())?,
+            )),
+            None => None,
+        };
+        Ok(ProductTransferRow {
+            sku: self.sku,
+            name: self.name,
+            description: self.description,
+            category_id: self.category_id,
+            rod_length: self.rod_length,
+            line_weight: self.line_weight,
+            cast_weight: self.cast_weight,
+            action: self.action,
+            material: self.material,
+            power: self.power,
+            reel_size: self.reel_size,
+            price: self.price,
+            image_url: self.image_url,
+            attributes,
+            slug: self.slug,
+            meta_title: self.meta_title,
+            meta_description: self.meta_description,
+        })
+    }
+
+    fn from_row(row: ProductTransferRow) -> Self {
+        CsvRow {
+            sku: row.sku,
+            name: row.name,
+            description: row.description,
+            category_id: row.category_id,
+            rod_length: row.rod_length,
+            line_weight: row.line_weight,
+            cast_weight: row.cast_weight,
+            action: row.action,
+            material: row.material,
+            power: row.power,
+            reel_size: row.reel_size,
+            price: row.price,
+            image_url: row.image_url,
+            attributes: row
+                .attributes
+                .filter(|a| !a.is_empty())
+                .map(|a| Value::Object(a.0).to_string()),
+            slug: row.slug,
+            meta_title: row.meta_title,
+            meta_description: row.meta_description,
+        }
+    }
+}
+
+/// Parse isi file impor. Baris yang tidak bisa dibaca menjadi pesan error untuk baris itu;
+/// `Err` hanya untuk file yang tidak bisa dibaca sama sekali.
+pub fn parse(
+    content: &str,
+    format: TransferFormat,
+) -> Result<Vec<Result<ProductTransferRow, String>>, String> {
+    // BOM dari Excel
+    let content = content.trim_start_matches('\u{feff}');
+
+    let rows: Vec<Result<ProductTransferRow, String>> = match format {
+        TransferFormat::Json => serde_json::from_str::<Vec<Value>>(content)
+            .map_err(|e| format!("File JSON tidak valid (harus berupa array): {}", e))?
+            .into_iter()
+            .map(|value| serde_json::from_value(value).map_err(|e| e.to_string()))
+            .collect(),
+        TransferFormat::Csv => csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(content.as_bytes())
+            .deserialize::<CsvRow>()
+            .map(|record| record.map_err(|e| e.to_string())?.into_row())
+            .collect(),
+    };
+
+    if rows.is_empty() {
+        return Err("File tidak berisi produk".to_string());
+    }
+    if rows.len() > MAX_IMPORT_ROWS {
+        return Err(format!("Maksimal {} produk per impor", MAX_IMPORT_ROWS));
+    }
+    Ok(rows)
+}
+
+/// Tulis katalog ke format yang sama dengan yang diterima `parse`.
+pub fn render(rows: Vec<ProductTransferRow>, format: TransferFormat) -> Result<String, String> {
+    match format {
+        TransferFormat::Json => serde_json::to_string_pretty(&rows).map_err(|e| e.to_string()),
+        TransferFormat::Csv => {
+            // Header ditulis sendiri agar tetap ada walau katalog kosong
+            let mut writer = csv::WriterBuilder::new()
+                .has_headers(false)
+                .from_writer(Vec::new());
+            writer
+                .write_record(CSV_HEADERS)
+                .map_err(|e| e.to_string())?;
+            for row in rows {
+                writer
+                    .serialize(CsvRow::from_row(row))
+                    .map_err(|e| e.to_string())?;
+            }
+            let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+            String::from_utf8(bytes).map_err(|e| e.to_string())
+        }
+    }
+}
@@ -0,0 +1,389 @@
+//! Parser spesifikasi joran: mengubah teks bebas seperti "7ft", "10-20lb", "1/4-3/4oz"
+//! menjadi angka dengan satuan baku (cm, lb, gram), plus format tampilan imperial/metrik.
+
+use crate::dtos::product::{RodSpecsDisplay, SpecDisplay};
+
+const CM_PER_INCH: f64 = 2.54;
+const LB_PER_KG: f64 = 2.204_62;
+const GRAM_PER_OZ: f64 = 28.349_5;
+
+/// Nilai numerik hasil parsing, disimpan di kolom products di samping teks aslinya.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RodSpecs {
+    pub rod_length_cm: Option<f64>,
+    pub line_min_lb: Option<f64>,
+    pub line_max_lb: Option<f64>,
+    pub cast_min_g: Option<f64>,
+    pub cast_max_g: Option<f64>,
+}
+
+impl RodSpecs {
+    /// Parse ketiga spesifikasi; string kosong dianggap tidak diisi.
+    pub fn parse(
+        rod_length: Option<&str>,
+        line_weight: Option<&str>,
+        cast_weight: Option<&str>,
+    ) -> Result<Self, String> {
+        let (specs, mut errors) = Self::parse_lenient(rod_length, line_weight, cast_weight);
+        if errors.is_empty() {
+            Ok(specs)
+        } else {
+            Err(errors.remove(0))
+        }
+    }
+
+    /// Seperti `parse`, tetapi field yang gagal dibiarkan kosong dan pesannya dikumpulkan.
+    /// Dipakai untuk backfill data lama yang formatnya belum tentu rapi.
+    pub fn parse_lenient(
+        rod_length: Option<&str>,
+        line_weight: Option<&str>,
+        cast_weight: Option<&str>,
+    ) -> (Self, Vec<String>) {
+        let mut errors = Vec::new();
+
+        let rod_length_cm = present(rod_length).and_then(|s| keep(parse_length_cm(s), &mut errors));
+        let line = present(line_weight)
+            .and_then(|s| keep(parse_range(s, LINE_UNITS, "line_weight"), &mut errors));
+        let cast = present(cast_weight)
+            .and_then(|s| keep(parse_range(s, CAST_UNITS, "cast_weight"), &mut errors));
+
+        let specs = Self {
+            rod_length_cm,
+            line_min_lb: line.map(|(min, _)| min),
+            line_max_lb: line.map(|(_, max)| max),
+            cast_min_g: cast.map(|(min, _)| min),
+            cast_max_g: cast.map(|(_, max)| max),
+        };
+        (specs, errors)
+    }
+}
+
+/// String kosong dianggap tidak diisi.
+fn present(s: Option<&str>) -> Option<&str> {
+    s.map(str::trim).filter(|s| !s.is_empty())
+}
+
+fn keep<T>(result: Result<T, String>, errors: &mut Vec<String>) -> Option<T> {
+    result.map_err(|e| errors.push(e)).ok()
+}
+
+/// Satuan yang diterima beserta faktor konversi ke satuan baku. Urutkan dari yang terpanjang.
+const LINE_UNITS: &[(&str, f64)] = &[("lbs", 1.0), ("lb", 1.0), ("kg", LB_PER_KG)];
+const CAST_UNITS: &[(&str, f64)] = &[("gram", 1.0), ("gr", 1.0), ("oz", GRAM_PER_OZ), ("g", 1.0)];
+
+/// Huruf kecil, tanpa spasi, dan variasi tanda baca diseragamkan.
+fn normalize(input: &str) -> String {
+    input
+        .to_lowercase()
+        .replace(" to ", "-")
+        .replace(['–', '—', '~'], "-")
+        .replace(['’', '′'], "'")
+        .replace(['”', '″'], "\"")
+        .replace("''", "\"")
+        .replace(',', ".")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect()
+}
+
+/// Angka desimal ("1.5") atau pecahan ("1/4").
+fn parse_number(s: &str) -> Option<f64> {
+    let value = match s.split_once('/') {
+        Some((num, den)) => {
+            let den: f64 = den.parse().ok()?;
+            if den == 0.0 {
+                return None;
+            }
+            num.parse::<f64>().ok()? / den
+        }
+        None => s.parse().ok()?,
+    };
+    (value.is_finite() && value >= 0.0).then_some(value)
+}
+
+/// Panjang joran dalam cm. Format: "7ft", "7'", "7'6\"", "7ft6in", "6.6ft", "2.1m", "210cm".
+pub fn parse_length_cm(input: &str) -> Result<f64, String> {
+    let s = normalize(input);
+    let invalid = || {
+        format!(
+            "Format rod_length '{}' tidak dikenali (contoh: 7ft, 7'6\", 2.1m, 210cm)",
+            input.trim()
+        )
+    };
+
+    let cm = if let Some(num) = s.strip_suffix("cm") {
+        parse_number(num).ok_or_else(invalid)?
+    } else if let Some(num) = ["meter", "mtr", "m"].iter().find_map(|u| s.strip_suffix(u)) {
+        parse_number(num).ok_or_else(invalid)? * 100.0
+    } else if let Some((feet, rest)) = ["feet", "ft", "'"].iter().find_map(|u| s.split_once(u)) {
+        let feet = parse_number(feet).ok_or_else(invalid)?;
+        let inches = ["inch", "in", "\""]
+            .iter()
+            .find_map(|u| rest.strip_suffix(u))
+            .unwrap_or(rest);
+        let inches = if inches.is_empty() {
+            0.0
+        } else {
+            parse_number(inches)
+                .filter(|i| *i < 12.0)
+                .ok_or_else(invalid)?
+        };
+        (feet * 12.0 + inches) * CM_PER_INCH
+    } else if let Some(num) = ["inch", "in", "\""].iter().find_map(|u| s.strip_suffix(u)) {
+        parse_number(num).ok_or_else(invalid)? * CM_PER_INCH
+    } else {
+        return Err(invalid());
+    };
+
+    if !(30.0..=1500.0).contains(&cm) {
+        return Err(format!(
+            "rod_length '{}' di luar rentang wajar (30 cm - 15 m)",
+            input.trim()
+        ));
+    }
+    Ok(round(cm, 2))
+}
+
+/// Rentang "10-20lb", "10lb-20lb", "1/4-3/4oz", "7-21g", atau nilai tunggal "15g".
+/// Hasil dalam satuan baku (lb untuk line, gram untuk cast).
+fn parse_range(input: &str, units: &[(&str, f64)], field: &str) -> Result<(f64, f64), String> {
+    let s = normalize(input);
+    let unit_names: Vec<&str> = units.iter().map(|(u, _)| *u).collect();
+    let invalid = || {
+        format!(
+            "Format {} '{}' tidak dikenali (satuan yang didukung: {})",
+            field,
+            input.trim(),
+            unit_names.join(", ")
+        )
+    };
+
+    let (body, factor) = units
+        .iter()
+        .find_map(|(unit, factor)| s.strip_suffix(unit).map(|body| (body, *factor)))
+        .ok_or_else(invalid)?;
+
+    let strip_unit = |part: &str| -> Option<f64> {
+        let part = units
+            .iter()
+            .find_map(|(unit, f)| (*f == factor).then(|| part.strip_suffix(unit)).flatten())
+            .unwrap_or(part);
+        parse_number(part)
+    };
+
+    let (min, max) = match body.split_once('-') {
+        Some((min, max)) => (
+            strip_unit(min).ok_or_else(invalid)?,
+            strip_unit(max).ok_or_else(invalid)?,
+        ),
+        None => {
+            let value = strip_unit(body).ok_or_else(invalid)?;
+            (value, value)
+        }
+    };
+
+    if min <= 0.0 || min > max {
+        return Err(format!(
+            "Rentang {} '{}' tidak valid: nilai minimum harus > 0 dan <= maksimum",
+            field,
+            input.trim()
+        ));
+    }
+    Ok((round(min * factor, 2), round(max * factor, 2)))
+}
+
+fn round(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (value * factor).round() / factor
+}
+
+/// Angka tanpa nol di belakang koma ("7", "0.25", "4.5").
+fn format_number(value: f64, decimals: usize) -> String {
+    let text = format!("{:.*}", decimals, value);
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    }
+}
+
+fn format_range(min: f64, max: f64, decimals: usize, unit: &str) -> String {
+    let (min, max) = (format_number(min, decimals), format_number(max, decimals));
+    if min == max {
+        format!("{} {}", min, unit)
+    } else {
+        format!("{}-{} {}", min, max, unit)
+    }
+}
+
+/// Tampilan panjang: 7'0" / 2.13 m.
+pub fn display_length(cm: f64) -> SpecDisplay {
+    let total_inches = (cm / CM_PER_INCH).round() as i64;
+    SpecDisplay {
+        imperial: format!("{}'{}\"", total_inches / 12, total_inches % 12),
+        metric: format!("{} m", format_number(cm / 100.0, 2)),
+    }
+}
+
+pub fn display_line(min_lb: f64, max_lb: f64) -> SpecDisplay {
+    SpecDisplay {
+        imperial: format_range(min_lb, max_lb, 1, "lb"),
+        metric: format_range(min_lb / LB_PER_KG, max_lb / LB_PER_KG, 1, "kg"),
+    }
+}
+
+pub fn display_cast(min_g: f64, max_g: f64) -> SpecDisplay {
+    SpecDisplay {
+        imperial: format_range(min_g / GRAM_PER_OZ, max_g / GRAM_PER_OZ, 2, "oz"),
+        metric: format_range(min_g, max_g, 0, "g"),
+    }
+}
+
+/// Tampilan imperial & metrik dari kolom numerik; level tanpa data dibiarkan kosong.
+pub fn display_specs(
+    rod_length_cm: Option<f64>,
+    line: (Option<f64>, Option<f64>),
+    cast: (Option<f64>, Option<f64>),
+) -> RodSpecsDisplay {
+    RodSpecsDisplay {
+        length: rod_length_cm.map(display_length),
+        line_weight: match line {
+            (Some(min), Some(max)) => Some(display_line(min, max)),
+            _ => None,
+        },
+        cast_weight: match cast {
+            (Some(min), Some(max)) => Some(display_cast(min, max)),
+            _ => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_length_formats() {
+        assert_eq!(parse_length_cm("7ft"), Ok(213.36));
+        assert_eq!(parse_length_cm("7 ft"), Ok(213.36));
+        assert_eq!(parse_length_cm("7'"), Ok(213.36));
+        assert_eq!(parse_length_cm("7'6\""), Ok(228.6));
+        assert_eq!(parse_length_cm("7ft6in"), Ok(228.6));
+        assert_eq!(parse_length_cm("7’6”"), Ok(228.6));
+        assert_eq!(parse_length_cm("6.6ft"), Ok(201.17));
+        assert_eq!(parse_length_cm("2.1m"), Ok(210.0));
+        assert_eq!(parse_length_cm("2,1 m"), Ok(210.0));
+        assert_eq!(parse_length_cm("210cm"), Ok(210.0));
+        assert_eq!(parse_length_cm("84in"), Ok(213.36));
+    }
+
+    #[test]
+    fn rejects_unknown_or_implausible_lengths() {
+        assert!(parse_length_cm("seven feet").is_err());
+        assert!(parse_length_cm("7 yards").is_err());
+        assert!(parse_length_cm("7ft13in").is_err());
+        assert!(parse_length_cm("0.2m").is_err());
+        assert!(parse_length_cm("20m").is_err());
+    }
+
+    #[test]
+    fn parses_line_weight_ranges() {
+        assert_eq!(
+            parse_range("10-20lb", LINE_UNITS, "line_weight"),
+            Ok((10.0, 20.0))
+        );
+        assert_eq!(
+            parse_range("10lb-20lb", LINE_UNITS, "line_weight"),
+            Ok((10.0, 20.0))
+        );
+        assert_eq!(
+            parse_range("8 to 16 lbs", LINE_UNITS, "line_weight"),
+            Ok((8.0, 16.0))
+        );
+        assert_eq!(
+            parse_range("12lb", LINE_UNITS, "line_weight"),
+            Ok((12.0, 12.0))
+        );
+        assert_eq!(
+            parse_range("5-10kg", LINE_UNITS, "line_weight"),
+            Ok((11.02, 22.05))
+        );
+    }
+
+    #[test]
+    fn parses_cast_weight_ranges() {
+        assert_eq!(
+            parse_range("1/4-3/4oz", CAST_UNITS, "cast_weight"),
+            Ok((7.09, 21.26))
+        );
+        assert_eq!(
+            parse_range("7-21g", CAST_UNITS, "cast_weight"),
+            Ok((7.0, 21.0))
+        );
+        assert_eq!(
+            parse_range("10–30 gram", CAST_UNITS, "cast_weight"),
+            Ok((10.0, 30.0))
+        );
+        assert_eq!(
+            parse_range("15gr", CAST_UNITS, "cast_weight"),
+            Ok((15.0, 15.0))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_ranges() {
+        assert!(parse_range("10-20", LINE_UNITS, "line_weight").is_err());
+        assert!(parse_range("20-10lb", LINE_UNITS, "line_weight").is_err());
+        assert!(parse_range("0-10lb", LINE_UNITS, "line_weight").is_err());
+        assert!(parse_range("1/0oz", CAST_UNITS, "cast_weight").is_err());
+        assert!(parse_range("10-20lb", CAST_UNITS, "cast_weight").is_err());
+    }
+
+    #[test]
+    fn parse_skips_empty_fields_and_reports_first_error() {
+        assert_eq!(
+            RodSpecs::parse(Some(" "), None, Some("")),
+            Ok(RodSpecs::default())
+        );
+        assert_eq!(
+            RodSpecs::parse(Some("7ft"), Some("10-20lb"), Some("1/4-3/4oz")),
+            Ok(RodSpecs {
+                rod_length_cm: Some(213.36),
+                line_min_lb: Some(10.0),
+                line_max_lb: Some(20.0),
+                cast_min_g: Some(7.09),
+                cast_max_g: Some(21.26),
+            })
+        );
+
+        let err = RodSpecs::parse(Some("tujuh kaki"), Some("10-20lb"), None).unwrap_err();
+        assert!(err.contains("rod_length"));
+    }
+
+    #[test]
+    fn parse_lenient_keeps_valid_fields() {
+        let (specs, errors) = RodSpecs::parse_lenient(Some("7ft"), Some("heavy"), Some("7-21g"));
+        assert_eq!(specs.rod_length_cm, Some(213.36));
+        assert_eq!(specs.line_min_lb, None);
+        assert_eq!(specs.cast_max_g, Some(21.0));
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("line_weight"));
+    }
+
+    #[test]
+    fn displays_imperial_and_metric() {
+        let length = display_length(213.36);
+        assert_eq!(length.imperial, "7'0\"");
+        assert_eq!(length.metric, "2.13 m");
+
+        let line = display_line(10.0, 20.0);
+        assert_eq!(line.imperial, "10-20 lb");
+        assert_eq!(line.metric, "4.5-9.1 kg");
+
+        let cast = display_cast(7.09, 21.26);
+        assert_eq!(cast.imperial, "0.25-0.75 oz");
+        assert_eq!(cast.metric, "7-21 g");
+
+        assert_eq!(display_cast(15.0, 15.0).metric, "15 g");
+    }
+}
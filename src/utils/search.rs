@@ -0,0 +1,228 @@
+//! Helper teks untuk pencarian produk: tokenisasi, koreksi typo, dan highlight snippet.
+
+/// Pecah teks menjadi kata huruf kecil (alfanumerik saja).
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+/// Jarak edit Levenshtein antar dua kata (dihitung per karakter, bukan byte).
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+/// Toleransi typo: kata pendek hanya boleh beda 1 huruf, kata panjang 2 huruf.
+fn max_distance(word: &str) -> usize {
+    if word.chars().count() <= 5 { 1 } else { 2 }
+}
+
+/// Ganti setiap kata query yang tidak dikenal dengan kata terdekat dari `vocabulary`.
+/// Mengembalikan `None` jika tidak ada kata yang dikoreksi.
+pub fn correct_query(query: &str, vocabulary: &[String]) -> Option<String> {
+    let mut changed = false;
+    let corrected: Vec<String> = tokenize(query)
+        .into_iter()
+        .map(|word| {
+            if vocabulary.contains(&word) {
+                return word;
+            }
+            let best = vocabulary
+                .iter()
+                .map(|candidate| (levenshtein(&word, candidate), candidate))
+                .filter(|(distance, _)| *distance <= max_distance(&word))
+                .min_by_key(|(distance, _)| *distance);
+            match best {
+                Some((_, candidate)) => {
+                    changed = true;
+                    candidate.clone()
+                }
+                None => word,
+            }
+        })
+        .collect();
+
+    changed.then(|| corrected.join(" "))
+}
+
+fn escape_html(c: char, out: &mut String) {
+    match c {
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        '&' => out.push_str("&amp;"),
+        '"' => out.push_str("&quot;"),
+        _ => out.push(c),
+    }
+}
+
+/// Potong `text` di sekitar kecocokan pertama dan bungkus setiap kata yang cocok dengan
+/// `<mark>`. Teks lain di-escape sehingga aman dirender sebagai HTML.
+pub fn highlight(text: &str, terms: &[String], max_chars: usize) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let terms: Vec<Vec<char>> = terms
+        .iter()
+        .filter(|t| !t.is_empty())
+        .map(|t| t.chars().collect())
+        .collect();
+
+    let match_len_at = |i: usize| {
+        terms
+            .iter()
+            .filter(|t| lower[i..].starts_with(t))
+            .map(|t| t.len())
+            .max()
+    };
+
+    // Jendela snippet dimulai sedikit sebelum kecocokan pertama
+    let first_match = (0..chars.len()).find(|&i| match_len_at(i).is_some());
+    let start = first_match.map_or(0, |i| i.saturating_sub(max_chars / 4));
+    let end = (start + max_chars).min(chars.len());
+
+    let mut out = String::new();
+    if start > 0 {
+        out.push('…');
+    }
+    let mut i = start;
+    while i < end {
+        match match_len_at(i) {
+            Some(len) => {
+                out.push_str("<mark>");
+                for c in &chars[i..(i + len).min(chars.len())] {
+                    escape_html(*c, &mut out);
+                }
+                out.push_str("</mark>");
+                i += len;
+            }
+            None => {
+                escape_html(chars[i], &mut out);
+                i += 1;
+            }
+        }
+    }
+    if end < chars.len() {
+        out.push('…');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn tokenize_splits_on_non_alphanumeric() {
+        assert_eq!(
+            tokenize("Joran  Casting-7ft, Shimano!"),
+            words(&["joran", "casting", "7ft", "shimano"])
+        );
+        assert_eq!(tokenize("Kail–Umpan"), words(&["kail", "umpan"]));
+        assert!(tokenize("").is_empty());
+        assert!(tokenize("  -- !! ").is_empty());
+    }
+
+    #[test]
+    fn tokenize_keeps_multibyte_letters() {
+        assert_eq!(
+            tokenize("Umpan ÑANDÚ Séri"),
+            words(&["umpan", "ñandú", "séri"])
+        );
+    }
+
+    #[test]
+    fn levenshtein_counts_characters_not_bytes() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("joran", "joran"), 0);
+        assert_eq!(levenshtein("", "kail"), 4);
+        assert_eq!(levenshtein("kail", ""), 4);
+        assert_eq!(levenshtein("sénar", "senar"), 1);
+        assert_eq!(levenshtein("ñandú", "nandu"), 2);
+    }
+
+    #[test]
+    fn correct_query_respects_distance_thresholds() {
+        let vocabulary = words(&["joran", "casting", "shimano", "spinning", "senar"]);
+
+        // Kata <= 5 huruf: maksimal 1 huruf berbeda
+        assert_eq!(correct_query("jorn", &vocabulary).as_deref(), Some("joran"));
+        assert_eq!(correct_query("jrn", &vocabulary), None);
+
+        // Kata lebih panjang: maksimal 2 huruf berbeda
+        assert_eq!(
+            correct_query("spinnig", &vocabulary).as_deref(),
+            Some("spinning")
+        );
+        assert_eq!(
+            correct_query("spnnig", &vocabulary).as_deref(),
+            Some("spinning")
+        );
+        assert_eq!(correct_query("sxxxning", &vocabulary), None);
+
+        assert_eq!(
+            correct_query("Shimanno casting", &vocabulary).as_deref(),
+            Some("shimano casting")
+        );
+    }
+
+    #[test]
+    fn correct_query_handles_empty_and_known_queries() {
+        let vocabulary = words(&["joran", "casting"]);
+        assert_eq!(correct_query("", &vocabulary), None);
+        assert_eq!(correct_query("   ", &vocabulary), None);
+        assert_eq!(correct_query("Joran Casting", &vocabulary), None);
+        assert_eq!(correct_query("jorn", &[]), None);
+    }
+
+    #[test]
+    fn correct_query_handles_multibyte_input() {
+        let vocabulary = words(&["senar", "umpan"]);
+        assert_eq!(
+            correct_query("sénar", &vocabulary).as_deref(),
+            Some("senar")
+        );
+        assert_eq!(correct_query("ümpän", &vocabulary).as_deref(), None);
+    }
+
+    #[test]
+    fn highlight_marks_terms_and_escapes_html() {
+        assert_eq!(
+            highlight("Joran <Casting> & Reel", &words(&["casting"]), 100),
+            "Joran &lt;<mark>Casting</mark>&gt; &amp; Reel"
+        );
+        assert_eq!(
+            highlight("Umpan Ñandú murah", &words(&["ñandú"]), 100),
+            "Umpan <mark>Ñandú</mark> murah"
+        );
+        assert_eq!(highlight("a \"b\"", &[], 100), "a &quot;b&quot;");
+    }
+
+    #[test]
+    fn highlight_trims_snippet_around_first_match() {
+        let text = format!("{}joran{}", "a".repeat(40), "b".repeat(40));
+        assert_eq!(
+            highlight(&text, &words(&["joran"]), 20),
+            format!("…{}<mark>joran</mark>{}…", "a".repeat(5), "b".repeat(10))
+        );
+    }
+}
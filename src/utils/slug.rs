@@ -0,0 +1,67 @@
+// src/utils/slug.rs
+//
+// Slug URL: huruf kecil ASCII, angka, dan '-' sebagai pemisah kata.
+
+/// Panjang maksimal slug (kolom slug VARCHAR(120), disisakan untuk akhiran `-2`, `-3`, ...).
+pub const MAX_SLUG_LEN: usize = 100;
+
+/// Buat slug dari teks bebas, mis. "Joran Casting 7'" -> "joran-casting-7".
+/// Karakter selain huruf/angka ASCII menjadi pemisah; hasilnya bisa kosong.
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.truncate(MAX_SLUG_LEN);
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Slug yang diisi manual harus sudah dalam bentuk akhir (tidak diubah otomatis).
+pub fn validate_slug(slug: &str) -> Result<(), String> {
+    if slug.is_empty() || slug.len() > MAX_SLUG_LEN {
+        return Err(format!(
+            "Slug wajib diisi (maksimal {} karakter)",
+            MAX_SLUG_LEN
+        ));
+    }
+    if slug != slugify(slug) {
+        return Err("Slug hanya boleh huruf kecil, angka, dan '-' di antara kata".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_lowercases_and_joins_words() {
+        assert_eq!(slugify("Joran Casting 7'"), "joran-casting-7");
+        assert_eq!(slugify("  Kail & Umpan -- Laut!! "), "kail-umpan-laut");
+        assert_eq!(slugify("Reel Spinning 2500"), "reel-spinning-2500");
+        assert_eq!(slugify("Senar PE 0.8"), "senar-pe-0-8");
+        assert_eq!(slugify("!!!"), "");
+    }
+
+    #[test]
+    fn slugify_truncates_without_trailing_dash() {
+        assert_eq!(slugify(&"a".repeat(150)).len(), MAX_SLUG_LEN);
+
+        let long = format!("{} b", "a".repeat(MAX_SLUG_LEN - 1));
+        assert_eq!(slugify(&long), "a".repeat(MAX_SLUG_LEN - 1));
+    }
+
+    #[test]
+    fn validate_slug_requires_final_form() {
+        assert!(validate_slug("joran-casting").is_ok());
+        assert!(validate_slug("").is_err());
+        assert!(validate_slug("Joran").is_err());
+        assert!(validate_slug("joran--casting").is_err());
+        assert!(validate_slug("joran-").is_err());
+        assert!(validate_slug(&"a".repeat(MAX_SLUG_LEN + 1)).is_err());
+    }
+}
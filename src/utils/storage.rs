@@ -0,0 +1,236 @@
+// src/utils/storage.rs
+//
+// Penyimpanan file unggahan. Driver dipilih lewat STORAGE_DRIVER:
+// - `local` (default): file di UPLOAD_DIR, disajikan aplikasi di /uploads
+// - `s3`: bucket S3-compatible (AWS, MinIO, R2, ...) lewat request bertanda tangan SigV4
+
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Prefix URL tempat file storage lokal disajikan.
+pub const LOCAL_URL_PREFIX: &str = "/uploads";
+
+#[async_trait]
+pub trait Storage: Send + Sync {
+    /// Simpan file di `key` (mis. `products/1/ab12/medium.jpg`), timpa jika sudah ada.
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), String>;
+
+    /// Hapus file; key yang sudah tidak ada dianggap berhasil.
+    async fn delete(&self, key: &str) -> Result<(), String>;
+
+    /// URL publik untuk menampilkan file.
+    fn public_url(&self, key: &str) -> String;
+}
+
+/// Buat storage sesuai konfigurasi env.
+pub fn from_env() -> Result<Arc<dyn Storage>, String> {
+    match env::var("STORAGE_DRIVER")
+        .unwrap_or_else(|_| "local".to_string())
+        .to_lowercase()
+        .as_str()
+    {
+        "local" => Ok(Arc::new(LocalStorage::from_env())),
+        "s3" => Ok(Arc::new(S3Storage::from_env()?)),
+        other => Err(format!("STORAGE_DRIVER tidak dikenal: {}", other)),
+    }
+}
+
+/// Folder yang perlu disajikan sebagai file statis (hanya untuk driver lokal).
+pub fn local_upload_dir() -> Option<PathBuf> {
+    match env::var("STORAGE_DRIVER") {
+        Ok(driver) if driver.eq_ignore_ascii_case("s3") => None,
+        _ => Some(LocalStorage::from_env().root),
+    }
+}
+
+/// Variabel env yang kosong dianggap tidak diset.
+fn optional_env(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+// --- LOCAL FILESYSTEM ---
+pub struct LocalStorage {
+    root: PathBuf,
+    base_url: String,
+}
+
+impl LocalStorage {
+    fn from_env() -> Self {
+        Self {
+            root: PathBuf::from(
+                optional_env("UPLOAD_DIR").unwrap_or_else(|| "uploads".to_string()),
+            ),
+            // Bisa diisi URL absolut (mis. CDN) jika folder upload disajikan di tempat lain
+            base_url: optional_env("UPLOAD_BASE_URL")
+                .unwrap_or_else(|| LOCAL_URL_PREFIX.to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+    async fn put(&self, key: &str, bytes: Vec<u8>, _content_type: &str) -> Result<(), String> {
+        let path = self.root.join(key);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| format!("Gagal membuat folder {}: {}", parent.display(), e))?;
+        }
+        tokio::fs::write(&path, bytes)
+            .await
+            .map_err(|e| format!("Gagal menulis {}: {}", path.display(), e))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), String> {
+        match tokio::fs::remove_file(self.root.join(key)).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(format!("Gagal menghapus {}: {}", key, e)),
+        }
+    }
+
+    fn public_url(&self, key: &str) -> String {
+        format!("{}/{}", self.base_url.trim_end_matches('/'), key)
+    }
+}
+
+// --- S3-COMPATIBLE ---
+// Path-style (`{endpoint}/{bucket}/{key}`) agar jalan juga di MinIO dan sejenisnya.
+pub struct S3Storage {
+    client: reqwest::Client,
+    endpoint: String,
+    host: String,
+    bucket: String,
+    region: String,
+    access_key: String,
+    secret_key: String,
+    public_base_url: String,
+}
+
+impl S3Storage {
+    fn from_env() -> Result<Self, String> {
+        let required =
+            |name: &str| optional_env(name).ok_or_else(|| format!("{} belum diset di .env", name));
+
+        let endpoint = required("S3_ENDPOINT")?.trim_end_matches('/').to_string();
+        let bucket = required("S3_BUCKET")?;
+        let parsed =
+            url::Url::parse(&endpoint).map_err(|e| format!("S3_ENDPOINT tidak valid: {}", e))?;
+        let host = match (parsed.host_str(), parsed.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err("S3_ENDPOINT tidak punya host".to_string()),
+        };
+        let public_base_url = optional_env("S3_PUBLIC_URL")
+            .unwrap_or_else(|| format!("{}/{}", endpoint, bucket))
+            .trim_end_matches('/')
+            .to_string();
+
+        Ok(Self {
+            client: reqwest::Client::new(),
+            endpoint,
+            host,
+            bucket,
+            region: optional_env("S3_REGION").unwrap_or_else(|| "us-east-1".to_string()),
+            access_key: required("S3_ACCESS_KEY")?,
+            secret_key: required("S3_SECRET_KEY")?,
+            public_base_url,
+        })
+    }
+
+    /// Header `x-amz-date` dan `Authorization` (AWS Signature Version 4) untuk satu request.
+    fn sign(&self, method: &str, path: &str, payload_hash: &str) -> (String, String) {
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let scope = format!("{}/{}/s3/aws4_request", date, self.region);
+        let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+
+        let canonical_request = format!(
+            "{}\n{}\n\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+            method, path, self.host, payload_hash, amz_date, signed_headers, payload_hash
+        );
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let mut key = hmac_sha256(format!("AWS4{}", self.secret_key).as_bytes(), &date);
+        for part in [self.region.as_str(), "s3", "aws4_request"] {
+            key = hmac_sha256(&key, part);
+        }
+        let signature = hex::encode(hmac_sha256(&key, &string_to_sign));
+
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.access_key, scope, signed_headers, signature
+        );
+        (amz_date, authorization)
+    }
+
+    async fn send(
+        &self,
+        method: reqwest::Method,
+        key: &str,
+        body: Vec<u8>,
+        content_type: Option<&str>,
+    ) -> Result<reqwest::Response, String> {
+        // Key dibuat aplikasi sendiri (huruf kecil, angka, `/._-`), jadi tidak perlu di-encode
+        let path = format!("/{}/{}", self.bucket, key);
+        let payload_hash = hex::encode(Sha256::digest(&body));
+        let (amz_date, authorization) = self.sign(method.as_str(), &path, &payload_hash);
+
+        let mut request = self
+            .client
+            .request(method, format!("{}{}", self.endpoint, path))
+            .header("x-amz-date", amz_date)
+            .header("x-amz-content-sha256", payload_hash)
+            .header("Authorization", authorization)
+            .body(body);
+        if let Some(content_type) = content_type {
+            request = request.header("Content-Type", content_type);
+        }
+        request.send().await.map_err(|e| e.to_string())
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC menerima key sepanjang apa pun");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[async_trait]
+impl Storage for S3Storage {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), String> {
+        let response = self
+            .send(reqwest::Method::PUT, key, bytes, Some(content_type))
+            .await?;
+        if !response.status().is_success() {
+            return Err(format!("S3 PUT {} gagal: {}", key, response.status()));
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), String> {
+        let response = self
+            .send(reqwest::Method::DELETE, key, Vec::new(), None)
+            .await?;
+        // S3 mengembalikan 204 juga untuk key yang tidak ada
+        if !response.status().is_success() && response.status() != reqwest::StatusCode::NOT_FOUND {
+            return Err(format!("S3 DELETE {} gagal: {}", key, response.status()));
+        }
+        Ok(())
+    }
+
+    fn public_url(&self, key: &str) -> String {
+        format!("{}/{}", self.public_base_url, key)
+    }
+}
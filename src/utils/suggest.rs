@@ -0,0 +1,402 @@
+//! Index prefix in-memory untuk `/products/suggest`.
+//!
+//! Index dibangun ulang dari database saat server start dan setiap kali produk atau
+//! kategori berubah, sehingga request autocomplete tidak perlu menyentuh database.
+//!
+//! Query pencarian baru tampil sebagai saran setelah dicari minimal `MIN_QUERY_HITS` kali.
+//! Sebelum itu hitungannya disimpan di map kandidat terpisah; keduanya dibatasi agar
+//! query acak tidak membuat index terus membesar.
+
+use crate::dtos::search::{SuggestResponse, Suggestion};
+use crate::models::search::SearchModel;
+use sqlx::{MySql, Pool};
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+
+/// Jumlah pencarian minimal sebelum query muncul di saran publik.
+const MIN_QUERY_HITS: i64 = 3;
+/// Batas query di saran publik; yang paling jarang dicari digeser oleh yang lebih populer.
+const MAX_QUERY_ENTRIES: usize = 500;
+/// Batas query kandidat yang belum mencapai `MIN_QUERY_HITS`. Jika penuh, query baru hanya
+/// dicatat di database dan ikut dimuat saat index dibangun ulang.
+const MAX_PENDING_QUERIES: usize = 10_000;
+
+static INDEX: LazyLock<RwLock<SuggestIndex>> =
+    LazyLock::new(|| RwLock::new(SuggestIndex::default()));
+
+#[derive(Debug, Clone)]
+struct Entry {
+    key: String,
+    text: String,
+    id: Option<i64>,
+    weight: i64,
+}
+
+/// Daftar entry yang diurutkan berdasarkan `key` agar pencarian prefix cukup binary search.
+#[derive(Debug, Default)]
+struct PrefixList {
+    entries: Vec<Entry>,
+}
+
+impl PrefixList {
+    fn from_entries(mut entries: Vec<Entry>) -> Self {
+        entries.sort_by(|a, b| a.key.cmp(&b.key));
+        Self { entries }
+    }
+
+    fn lookup(&self, prefix: &str, limit: usize) -> Vec<Suggestion> {
+        let start = self.entries.partition_point(|e| e.key.as_str() < prefix);
+        let mut matches: Vec<&Entry> = self.entries[start..]
+            .iter()
+            .take_while(|e| e.key.starts_with(prefix))
+            .collect();
+
+        matches.sort_by(|a, b| b.weight.cmp(&a.weight).then_with(|| a.text.cmp(&b.text)));
+
+        let mut suggestions: Vec<Suggestion> = Vec::new();
+        for entry in matches {
+            if suggestions.len() == limit {
+                break;
+            }
+            if suggestions
+                .iter()
+                .any(|s| s.id == entry.id && s.text == entry.text)
+            {
+                continue;
+            }
+            suggestions.push(Suggestion {
+                text: entry.text.clone(),
+                id: entry.id,
+            });
+        }
+        suggestions
+    }
+}
+
+#[derive(Debug, Default)]
+struct SuggestIndex {
+    products: PrefixList,
+    categories: PrefixList,
+    queries: PrefixList,
+    /// Hitungan query yang belum cukup sering dicari untuk tampil.
+    pending_queries: HashMap<String, i64>,
+}
+
+/// Huruf kecil dengan spasi dirapikan; dipakai untuk key index dan query.
+pub fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Satu entry per awal kata, sehingga "Shimano Joran Casting" juga cocok untuk "cast".
+/// Kecocokan dari awal nama diberi bobot lebih tinggi.
+fn name_entries(id: i64, name: &str) -> Vec<Entry> {
+    let key = normalize(name);
+    let words: Vec<&str> = key.split(' ').collect();
+    (0..words.len())
+        .map(|i| Entry {
+            key: words[i..].join(" "),
+            text: name.to_string(),
+            id: Some(id),
+            weight: if i == 0 { 1 } else { 0 },
+        })
+        .collect()
+}
+
+/// Bangun ulang index dari database. Index lama tetap dipakai sampai index baru siap.
+pub async fn refresh(pool: &Pool<MySql>) -> Result<(), sqlx::Error> {
+    let products = SearchModel::find_product_names(pool).await?;
+    let categories = SearchModel::find_category_names(pool).await?;
+    let (popular, pending): (Vec<_>, Vec<_>) = SearchModel::find_popular_queries(pool)
+        .await?
+        .into_iter()
+        .partition(|q| q.hits >= MIN_QUERY_HITS);
+
+    let index = SuggestIndex {
+        products: PrefixList::from_entries(
+            products
+                .iter()
+                .flat_map(|p| name_entries(p.id, &p.name))
+                .collect(),
+        ),
+        categories: PrefixList::from_entries(
+            categories
+                .iter()
+                .flat_map(|c| name_entries(c.id, &c.name))
+                .collect(),
+        ),
+        queries: PrefixList::from_entries(
+            popular
+                .into_iter()
+                .take(MAX_QUERY_ENTRIES)
+                .map(|q| Entry {
+                    key: normalize(&q.query),
+                    text: q.query,
+                    id: None,
+                    weight: q.hits,
+                })
+                .collect(),
+        ),
+        pending_queries: pending
+            .into_iter()
+            .take(MAX_PENDING_QUERIES)
+            .map(|q| (normalize(&q.query), q.hits))
+            .collect(),
+    };
+
+    *INDEX.write().unwrap_or_else(|e| e.into_inner()) = index;
+    Ok(())
+}
+
+/// Dipanggil setelah produk/kategori berubah. Gagal refresh hanya di-log karena
+/// perubahan datanya sendiri sudah tersimpan.
+pub async fn refresh_or_log(pool: &Pool<MySql>) {
+    if let Err(e) = refresh(pool).await {
+        eprintln!("Gagal memperbarui index saran pencarian: {}", e);
+    }
+}
+
+impl SuggestIndex {
+    /// Tambah satu hitungan untuk `key` (sudah dinormalisasi).
+    fn record_query(&mut self, key: String) {
+        let Self {
+            queries,
+            pending_queries,
+            ..
+        } = self;
+        let entries = &mut queries.entries;
+
+        let pos = match entries.binary_search_by(|e| e.key.as_str().cmp(&key)) {
+            Ok(pos) => {
+                entries[pos].weight += 1;
+                return;
+            }
+            Err(pos) => pos,
+        };
+
+        let full = pending_queries.len() >= MAX_PENDING_QUERIES;
+        let hits = match pending_queries.get_mut(&key) {
+            Some(hits) => {
+                *hits += 1;
+                *hits
+            }
+            None if !full => {
+                pending_queries.insert(key.clone(), 1);
+                1
+            }
+            None => return,
+        };
+        if hits < MIN_QUERY_HITS {
+            return;
+        }
+
+        // Saran penuh: geser query yang paling jarang dicari jika yang baru lebih populer
+        let mut pos = pos;
+        if entries.len() >= MAX_QUERY_ENTRIES {
+            let Some((least, least_weight)) = entries
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, e)| e.weight)
+                .map(|(i, e)| (i, e.weight))
+            else {
+                return;
+            };
+            if least_weight >= hits {
+                return;
+            }
+            let evicted = entries.remove(least);
+            if least < pos {
+                pos -= 1;
+            }
+            pending_queries.remove(&key);
+            // Query yang tergeser kembali jadi kandidat hanya jika masih ada tempat
+            if pending_queries.len() < MAX_PENDING_QUERIES {
+                pending_queries.insert(evicted.key, evicted.weight);
+            }
+        } else {
+            pending_queries.remove(&key);
+        }
+
+        entries.insert(
+            pos,
+            Entry {
+                text: key.clone(),
+                key,
+                id: None,
+                weight: hits,
+            },
+        );
+    }
+}
+
+/// Tambah hitungan query di index tanpa menunggu refresh berikutnya. Query yang baru
+/// mencapai `MIN_QUERY_HITS` dipindah dari kandidat ke saran publik.
+pub fn record_query(query: &str) {
+    let key = normalize(query);
+    INDEX
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .record_query(key);
+}
+
+pub fn suggest(prefix: &str, limit: usize) -> SuggestResponse {
+    let prefix = normalize(prefix);
+    if prefix.is_empty() {
+        return SuggestResponse::default();
+    }
+
+    let index = INDEX.read().unwrap_or_else(|e| e.into_inner());
+    SuggestResponse {
+        products: index.products.lookup(&prefix, limit),
+        categories: index.categories.lookup(&prefix, limit),
+        queries: index.queries.lookup(&prefix, limit),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query_entry(key: &str, weight: i64) -> Entry {
+        Entry {
+            key: key.to_string(),
+            text: key.to_string(),
+            id: None,
+            weight,
+        }
+    }
+
+    fn record_times(index: &mut SuggestIndex, key: &str, times: i64) {
+        for _ in 0..times {
+            index.record_query(key.to_string());
+        }
+    }
+
+    fn has_query(index: &SuggestIndex, key: &str) -> bool {
+        index.queries.entries.iter().any(|e| e.key == key)
+    }
+
+    #[test]
+    fn query_is_promoted_after_min_hits() {
+        let mut index = SuggestIndex::default();
+
+        record_times(&mut index, "joran casting", MIN_QUERY_HITS - 1);
+        assert!(!has_query(&index, "joran casting"));
+        assert_eq!(
+            index.pending_queries.get("joran casting"),
+            Some(&(MIN_QUERY_HITS - 1))
+        );
+
+        index.record_query("joran casting".to_string());
+        assert!(has_query(&index, "joran casting"));
+        assert!(!index.pending_queries.contains_key("joran casting"));
+
+        index.record_query("joran casting".to_string());
+        assert_eq!(index.queries.entries[0].weight, MIN_QUERY_HITS + 1);
+    }
+
+    #[test]
+    fn promoted_queries_stay_sorted_by_key() {
+        let mut index = SuggestIndex::default();
+        for key in ["umpan", "joran", "kail"] {
+            record_times(&mut index, key, MIN_QUERY_HITS);
+        }
+        let keys: Vec<&str> = index
+            .queries
+            .entries
+            .iter()
+            .map(|e| e.key.as_str())
+            .collect();
+        assert_eq!(keys, ["joran", "kail", "umpan"]);
+    }
+
+    #[test]
+    fn full_list_evicts_least_searched_query() {
+        let mut entries: Vec<Entry> = (0..MAX_QUERY_ENTRIES)
+            .map(|i| query_entry(&format!("q{:04}", i), 10))
+            .collect();
+        entries[7].weight = MIN_QUERY_HITS;
+        let mut index = SuggestIndex {
+            queries: PrefixList::from_entries(entries),
+            ..Default::default()
+        };
+
+        // Belum lebih populer dari entry terlemah: tetap kandidat
+        record_times(&mut index, "senar", MIN_QUERY_HITS);
+        assert!(!has_query(&index, "senar"));
+
+        index.record_query("senar".to_string());
+        assert!(has_query(&index, "senar"));
+        assert!(!has_query(&index, "q0007"));
+        assert_eq!(index.queries.entries.len(), MAX_QUERY_ENTRIES);
+        assert_eq!(index.pending_queries.get("q0007"), Some(&MIN_QUERY_HITS));
+        assert!(
+            index
+                .queries
+                .entries
+                .windows(2)
+                .all(|w| w[0].key < w[1].key)
+        );
+    }
+
+    #[test]
+    fn pending_queries_never_exceed_cap() {
+        let mut entries: Vec<Entry> = (0..MAX_QUERY_ENTRIES)
+            .map(|i| query_entry(&format!("q{:04}", i), 10))
+            .collect();
+        entries[0].weight = 1;
+        let mut pending: HashMap<String, i64> = (0..MAX_PENDING_QUERIES)
+            .map(|i| (format!("p{:05}", i), 1))
+            .collect();
+        pending.insert("p00000".to_string(), 20);
+        let mut index = SuggestIndex {
+            queries: PrefixList::from_entries(entries),
+            pending_queries: pending,
+            ..Default::default()
+        };
+
+        // Kandidat penuh: query baru tidak dicatat
+        index.record_query("query baru".to_string());
+        assert!(!index.pending_queries.contains_key("query baru"));
+        assert_eq!(index.pending_queries.len(), MAX_PENDING_QUERIES);
+
+        // Promosi menggeser entry publik; jumlah kandidat tetap dalam batas
+        index.record_query("p00000".to_string());
+        assert!(has_query(&index, "p00000"));
+        assert!(!has_query(&index, "q0000"));
+        assert!(index.pending_queries.len() <= MAX_PENDING_QUERIES);
+    }
+
+    #[test]
+    fn lookup_matches_prefix_ordered_by_weight() {
+        let mut entries = name_entries(1, "Shimano Joran Casting");
+        entries.extend(name_entries(2, "Joran Spinning"));
+        entries.extend(name_entries(3, "Kail Pancing"));
+        let list = PrefixList::from_entries(entries);
+
+        let texts: Vec<String> = list
+            .lookup("joran", 10)
+            .into_iter()
+            .map(|s| s.text)
+            .collect();
+        assert_eq!(texts, ["Joran Spinning", "Shimano Joran Casting"]);
+
+        let cast = list.lookup("cast", 10);
+        assert_eq!(cast.len(), 1);
+        assert_eq!(cast[0].id, Some(1));
+
+        assert!(list.lookup("umpan", 10).is_empty());
+        assert_eq!(list.lookup("j", 1).len(), 1);
+    }
+
+    #[test]
+    fn lookup_skips_duplicate_suggestions() {
+        // "joran joran" menghasilkan dua key yang sama-sama diawali "joran"
+        let list = PrefixList::from_entries(name_entries(5, "Joran Joran"));
+        let suggestions = list.lookup("joran", 10);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].id, Some(5));
+    }
+}